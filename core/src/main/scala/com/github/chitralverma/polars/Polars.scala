@@ -1,6 +1,6 @@
 package com.github.chitralverma.polars
 
-import com.github.chitralverma.polars.api.io.Scannable
+import com.github.chitralverma.polars.api.io.{Readable, Scannable}
 import com.github.chitralverma.polars.api.{DataFrame, LazyFrame}
 import com.github.chitralverma.polars.config.Config
 import com.github.chitralverma.polars.internal.jni.{common, data_frame, lazy_frame}
@@ -15,8 +15,9 @@ object Polars {
     * lazily scan datasets of various formats
     * ([[com.github.chitralverma.polars.api.io.Scannable.parquet parquet]],
     * [[com.github.chitralverma.polars.api.io.Scannable.ipc ipc]],
-    * [[com.github.chitralverma.polars.api.io.Scannable.csv csv]] and
-    * [[com.github.chitralverma.polars.api.io.Scannable.jsonLines jsonLines]]) from local
+    * [[com.github.chitralverma.polars.api.io.Scannable.csv csv]],
+    * [[com.github.chitralverma.polars.api.io.Scannable.jsonLines jsonLines]] and
+    * [[com.github.chitralverma.polars.api.io.Scannable.lines lines]]) from local
     * filesystems and cloud object stores (aws, gcp and azure) as a
     * [[com.github.chitralverma.polars.api.LazyFrame LazyFrame]].
     * @return
@@ -24,6 +25,15 @@ object Polars {
     */
   def scan: Scannable = new Scannable()
 
+  /** Returns a [[com.github.chitralverma.polars.api.io.Readable Readable]] that can be used to
+    * eagerly read datasets of formats that cannot be scanned lazily (Arrow IPC streams, Avro
+    * and JSON documents) from local filesystems, cloud object stores (aws, gcp and azure) and
+    * in-memory buffers as a [[com.github.chitralverma.polars.api.DataFrame DataFrame]].
+    * @return
+    *   [[com.github.chitralverma.polars.api.io.Readable Readable]]
+    */
+  def read: Readable = new Readable()
+
   def concat(lazyFrame: LazyFrame, lazyFrames: Array[LazyFrame]): LazyFrame =
     concat(lazyFrame, lazyFrames, reChunk = false, parallel = true)
 
@@ -48,7 +58,9 @@ object Polars {
   def concat(dataFrame: DataFrame, dataFrames: Array[DataFrame]): DataFrame =
     if (dataFrames.isEmpty) dataFrame
     else {
-      val ptr = data_frame.concatDataFrames(dataFrames.+:(dataFrame).map(_.ptr))
+      val ptr = DataFrame.lockedAll((dataFrame +: dataFrames).toSeq) { ptrs =>
+        data_frame.concatDataFrames(ptrs.toArray)
+      }
 
       DataFrame.withPtr(ptr)
     }
@@ -0,0 +1,74 @@
+package com.github.chitralverma.polars.api
+
+import com.github.chitralverma.polars.internal.jni.query
+
+/** A query started by [[LazyFrame.collectAsync]] that executes on the polars thread pool while
+  * the caller continues. Its result can be polled, awaited (optionally with a timeout) or the
+  * query can be cancelled.
+  *
+  * Closing a query that is still running cancels it.
+  */
+class AsyncQuery private (private[polars] val _ptr: Long) extends AutoCloseable {
+
+  private var isClosed = false
+
+  private[polars] def ptr: Long = {
+    checkClosed()
+    _ptr
+  }
+
+  override def close(): Unit = synchronized {
+    if (!isClosed && _ptr != 0) {
+      query.free(_ptr)
+      isClosed = true
+    }
+  }
+
+  override def finalize(): Unit = close()
+
+  private def checkClosed(): Unit =
+    if (isClosed) throw new IllegalStateException("AsyncQuery is already closed.")
+
+  /** Returns the result if the query has finished, or `None` while it is still running.
+    *
+    * @throws RuntimeException
+    *   if the query failed or was cancelled
+    */
+  def poll(): Option[DataFrame] = AsyncQuery.toResult(query.poll(ptr))
+
+  /** Blocks until the query finishes and returns its result. Interrupting the calling thread
+    * cancels the query and throws an `InterruptedException`.
+    *
+    * @throws RuntimeException
+    *   if the query failed or was cancelled
+    */
+  def await(): DataFrame = DataFrame.withPtr(query.awaitResult(ptr, 0))
+
+  /** Blocks until the query finishes or `timeoutMillis` elapses, returning `None` on timeout. The
+    * query keeps running after a timeout.
+    *
+    * @throws RuntimeException
+    *   if the query failed or was cancelled
+    */
+  def await(timeoutMillis: Long): Option[DataFrame] = {
+    require(timeoutMillis > 0, "Timeout must be positive.")
+    AsyncQuery.toResult(query.awaitResult(ptr, timeoutMillis))
+  }
+
+  /** Asks polars to stop executing the query at its earliest convenience. A later `poll` or
+    * `await` then fails with the cancellation.
+    */
+  def cancel(): Unit = query.cancel(ptr)
+
+  /** Whether the query has finished, successfully or not. */
+  def isDone: Boolean = query.isDone(ptr)
+}
+
+object AsyncQuery {
+
+  private[polars] def withPtr(ptr: Long) = new AsyncQuery(ptr)
+
+  private def toResult(dfPtr: Long): Option[DataFrame] =
+    if (dfPtr == 0) None else Some(DataFrame.withPtr(dfPtr))
+
+}
@@ -1,6 +1,6 @@
 package com.github.chitralverma.polars.api
 
-import java.util.Collections
+import java.util.concurrent.atomic.AtomicLong
 
 import scala.annotation.varargs
 import scala.jdk.CollectionConverters._
@@ -15,6 +15,8 @@ class DataFrame private (private[polars] val _ptr: Long) extends AutoCloseable {
 
   private var isClosed = false
 
+  private val id = DataFrame.ids.getAndIncrement()
+
   private[polars] def ptr: Long = {
     checkClosed()
     _ptr
@@ -32,16 +34,84 @@ class DataFrame private (private[polars] val _ptr: Long) extends AutoCloseable {
   private[polars] def checkClosed(): Unit =
     if (isClosed) throw new IllegalStateException("DataFrame is already closed.")
 
-  lazy val schema: Schema = {
-    val schemaString = data_frame.schemaString(ptr)
+  /** Runs `f` with the native pointer while holding the lock of this DataFrame, so that native
+    * reads never overlap the in-place changes of [[dropInPlace]] and [[extend]].
+    */
+  private[polars] def locked[T](f: Long => T): T = synchronized(f(ptr))
+
+  /** The schema, read on every access since [[dropInPlace]] and [[extend]] modify the DataFrame.
+    */
+  def schema: Schema = {
+    val schemaString = locked(data_frame.schemaString(_))
     Schema.fromString(schemaString)
   }
 
-  lazy val width: Int = schema.getFields.length
+  def width: Int = locked(data_frame.shape(_))(1).toInt
+
+  /** The schema as polars' own data types in a stable JSON contract, keeping information lost in
+    * [[schema]] such as categorical vs string, enum categories, decimal precision and time zones.
+    *
+    * Each field's `dtype` object is accepted wherever a data type string is, e.g. by `cast`.
+    */
+  def schemaJson: String = locked(data_frame.schemaJson(_))
+
+  def height: Long = count()
+
+  def shape: (Long, Int) = {
+    val hw = locked(data_frame.shape(_))
+    (hw(0), hw(1).toInt)
+  }
+
+  def columns: Array[String] = locked(data_frame.columnNames(_))
+
+  /** Column data types in the JSON contract of [[schemaJson]], e.g. `{"type":"int64"}`, as
+    * accepted by `cast`.
+    */
+  def dtypes: Array[String] = locked(data_frame.dtypes(_))
+
+  def getColumn(name: String): Series = Series.withPtr(locked(data_frame.getColumn(_, name)))
+
+  def getColumn(index: Int): Series =
+    Series.withPtr(locked(data_frame.getColumnAt(_, index)))
 
-  lazy val height: Long = count()
+  def getColumns: Array[Series] = locked(data_frame.getColumns(_)).map(Series.withPtr)
 
-  lazy val shape: (Long, Int) = (height, width)
+  /** Values of row `index`, boxed as with [[Row]] values. */
+  def row(index: Long): Array[Object] = locked(data_frame.row(_, index))
+
+  /** Selects columns by name without going through a LazyFrame. */
+  @varargs
+  def selectColumns(colName: String, colNames: String*): DataFrame =
+    DataFrame.withPtr(locked(data_frame.selectColumns(_, (colName +: colNames).toArray)))
+
+  /** Removes the column `name` from this DataFrame itself and returns it. Other threads using
+    * this DataFrame wait until the column is removed.
+    */
+  def dropInPlace(name: String): Series = Series.withPtr(locked(data_frame.dropInPlace(_, name)))
+
+  /** Adds the series as new columns, which must match the height of this DataFrame. */
+  @varargs
+  def hstack(series: Series, more: Series*): DataFrame =
+    DataFrame.withPtr(locked(data_frame.hstack(_, (series +: more).map(_.ptr).toArray)))
+
+  /** Appends the rows of `other`, which must have the same schema, as new chunks. */
+  def vstack(other: DataFrame): DataFrame =
+    DataFrame.withPtr(DataFrame.locked(this, other)(data_frame.vstack(_, _)))
+
+  /** Appends the rows of `other`, which must have the same schema, to this DataFrame itself.
+    * Other threads using this DataFrame wait until the rows are appended.
+    */
+  def extend(other: DataFrame): Unit = DataFrame.locked(this, other)(data_frame.extend(_, _))
+
+  /** Adds the series as a column, replacing an existing column of the same name. */
+  def withColumn(series: Series): DataFrame =
+    DataFrame.withPtr(locked(data_frame.withColumn(_, series.ptr)))
+
+  /** Whether both DataFrames have the same column names, data types and values. With
+    * `nullEqual`, nulls compare equal to each other, otherwise any null makes them unequal.
+    */
+  def frameEquals(other: DataFrame, nullEqual: Boolean = false): Boolean =
+    DataFrame.locked(this, other)(data_frame.equals(_, _, nullEqual))
 
   @varargs
   def select(colName: String, colNames: String*): DataFrame =
@@ -140,14 +210,14 @@ class DataFrame private (private[polars] val _ptr: Long) extends AutoCloseable {
       .collect(projectionPushdown = false, predicatePushdown = false, commSubplanElim = false)
 
   def limit(n: Long): DataFrame =
-    DataFrame.withPtr(data_frame.limit(ptr, n))
+    DataFrame.withPtr(locked(data_frame.limit(_, n)))
 
   def head(n: Long): DataFrame = limit(n)
 
   def first(): DataFrame = limit(1)
 
   def tail(n: Long): DataFrame =
-    DataFrame.withPtr(data_frame.tail(ptr, n))
+    DataFrame.withPtr(locked(data_frame.tail(_, n)))
 
   def last(): DataFrame = tail(1)
 
@@ -166,7 +236,7 @@ class DataFrame private (private[polars] val _ptr: Long) extends AutoCloseable {
     toLazy.dropNulls(subset).collect(noOptimization = true)
 
   def rename(oldName: String, newName: String): DataFrame =
-    rename(Collections.singletonMap(oldName, newName))
+    DataFrame.withPtr(locked(data_frame.rename(_, oldName, newName)))
 
   def rename(mapping: Map[String, String]): DataFrame =
     rename(mapping.asJava)
@@ -184,13 +254,13 @@ class DataFrame private (private[polars] val _ptr: Long) extends AutoCloseable {
     toLazy.unique(subset, keep, maintainOrder).collect(noOptimization = true)
 
   def toLazy: LazyFrame =
-    LazyFrame.withPtr(data_frame.toLazy(ptr))
+    LazyFrame.withPtr(locked(data_frame.toLazy(_)))
 
   def show(): Unit =
-    data_frame.show(ptr)
+    locked(data_frame.show(_))
 
   def count(): Long =
-    data_frame.count(ptr)
+    locked(data_frame.count(_))
 
   /** Provides an iterator to traverse a specified number of rows from the DataFrame.
     * @param nRows
@@ -201,7 +271,7 @@ class DataFrame private (private[polars] val _ptr: Long) extends AutoCloseable {
     *   Iterator of [[Row]]
     */
   def rows(nRows: Long): Iterator[Row] =
-    RowIterator.withPtr(ptr, this).lazyIterator(nRows)
+    locked(ptr => RowIterator.withPtr(ptr, this).lazyIterator(nRows))
 
   /** Provides an iterator to traverse a all rows from the DataFrame.
     * @return
@@ -218,6 +288,22 @@ object DataFrame {
 
   private[polars] def withPtr(ptr: Long) = new DataFrame(ptr)
 
+  private val ids = new AtomicLong()
+
+  /** Runs `f` with the native pointers of `frames` while holding all their locks, taken in the
+    * order the frames were created so that concurrent calls cannot deadlock.
+    */
+  private[polars] def lockedAll[T](frames: Seq[DataFrame])(f: Seq[Long] => T): T = {
+    def lockFrom(remaining: List[DataFrame]): T = remaining match {
+      case Nil => f(frames.map(_.ptr))
+      case frame :: rest => frame.synchronized(lockFrom(rest))
+    }
+    lockFrom(frames.distinct.sortBy(_.id).toList)
+  }
+
+  private def locked[T](df: DataFrame, other: DataFrame)(f: (Long, Long) => T): T =
+    lockedAll(Seq(df, other))(ptrs => f(ptrs(0), ptrs(1)))
+
   /** Initialize new [[com.github.chitralverma.polars.api.DataFrame]] from one or more
     * [[com.github.chitralverma.polars.api.Series]]. The name of a series is used as column name
     * and its values are the values of this column.
@@ -267,4 +353,101 @@ object DataFrame {
   def fromSeries(series: Series, more: java.lang.Iterable[Series]): DataFrame =
     fromSeries(series, more.asScala)
 
+  /** Number of values per column used to infer its data type when building a
+    * [[com.github.chitralverma.polars.api.DataFrame]] from rows without a schema.
+    */
+  val DefaultInferLength: Int = 100
+
+  /** Initialize new [[com.github.chitralverma.polars.api.DataFrame]] from rows of values, one
+    * value per column.
+    *
+    * Values are decoded like literals: boxed primitives, strings, `java.time` values, decimals
+    * and byte arrays map to the matching column type, `null` and `None` to nulls, lists (and
+    * scala sequences) to list columns and maps with string keys to struct columns.
+    *
+    * @param rows
+    *   rows of values, all of the same length
+    * @param schema
+    *   names and data types of the columns. If `null`, columns are named `column_0`,
+    *   `column_1`, ... and the type of each column is inferred from its values.
+    * @param inferLength
+    *   number of values per column used to infer its type when no schema is given, or all
+    *   values if not positive. Later values that do not fit the inferred type become nulls.
+    *
+    * @return
+    *   [[com.github.chitralverma.polars.api.DataFrame]] formed from the provided rows
+    */
+  def fromRows(rows: Array[Array[Object]], schema: Schema, inferLength: Int): DataFrame = {
+    val (names, dataTypes) = Option(schema) match {
+      case Some(s) => (s.getFieldNames, s.getFields.map(_.dataType.ffiName))
+      case None => (null, null)
+    }
+
+    DataFrame.withPtr(
+      data_frame.fromRows(rows.map(_.map(toJavaValue)), names, dataTypes, inferLength)
+    )
+  }
+
+  def fromRows(rows: Array[Array[Object]], schema: Schema): DataFrame =
+    fromRows(rows, schema, DefaultInferLength)
+
+  def fromRows(rows: Array[Array[Object]]): DataFrame =
+    fromRows(rows, null, DefaultInferLength)
+
+  def fromRows(rows: Iterator[Array[Object]], schema: Schema, inferLength: Int): DataFrame =
+    fromRows(rows.toArray, schema, inferLength)
+
+  def fromRows(rows: Iterator[Array[Object]], schema: Schema): DataFrame =
+    fromRows(rows.toArray, schema, DefaultInferLength)
+
+  def fromRows(rows: Iterator[Array[Object]]): DataFrame =
+    fromRows(rows.toArray, null, DefaultInferLength)
+
+  def fromRows(
+      rows: java.util.Iterator[Array[Object]],
+      schema: Schema,
+      inferLength: Int
+  ): DataFrame = fromRows(rows.asScala, schema, inferLength)
+
+  def fromRows(rows: java.util.Iterator[Array[Object]], schema: Schema): DataFrame =
+    fromRows(rows.asScala, schema, DefaultInferLength)
+
+  def fromRows(rows: java.util.Iterator[Array[Object]]): DataFrame =
+    fromRows(rows.asScala, null, DefaultInferLength)
+
+  /** Initialize new [[com.github.chitralverma.polars.api.DataFrame]] from products like case
+    * classes or tuples, one column per element. See `fromRows` for how values are decoded.
+    *
+    * @param rows
+    *   products with the same number of elements
+    * @param schema
+    *   names and data types of the columns, or `null` to infer them as in `fromRows`
+    *
+    * @return
+    *   [[com.github.chitralverma.polars.api.DataFrame]] formed from the provided products
+    */
+  def fromProducts(rows: Iterable[Product], schema: Schema = null): DataFrame =
+    fromRows(
+      rows.iterator.map(_.productIterator.map(_.asInstanceOf[Object]).toArray),
+      schema,
+      DefaultInferLength
+    )
+
+  /** Converts scala values to the java types understood by the native value decoder. */
+  private def toJavaValue(value: Object): Object = value match {
+    case null | None => null
+    case Some(v) => toJavaValue(v.asInstanceOf[Object])
+    case m: scala.collection.Map[_, _] =>
+      m.map { case (k, v) => k -> toJavaValue(v.asInstanceOf[Object]) }.asJava
+    case bytes: Array[Byte] => bytes
+    case arr: Array[_] => arr.toSeq.map(v => toJavaValue(v.asInstanceOf[Object])).asJava
+    case it: scala.collection.Iterable[_] =>
+      it.toSeq.map(v => toJavaValue(v.asInstanceOf[Object])).asJava
+    case l: java.util.List[_] =>
+      l.asScala.map(v => toJavaValue(v.asInstanceOf[Object])).asJava
+    case m: java.util.Map[_, _] =>
+      m.asScala.map { case (k, v) => k -> toJavaValue(v.asInstanceOf[Object]) }.asJava
+    case v => v
+  }
+
 }
@@ -39,6 +39,26 @@ class LazyFrame private (private[polars] val _ptr: Long) extends AutoCloseable {
 
   lazy val width: Int = schema.getFields.length
 
+  /** The schema as polars' own data types in a stable JSON contract, keeping information lost in
+    * [[schema]] such as categorical vs string, enum categories, decimal precision and time zones.
+    *
+    * Each field's `dtype` object is accepted wherever a data type string is, e.g. by `cast`.
+    */
+  def schemaJson: String = {
+    checkClosed()
+    lazy_frame.schemaJson(ptr)
+  }
+
+  /** Cast the columns named in `schemaJson` (as returned by [[schemaJson]]) to their data types.
+    *
+    * @param strict
+    *   raise an error on values that cannot be converted instead of turning them into nulls
+    */
+  def castToSchema(schemaJson: String, strict: Boolean = true): LazyFrame = {
+    checkClosed()
+    LazyFrame.withPtr(lazy_frame.castToSchema(ptr, schemaJson, strict))
+  }
+
   @varargs
   def select(colName: String, colNames: String*): LazyFrame = {
     val ldfPtr = lazy_frame.selectFromStrings(ptr, colNames.+:(colName).distinct.toArray)
@@ -258,6 +278,23 @@ class LazyFrame private (private[polars] val _ptr: Long) extends AutoCloseable {
     println(planStr)
   }
 
+  /** Renders the plan of this LazyFrame as a Graphviz DOT graph.
+    *
+    * @param optimized
+    *   whether to render the optimized plan instead of the plan as it was built
+    */
+  def toDot(optimized: Boolean = true): String = lazy_frame.toDot(ptr, optimized)
+
+  /** Returns the plan of this LazyFrame as a JSON tree. Every node carries its `type`, output
+    * `schema` and child nodes under `inputs`. Depending on its type, a node also carries its
+    * `sources`, `projection`, `predicates` (including those pushed down into scans) and
+    * `expressions`.
+    *
+    * @param optimized
+    *   whether to return the optimized plan instead of the plan as it was built
+    */
+  def planTree(optimized: Boolean = true): String = lazy_frame.planTree(ptr, optimized)
+
   def cache: LazyFrame = {
     val ldfPtr = lazy_frame.cache(ptr)
 
@@ -295,10 +332,136 @@ class LazyFrame private (private[polars] val _ptr: Long) extends AutoCloseable {
     DataFrame.withPtr(dfPtr)
   }
 
+  /** Collects this LazyFrame on the in-memory engine while timing every node of its physical
+    * plan.
+    *
+    * @return
+    *   the result, and a DataFrame with one row per executed node holding its name (`node`) and
+    *   its `start` and `end` in microseconds since the query started
+    */
+  def profile(): (DataFrame, DataFrame) = {
+    val ptrs = lazy_frame.profile(ptr)
+    (DataFrame.withPtr(ptrs(0)), DataFrame.withPtr(ptrs(1)))
+  }
+
+  /** Collects this LazyFrame on the streaming engine while recording metrics for every operator.
+    *
+    * @return
+    *   the result, and a DataFrame with one row per operator holding its name (`node`), the rows
+    *   and morsels it received (`rows_in`, `morsels_in`) and sent (`rows_out`, `morsels_out`),
+    *   and the time spent in it (`time`) in microseconds, summed over all threads
+    */
+  def profileStreaming(): (DataFrame, DataFrame) = {
+    val ptrs = lazy_frame.profileStreaming(ptr)
+    (DataFrame.withPtr(ptrs(0)), DataFrame.withPtr(ptrs(1)))
+  }
+
+  /** Starts collecting this LazyFrame on the polars thread pool and returns immediately. The
+    * returned [[AsyncQuery]] is used to poll, await or cancel the query.
+    */
+  def collectAsync(): AsyncQuery = AsyncQuery.withPtr(lazy_frame.collectAsync(ptr))
+
+  /** Serializes the logical plan of this LazyFrame to JSON, tagged with the polars DSL version it
+    * was written with. Use [[LazyFrame.deserializeJson]] to restore it, possibly in another JVM.
+    *
+    * @param allowInMemory
+    *   whether the plan may embed in-memory DataFrames. When `false`, serializing a plan built
+    *   from a DataFrame rather than a scan fails.
+    */
+  def serializeJson(allowInMemory: Boolean = false): String =
+    lazy_frame.serializeJson(ptr, allowInMemory)
+
+  /** Serializes the logical plan of this LazyFrame to polars' versioned binary format. Use
+    * [[LazyFrame.deserializeBinary]] to restore it, possibly in another JVM.
+    *
+    * @param allowInMemory
+    *   whether the plan may embed in-memory DataFrames. When `false`, serializing a plan built
+    *   from a DataFrame rather than a scan fails.
+    */
+  def serializeBinary(allowInMemory: Boolean = false): Array[Byte] =
+    lazy_frame.serializeBinary(ptr, allowInMemory)
+
+  /** Provides an iterator over the rows of the result of this LazyFrame.
+    *
+    * @param nRows
+    *   number of rows to traverse, or all rows if negative
+    * @param streaming
+    *   run the query on the streaming engine and produce rows as it emits them, instead of after
+    *   collecting the whole result
+    * @return
+    *   Iterator of [[Row]], which should be closed if not fully consumed
+    */
+  def rows(nRows: Long = -1L, streaming: Boolean = true): Iterator[Row] with AutoCloseable =
+    RowIterator.fromLazyFrame(this, nRows, streaming)
+
 }
 
 object LazyFrame {
+  import com.github.chitralverma.polars.jsonMapper
 
   private[polars] def withPtr(ptr: Long) = new LazyFrame(ptr)
 
+  /** Restores a LazyFrame written by `LazyFrame.serializeJson`.
+    *
+    * @throws RuntimeException
+    *   if the input is malformed or was written by an incompatible polars version
+    */
+  def deserializeJson(json: String): LazyFrame = withPtr(lazy_frame.deserializeJson(json))
+
+  /** Restores a LazyFrame written by `LazyFrame.serializeBinary`.
+    *
+    * @throws RuntimeException
+    *   if the input is malformed or was written by an incompatible polars version
+    */
+  def deserializeBinary(bytes: Array[Byte]): LazyFrame =
+    withPtr(lazy_frame.deserializeBinary(bytes))
+
+  /** Collects all of `lazyFrames` in a single pass. Their plans are optimized together so that
+    * common subplans, like a shared scan, are only evaluated once. An optimization is applied
+    * only if it is enabled on every input.
+    *
+    * @return
+    *   one [[DataFrame]] per input, in the same order
+    */
+  def collectAll(lazyFrames: Array[LazyFrame]): Array[DataFrame] =
+    lazy_frame.collectAll(lazyFrames.map(_.ptr)).map(DataFrame.withPtr)
+
+  /** Streams each of `lazyFrames` to the path at the same position of `paths` in the format at
+    * the same position of `formats`, executing all sinks in a single pass so that common
+    * subplans are only evaluated once.
+    *
+    * Supported formats are 'parquet', 'ipc', 'csv' and 'json' (newline delimited). Cloud options
+    * are resolved for each path separately, so local and cloud targets can be mixed.
+    *
+    * Supported options:
+    *   - `sink_maintain_order`: Keeps the order of the rows in the output. Default: true.
+    *   - `sink_mkdir`: Creates missing parent directories of the paths. Default: false.
+    */
+  def sinkAll(
+      lazyFrames: Array[LazyFrame],
+      paths: Array[String],
+      formats: Array[String],
+      options: Map[String, String] = Map.empty
+  ): Unit = {
+    require(
+      lazyFrames.length == paths.length && paths.length == formats.length,
+      "Expected one path and one format per LazyFrame."
+    )
+
+    lazy_frame.sinkAll(
+      lazyFrames.map(_.ptr),
+      paths,
+      formats,
+      jsonMapper.writeValueAsString(options)
+    )
+  }
+
+  /** Java friendly variant of `sinkAll`. */
+  def sinkAll(
+      lazyFrames: Array[LazyFrame],
+      paths: Array[String],
+      formats: Array[String],
+      options: java.util.Map[String, String]
+  ): Unit = sinkAll(lazyFrames, paths, formats, options.asScala.toMap)
+
 }
@@ -12,62 +12,99 @@ import com.github.chitralverma.polars.jsonMapper
 class RowIterator private (private[polars] val ptr: Long, private val parent: DataFrame) {
 
   private[polars] def lazyIterator(nRows: Long): Iterator[Row] with AutoCloseable =
-    new Iterator[Row] with AutoCloseable {
-
-      // Hold a reference to the parent DataFrame to prevent it from being GC'd.
-      // The native iterator borrows memory from the parent DataFrame.
-      private val _parent = parent
+    new RowIterator.Rows(row.createIterator(ptr, nRows), parent)
+}
 
-      private var isClosed = false
-      private val iteratorPtr = row.createIterator(ptr, nRows)
-      private val schema = {
-        val schemaString = row.schemaString(iteratorPtr)
-        Schema.fromString(schemaString)
-      }
+object RowIterator {
 
-      private var nextValue: Option[Array[Object]] = fetchNext()
+  /** Number of rows fetched from native code per JNI call. */
+  private[polars] val BatchSize: Int = 1024
 
-      private def fetchNext(): Option[Array[Object]] = {
-        if (isClosed) return None
+  private[polars] def withPtr(ptr: Long, parent: DataFrame) = new RowIterator(ptr, parent)
 
-        val value = row.advanceIterator(iteratorPtr)
-        val res = Option(value)
-        if (res.isEmpty) close()
-        res
+  /** Iterates over the result of `ldf`. With `streaming`, rows are produced as the streaming
+    * engine emits them instead of after the whole result is collected.
+    */
+  private[polars] def fromLazyFrame(
+      ldf: LazyFrame,
+      nRows: Long,
+      streaming: Boolean
+  ): Iterator[Row] with AutoCloseable =
+    new Rows(row.createLazyIterator(ldf.ptr, nRows, streaming), ldf)
+
+  private class Rows(iteratorPtr: Long, parent: AnyRef) extends Iterator[Row] with AutoCloseable {
+
+    // Hold a reference to the parent to prevent it from being GC'd.
+    // The native iterator borrows memory from the parent DataFrame.
+    private val _parent = parent
+
+    private var isClosed = false
+    private val schema = {
+      val schemaString = row.schemaString(iteratorPtr)
+      Schema.fromString(schemaString)
+    }
+    private val schemaJson = row.schemaJson(iteratorPtr)
+
+    private var batch: Array[Array[Object]] = Array.empty
+    private var index = 0
+
+    // Batches are fetched on demand, so that rows of a batch are handed out before an error
+    // raised while reading the next one.
+    private def fetchNext(): Unit = {
+      batch = if (isClosed) null else row.advanceBatch(iteratorPtr, BatchSize)
+      index = 0
+      if (batch == null) {
+        batch = Array.empty
+        close()
       }
+    }
 
-      override def hasNext: Boolean = nextValue.isDefined
+    override def hasNext: Boolean = {
+      if (index == batch.length && !isClosed) fetchNext()
+      index < batch.length
+    }
 
-      override def next(): Row = {
+    override def next(): Row = {
+      if (!hasNext) {
         if (isClosed) throw new NoSuchElementException("Iterator is already closed.")
-        val arr = nextValue.getOrElse(throw new NoSuchElementException("End of iterator"))
-        nextValue = fetchNext()
-        Row.fromObjects(arr, schema)
+        else throw new NoSuchElementException("End of iterator")
       }
 
-      override def close(): Unit = synchronized {
-        if (!isClosed) {
-          row.free(iteratorPtr)
-          isClosed = true
-        }
-      }
-
-      override def finalize(): Unit = close()
+      val arr = batch(index)
+      index += 1
+      Row.fromObjects(arr, schema, Some(schemaJson))
     }
-}
 
-object RowIterator {
+    override def close(): Unit = synchronized {
+      if (!isClosed) {
+        row.free(iteratorPtr)
+        isClosed = true
+        batch = Array.empty
+        index = 0
+      }
+    }
 
-  private[polars] def withPtr(ptr: Long, parent: DataFrame) = new RowIterator(ptr, parent)
+    override def finalize(): Unit = close()
+  }
 }
 
-class Row private (private[polars] val arr: Array[Object], schema: Schema) {
+class Row private (
+    private[polars] val arr: Array[Object],
+    schema: Schema,
+    schemaJson: Option[String]
+) {
 
   assert(schema != null, "Schema of a Row cannot be null")
 
   /** Returns the Schema for the row. This yields same value as [[DataFrame.schema]] */
   def getSchema: Schema = schema
 
+  /** Returns the schema for the row in polars' own JSON contract, the same value as
+    * [[DataFrame.schemaJson]]. Only rows read from a DataFrame carry it, so rows built by hand or
+    * returned by [[getStruct(i:Int)*]] return `None`.
+    */
+  def getSchemaJson: Option[String] = schemaJson
+
   /** Returns the data type of field at position `i`. */
   def getDataType(i: Int): DataType = schema.getFields(i).dataType
 
@@ -410,5 +447,11 @@ object Row {
   private def assertDataType(target: DataType, dt: DataType): Unit =
     assert(dt == target, s"Data Type mismatch, field is of `$target` but `$dt` was provided")
 
-  def fromObjects(arr: Array[Object], schema: Schema) = new Row(arr, schema)
+  def fromObjects(arr: Array[Object], schema: Schema) = new Row(arr, schema, None)
+
+  private[polars] def fromObjects(
+      arr: Array[Object],
+      schema: Schema,
+      schemaJson: Option[String]
+  ): Row = new Row(arr, schema, schemaJson)
 }
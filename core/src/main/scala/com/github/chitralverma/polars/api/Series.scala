@@ -2,6 +2,8 @@ package com.github.chitralverma.polars.api
 
 import scala.jdk.CollectionConverters._
 
+import com.github.chitralverma.polars.api.expressions.BinaryOperators._
+import com.github.chitralverma.polars.api.types.DataType
 import com.github.chitralverma.polars.internal.jni.series
 
 class Series private (private[polars] val _ptr: Long) extends AutoCloseable {
@@ -27,6 +29,187 @@ class Series private (private[polars] val _ptr: Long) extends AutoCloseable {
 
   def show(): Unit =
     series.show(ptr)
+
+  def name: String = series.name(ptr)
+
+  /** Returns a copy of this series with the given name. */
+  def rename(name: String): Series = Series.withPtr(series.rename(ptr, name))
+
+  /** Data type of the series in the JSON contract of `DataFrame.schemaJson`, e.g.
+    * `{"type":"int64"}`, as accepted by `cast` and `Series.ofType`.
+    */
+  def dtype: String = series.dtype(ptr)
+
+  def len: Long = series.len(ptr)
+
+  def nullCount: Long = series.nullCount(ptr)
+
+  /** Value at `index`, boxed as with [[Row]] values, or `null` for a null value. */
+  def get(index: Long): Any = series.get(ptr, index)
+
+  /** `length` values starting at `offset`, where a negative offset counts from the end. */
+  def slice(offset: Long, length: Long): Series =
+    Series.withPtr(series.slice(ptr, offset, length))
+
+  /** The first `n` values, or all but the last `-n` values if `n` is negative. */
+  def head(n: Long = 10): Series = Series.withPtr(series.head(ptr, n))
+
+  /** The last `n` values, or all but the first `-n` values if `n` is negative. */
+  def tail(n: Long = 10): Series = Series.withPtr(series.tail(ptr, n))
+
+  def sort(descending: Boolean = false, nullsLast: Boolean = false): Series =
+    Series.withPtr(series.sort(ptr, descending, nullsLast))
+
+  /** Indices that would sort the series. */
+  def argSort(descending: Boolean = false, nullsLast: Boolean = false): Series =
+    Series.withPtr(series.argSort(ptr, descending, nullsLast))
+
+  def unique(maintainOrder: Boolean = false): Series =
+    Series.withPtr(series.unique(ptr, maintainOrder))
+
+  def nUnique: Long = series.nUnique(ptr)
+
+  /** DataFrame of the unique values and their `count`, or their `proportion` if `normalize`.
+    *
+    * @param sort
+    *   sort by descending count
+    */
+  def valueCounts(sort: Boolean = false, normalize: Boolean = false): DataFrame =
+    DataFrame.withPtr(series.valueCounts(ptr, sort, normalize))
+
+  /** Keeps the values for which the boolean series `mask` is `true`. */
+  def filter(mask: Series): Series = Series.withPtr(series.filter(ptr, mask.ptr))
+
+  /** Gathers the values at `indices`, in that order. */
+  def take(indices: Array[Long]): Series = Series.withPtr(series.take(ptr, indices))
+
+  /** Casts the series to `dataType`. If `strict`, values that cannot be represented in
+    * `dataType` are an error, otherwise they become null.
+    */
+  def cast(dataType: String, strict: Boolean = true): Series =
+    Series.withPtr(series.cast(ptr, dataType, strict))
+
+  /** Plus. `other` is a [[Series]] or a scalar broadcast to every value. */
+  def +(other: Any): Series = applyBinaryOp(other, PLUS.id)
+
+  /** Minus. */
+  def -(other: Any): Series = applyBinaryOp(other, MINUS.id)
+
+  /** Multiply. */
+  def *(other: Any): Series = applyBinaryOp(other, MULTIPLY.id)
+
+  /** Divide. */
+  def /(other: Any): Series = applyBinaryOp(other, DIVIDE.id)
+
+  /** Modulus. */
+  def %(other: Any): Series = applyBinaryOp(other, MODULUS.id)
+
+  /** And. */
+  def &&(other: Any): Series = applyBinaryOp(other, AND.id)
+
+  /** Or. */
+  def ||(other: Any): Series = applyBinaryOp(other, OR.id)
+
+  /** EqualTo. */
+  def ===(other: Any): Series = applyBinaryOp(other, EQUAL_TO.id)
+
+  /** NotEqualTo. */
+  def <>(other: Any): Series = applyBinaryOp(other, NOT_EQUAL_TO.id)
+
+  /** LessThan. */
+  def <(other: Any): Series = applyBinaryOp(other, LESS_THAN.id)
+
+  /** LessThanEqualTo. */
+  def <=(other: Any): Series = applyBinaryOp(other, LESS_THAN_EQUAL_TO.id)
+
+  /** GreaterThan. */
+  def >(other: Any): Series = applyBinaryOp(other, GREATER_THAN.id)
+
+  /** GreaterThanEqualTo. */
+  def >=(other: Any): Series = applyBinaryOp(other, GREATER_THAN_EQUAL_TO.id)
+
+  private def applyBinaryOp(other: Any, operator: Int): Series =
+    other match {
+      case s: Series => Series.withPtr(series.applyBinary(ptr, s.ptr, operator))
+      case v => Series.withPtr(series.applyBinaryScalar(ptr, v, operator))
+    }
+
+  def sum: Any = series.reduce(ptr, "sum")
+
+  def min: Any = series.reduce(ptr, "min")
+
+  def max: Any = series.reduce(ptr, "max")
+
+  def mean: Any = series.reduce(ptr, "mean")
+
+  def median: Any = series.reduce(ptr, "median")
+
+  def std(ddof: Int = 1): Any = series.std(ptr, ddof)
+
+  def variance(ddof: Int = 1): Any = series.variance(ptr, ddof)
+
+  /** @param method
+    *   one of `nearest`, `lower`, `higher`, `midpoint`, `linear` or `equiprobable`
+    */
+  def quantile(quantile: Double, method: String = "nearest"): Any =
+    series.quantile(ptr, quantile, method)
+
+  /** Whether both series have the same name, data type and values. With `nullEqual`, nulls
+    * compare equal to each other, otherwise any null makes the series unequal.
+    */
+  def seriesEquals(other: Series, nullEqual: Boolean = false): Boolean =
+    series.equals(ptr, other.ptr, nullEqual)
+
+  def toFrame: DataFrame = DataFrame.withPtr(series.toFrame(ptr))
+
+  /** Copies the values, cast to `int64`, into a new array. Null values become `0`; see
+    * [[validity]] to tell them apart.
+    */
+  def toLongArray: Array[Long] = series.toLongArray(ptr)
+
+  /** Copies the values, cast to `int32`, into a new array. Null values become `0`. */
+  def toIntArray: Array[Int] = series.toIntArray(ptr)
+
+  /** Copies the values, cast to `float32`, into a new array. Null values become `0`. */
+  def toFloatArray: Array[Float] = series.toFloatArray(ptr)
+
+  /** Copies the values, cast to `float64`, into a new array. Null values become `0`. */
+  def toDoubleArray: Array[Double] = series.toDoubleArray(ptr)
+
+  /** Copies the values, cast to `bool`, into a new array. Null values become `false`. */
+  def toBooleanArray: Array[Boolean] = series.toBooleanArray(ptr)
+
+  /** Copies the values, cast to `str`, into a new array. Null values stay `null`. */
+  def toStringArray: Array[String] = series.toStringArray(ptr)
+
+  /** Validity of the values, with bit `i` set if value `i` is not null, or `None` if the series
+    * has no nulls.
+    */
+  def validity: Option[java.util.BitSet] =
+    Option(series.validity(ptr)).map(java.util.BitSet.valueOf)
+
+  /** Writes the values, cast to the fixed-width `dataType`, into the direct buffer `data` from
+    * its position in native byte order, with null values as `0`. If `validity` is given, an
+    * Arrow validity bitmap (one bit per value, least significant bit first, set for valid values)
+    * is written into it from its position. Buffer positions are left unchanged.
+    *
+    * @return
+    *   the number of values written
+    */
+  def fillDirectBuffer(
+      dataType: String,
+      data: java.nio.ByteBuffer,
+      validity: java.nio.ByteBuffer = null
+  ): Long = {
+    require(data.isDirect, "Data buffer must be a direct buffer")
+    require(
+      data.order() == java.nio.ByteOrder.nativeOrder(),
+      "Data buffer must use the native byte order"
+    )
+    require(validity == null || validity.isDirect, "Validity buffer must be a direct buffer")
+
+    series.fillDirectBuffer(ptr, dataType, data, validity)
+  }
 }
 
 object Series {
@@ -70,7 +253,13 @@ object Series {
     *   retaining type.
     */
   def ofInt(name: String, values: Array[java.lang.Integer]): Series =
-    Series.ofInt(name, values.map(_.intValue()))
+    Series.withPtr(
+      series.newNullableIntSeries(
+        name,
+        values.map(v => if (v == null) 0 else v.intValue()),
+        validityOf(values)
+      )
+    )
 
   /** Initialize new series by name and values of type [[java.lang.Integer]].
     *
@@ -125,7 +314,13 @@ object Series {
     *   retaining type.
     */
   def ofLong(name: String, values: Array[java.lang.Long]): Series =
-    Series.ofLong(name, values.map(_.longValue()))
+    Series.withPtr(
+      series.newNullableLongSeries(
+        name,
+        values.map(v => if (v == null) 0L else v.longValue()),
+        validityOf(values)
+      )
+    )
 
   /** Initialize new series by name and values of type [[java.lang.Long]].
     *
@@ -181,7 +376,13 @@ object Series {
     *   retaining type.
     */
   def ofFloat(name: String, values: Array[java.lang.Float]): Series =
-    Series.ofFloat(name, values.map(_.floatValue()))
+    Series.withPtr(
+      series.newNullableFloatSeries(
+        name,
+        values.map(v => if (v == null) 0f else v.floatValue()),
+        validityOf(values)
+      )
+    )
 
   /** Initialize new series by name and values of type [[java.lang.Float]].
     *
@@ -237,7 +438,13 @@ object Series {
     *   retaining type.
     */
   def ofDouble(name: String, values: Array[java.lang.Double]): Series =
-    Series.ofDouble(name, values.map(_.doubleValue()))
+    Series.withPtr(
+      series.newNullableDoubleSeries(
+        name,
+        values.map(v => if (v == null) 0d else v.doubleValue()),
+        validityOf(values)
+      )
+    )
 
   /** Initialize new series by name and values of type [[java.lang.Double]].
     *
@@ -293,7 +500,13 @@ object Series {
     *   retaining type.
     */
   def ofBoolean(name: String, values: Array[java.lang.Boolean]): Series =
-    Series.ofBoolean(name, values.map(_.booleanValue()))
+    Series.withPtr(
+      series.newNullableBooleanSeries(
+        name,
+        values.map(v => if (v == null) false else v.booleanValue()),
+        validityOf(values)
+      )
+    )
 
   /** Initialize new series by name and values of type [[java.lang.Boolean]].
     *
@@ -576,5 +789,220 @@ object Series {
   def ofList[T](name: String, values: Array[Array[T]]): Series =
     Series.ofList(name, values.map(_.toSeq).toSeq)
 
+  /** Initialize new series by name and boxed values of an explicit data type.
+    *
+    * @param name
+    *   Name of Series
+    * @param dataType
+    *   type string such as `uint64`, `decimal(18, 4)`, `datetime[ms, UTC]` or `list[int32]`
+    * @param values
+    *   Values of Series as a scala or java array, where `null` elements become nulls
+    *
+    * @return
+    *   Series of the provided type. Values that cannot be represented in it raise an error.
+    */
+  def ofType(name: String, dataType: String, values: Array[Any]): Series =
+    Series.withPtr(series.newTypedSeries(name, values.map(_.asInstanceOf[AnyRef]), dataType))
+
+  /** Initialize new series by name and boxed values of an explicit data type.
+    *
+    * @param name
+    *   Name of Series
+    * @param dataType
+    *   type string, see [[ofType(name:String,dataType:String,values:Array[Any])*]]
+    * @param values
+    *   Values of Series as a scala iterable, where `null` elements become nulls
+    */
+  def ofType(name: String, dataType: String, values: Iterable[Any]): Series =
+    Series.ofType(name, dataType, values.toArray)
+
+  /** Initialize new series by name and boxed values of an explicit data type.
+    *
+    * @param name
+    *   Name of Series
+    * @param dataType
+    *   type string, see [[ofType(name:String,dataType:String,values:Array[Any])*]]
+    * @param values
+    *   Values of Series as a java iterable, where `null` elements become nulls
+    */
+  def ofType(name: String, dataType: String, values: java.lang.Iterable[_]): Series =
+    Series.ofType(name, dataType, values.asScala.toArray[Any])
+
+  /** Initialize new series by name and boxed values of an explicit [[DataType]].
+    *
+    * @param name
+    *   Name of Series
+    * @param dataType
+    *   data type of the series
+    * @param values
+    *   Values of Series as a scala or java array, where `null` elements become nulls
+    */
+  def ofType(name: String, dataType: DataType, values: Array[Any]): Series =
+    Series.ofType(name, dataType.ffiName, values)
+
+  /** Initialize new series of type [[scala.Byte]] (`int8`), or of unsigned `uint8` values whose
+    * bits are stored in `values`, with an optional validity mask.
+    *
+    * @param validity
+    *   `null` if all values are valid, else a `boolean[]` with one entry per value or a
+    *   bit-packed `long[]` as returned by [[java.util.BitSet.toLongArray]]
+    */
+  def ofByte(
+      name: String,
+      values: Array[Byte],
+      validity: AnyRef = null,
+      unsigned: Boolean = false
+  ): Series =
+    Series.withPtr(
+      if (unsigned) series.newUInt8Series(name, values, validity)
+      else series.newInt8Series(name, values, validity)
+    )
+
+  /** Initialize new series of type [[scala.Short]] (`int16`), or of unsigned `uint16` values
+    * whose bits are stored in `values`, with an optional validity mask (see [[ofByte]]).
+    */
+  def ofShort(
+      name: String,
+      values: Array[Short],
+      validity: AnyRef = null,
+      unsigned: Boolean = false
+  ): Series =
+    Series.withPtr(
+      if (unsigned) series.newUInt16Series(name, values, validity)
+      else series.newInt16Series(name, values, validity)
+    )
+
+  /** Initialize new series of unsigned `uint32` values whose bits are stored in `values`, with
+    * an optional validity mask (see [[ofByte]]).
+    */
+  def ofUInt32(name: String, values: Array[Int], validity: AnyRef = null): Series =
+    Series.withPtr(series.newUInt32Series(name, values, validity))
+
+  /** Initialize new series of unsigned `uint64` values whose bits are stored in `values`, as
+    * with [[java.lang.Long.toUnsignedString]], with an optional validity mask (see [[ofByte]]).
+    */
+  def ofUInt64(name: String, values: Array[Long], validity: AnyRef = null): Series =
+    Series.withPtr(series.newUInt64Series(name, values, validity))
+
+  /** Initialize new series of type `decimal(precision, scale)`. Values are rescaled to `scale`,
+    * and `null` values become nulls.
+    */
+  def ofDecimal(
+      name: String,
+      values: Array[java.math.BigDecimal],
+      precision: Int,
+      scale: Int
+  ): Series = {
+    val unscaled: Array[Object] = values.map { v =>
+      if (v == null) null
+      else v.setScale(scale, java.math.RoundingMode.UNNECESSARY).unscaledValue()
+    }
+    Series.withPtr(series.newBigDecimalSeries(name, unscaled, precision, scale))
+  }
+
+  /** Initialize new series of type `decimal(precision, scale)` from unscaled values, e.g.
+    * `12345` with scale `2` for `123.45`, with an optional validity mask (see [[ofByte]]).
+    */
+  def ofDecimal(
+      name: String,
+      unscaled: Array[Long],
+      precision: Int,
+      scale: Int,
+      validity: AnyRef = null
+  ): Series =
+    Series.withPtr(series.newDecimalSeries(name, unscaled, precision, scale, validity))
+
+  /** Initialize new series of type `float16` from `values` rounded to half precision, with an
+    * optional validity mask (see [[ofByte]]).
+    */
+  def ofFloat16(name: String, values: Array[Float], validity: AnyRef = null): Series =
+    Series.withPtr(series.newFloat16Series(name, values, validity))
+
+  /** Initialize new binary series, where `null` values become nulls. */
+  def ofBinary(name: String, values: Array[Array[Byte]]): Series =
+    Series.withPtr(series.newBinarySeries(name, values))
+
+  /** Initialize new categorical series, where `null` values become nulls. */
+  def ofCategorical(name: String, values: Array[String]): Series =
+    Series.withPtr(series.newCategoricalSeries(name, values, null))
+
+  /** Initialize new enum series over exactly `categories`, where `null` values become nulls. */
+  def ofEnum(name: String, values: Array[String], categories: Array[String]): Series =
+    Series.withPtr(series.newCategoricalSeries(name, values, categories))
+
+  /** Initialize new duration series with nanosecond precision, like `lit` of a
+    * [[java.time.Duration]], where `null` values become nulls.
+    *
+    * @throws java.lang.ArithmeticException
+    *   if a duration is too long to be expressed in nanoseconds (about 292 years)
+    */
+  def ofDuration(name: String, values: Array[java.time.Duration]): Series =
+    Series.withPtr(
+      series.newDurationSeries(
+        name,
+        values.map(v => if (v == null) 0L else v.toNanos),
+        "ns",
+        validityOf(values)
+      )
+    )
+
+  /** Initialize new datetime series from timestamps since the epoch.
+    *
+    * @param timeUnit
+    *   unit of `values`, one of `ns`, `us` or `ms`
+    * @param timeZone
+    *   time zone of the series, or `null` for naive datetimes
+    * @param validity
+    *   optional validity mask (see [[ofByte]])
+    */
+  def ofEpochDateTime(
+      name: String,
+      values: Array[Long],
+      timeUnit: String,
+      timeZone: String = null,
+      validity: AnyRef = null
+  ): Series =
+    Series.withPtr(series.newEpochDatetimeSeries(name, values, timeUnit, timeZone, validity))
+
+  /** Initialize new series over the memory of a direct [[java.nio.ByteBuffer]] without copying
+    * it. The series keeps the buffer alive, and its contents must not be modified afterwards.
+    *
+    * @param dataType
+    *   a fixed-width numeric or temporal type, e.g. `float64`, `int32` or `datetime[us]`
+    * @param data
+    *   direct buffer holding `length` values in native byte order between its position and
+    *   limit, starting at an address aligned to the value width
+    * @param validity
+    *   `null` if all values are valid, else a direct buffer holding an Arrow validity bitmap (one
+    *   bit per value, least significant bit first, set for valid values) between its position
+    *   and limit
+    */
+  def ofDirectBuffer(
+      name: String,
+      dataType: String,
+      data: java.nio.ByteBuffer,
+      length: Long,
+      validity: java.nio.ByteBuffer = null
+  ): Series = {
+    require(data.isDirect, "Data buffer must be a direct buffer")
+    require(
+      data.order() == java.nio.ByteOrder.nativeOrder(),
+      "Data buffer must use the native byte order"
+    )
+    require(validity == null || validity.isDirect, "Validity buffer must be a direct buffer")
+
+    Series.withPtr(
+      series.newDirectBufferSeries(
+        name,
+        dataType,
+        data,
+        length,
+        validity
+      )
+    )
+  }
+
+  private def validityOf(values: Array[_ <: AnyRef]): Array[Boolean] = values.map(_ != null)
+
   def withPtr(ptr: Long) = new Series(ptr)
 }
@@ -175,4 +175,19 @@ object Column {
     new Column(ptr)
   }
 
+  /** Restores an expression written by [[Expression.serializeJson]].
+    *
+    * @throws RuntimeException
+    *   if the input is malformed or was written by an incompatible polars version
+    */
+  def deserializeJson(json: String): Column = withPtr(column_expr.deserializeJson(json))
+
+  /** Restores an expression written by [[Expression.serializeBinary]].
+    *
+    * @throws RuntimeException
+    *   if the input is malformed or was written by an incompatible polars version
+    */
+  def deserializeBinary(bytes: Array[Byte]): Column =
+    withPtr(column_expr.deserializeBinary(bytes))
+
 }
@@ -51,7 +51,39 @@ class Expression(protected[polars] val _ptr: Long) extends AutoCloseable {
     Column.withPtr(column_expr.cast(ptr, dataType.ffiName))
   }
 
+  /** Cast the expression to the DataType described by a type string such as `datetime[ms, UTC]`,
+    * `decimal(18,4)`, `list[int64]`, `array[float32, 128]`, `struct<a: int64, b: string>`,
+    * `enum[a, b]`, `categorical` or `duration[us]`.
+    *
+    * @param dataType
+    *   target data type string
+    */
+  def cast(dataType: String): Column = {
+    checkClosed()
+    Column.withPtr(column_expr.cast(ptr, dataType))
+  }
+
+  /** Cast the expression to the DataType described by a type string, with explicit handling of
+    * values that cannot be converted.
+    *
+    * @param dataType
+    *   target data type string, see [[cast(dataType:String)*]]
+    * @param strict
+    *   raise an error on values that cannot be converted instead of turning them into nulls
+    * @param wrapNumerical
+    *   when not strict, wrap out-of-range numbers around instead of turning them into nulls
+    */
+  def cast(dataType: String, strict: Boolean, wrapNumerical: Boolean = false): Column = {
+    checkClosed()
+    Column.withPtr(column_expr.castWithOptions(ptr, dataType, strict, wrapNumerical))
+  }
+
   /** Check if the expression values are present in the provided array.
+    *
+    * Values may be boxed primitives, strings, `java.time` dates/times/timestamps,
+    * `java.math.BigDecimal`/`BigInteger` (up to 38 digits of precision), byte arrays,
+    * `java.util.List` or `java.util.Map` (as structs with fields sorted by key). Values of any
+    * other type are rejected.
     *
     * @param values
     *   array of values to match
@@ -809,6 +841,22 @@ class Expression(protected[polars] val _ptr: Long) extends AutoCloseable {
   /** Reinterpret the bits of an integer column as a signed integer of the same width. */
   def reinterpret(): Column = reinterpret(signed = true)
 
+  /** Serializes this expression to JSON, tagged with the polars DSL version it was written with.
+    * Use [[Column.deserializeJson]] to restore it, possibly in another JVM.
+    */
+  def serializeJson(): String = {
+    checkClosed()
+    column_expr.serializeJson(ptr)
+  }
+
+  /** Serializes this expression to a compact binary form, tagged with the polars DSL version it
+    * was written with. Use [[Column.deserializeBinary]] to restore it, possibly in another JVM.
+    */
+  def serializeBinary(): Array[Byte] = {
+    checkClosed()
+    column_expr.serializeBinary(ptr)
+  }
+
   override def close(): Unit = synchronized {
     if (!isClosed && _ptr != 0) {
       column_expr.free(_ptr)
@@ -0,0 +1,82 @@
+package com.github.chitralverma.polars.api.io
+
+import scala.jdk.CollectionConverters._
+
+import com.github.chitralverma.polars.api.DataFrame
+import com.github.chitralverma.polars.internal.jni.io.ipc_stream_writer
+
+/** Writes [[DataFrame]]s one after the other to a single Arrow IPC stream, at a path (local or
+  * cloud) or into memory. The schema of the stream is that of the first written DataFrame, and
+  * every later DataFrame must have the same schema.
+  *
+  * The stream is complete once [[finish]] is called. Closing an unfinished writer discards the
+  * stream.
+  */
+class IpcStreamWriter private (private val ptr: Long) extends AutoCloseable {
+
+  private var isClosed = false
+
+  private def checkClosed(): Unit =
+    if (isClosed) throw new IllegalStateException("IpcStreamWriter is already closed.")
+
+  /** Appends the content of `df` to the stream. */
+  def write(df: DataFrame): IpcStreamWriter = synchronized {
+    checkClosed()
+    df.locked(ipc_stream_writer.write(ptr, _))
+    this
+  }
+
+  /** Ends the stream, committing it to its path.
+    *
+    * @return
+    *   the encoded stream if it was written into memory, `null` otherwise
+    */
+  def finish(): Array[Byte] = synchronized {
+    checkClosed()
+    ipc_stream_writer.finish(ptr)
+  }
+
+  override def close(): Unit = synchronized {
+    if (!isClosed) {
+      ipc_stream_writer.free(ptr)
+      isClosed = true
+    }
+  }
+
+  override def finalize(): Unit = close()
+}
+
+object IpcStreamWriter {
+  import com.github.chitralverma.polars.jsonMapper
+
+  /** Opens a stream at the specified path (local or cloud).
+    *
+    * Supports the `write_mode` and cloud options of [[Writeable]] along with its IPC options
+    * `write_ipc_compat_level`, `write_compression` and `write_compression_level`.
+    *
+    * @param filePath
+    *   output file location
+    * @param options
+    *   options for the stream
+    */
+  def open(filePath: String, options: Map[String, String]): IpcStreamWriter =
+    new IpcStreamWriter(ipc_stream_writer.open(filePath, jsonMapper.writeValueAsString(options)))
+
+  def open(filePath: String, options: java.util.Map[String, String]): IpcStreamWriter =
+    open(filePath, options.asScala.toMap)
+
+  def open(filePath: String): IpcStreamWriter = open(filePath, Map.empty[String, String])
+
+  /** Opens a stream into memory, whose bytes are returned by [[IpcStreamWriter.finish finish]].
+    *
+    * @param options
+    *   IPC options for the stream, as in [[open]]
+    */
+  def inMemory(options: Map[String, String]): IpcStreamWriter =
+    new IpcStreamWriter(ipc_stream_writer.open(null, jsonMapper.writeValueAsString(options)))
+
+  def inMemory(options: java.util.Map[String, String]): IpcStreamWriter =
+    inMemory(options.asScala.toMap)
+
+  def inMemory(): IpcStreamWriter = inMemory(Map.empty[String, String])
+}
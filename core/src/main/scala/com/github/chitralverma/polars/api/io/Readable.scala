@@ -0,0 +1,215 @@
+package com.github.chitralverma.polars.api.io
+
+import java.nio.ByteBuffer
+
+import scala.annotation.varargs
+import scala.collection.mutable.{Map => MutableMap}
+import scala.jdk.CollectionConverters._
+
+import com.github.chitralverma.polars.Polars
+import com.github.chitralverma.polars.api.DataFrame
+import com.github.chitralverma.polars.api.io.Scannable.toBuffers
+import com.github.chitralverma.polars.internal.jni.io.read._
+
+/** Interface used to eagerly read datasets of formats that cannot be scanned lazily, from local
+  * filesystems and cloud object stores (aws, gcp and azure), or from in-memory buffers. Use
+  * [[Polars.read read()]] to access this.
+  *
+  * Paths are read as they are, without globbing. In-memory buffers are given either as byte
+  * arrays or as `ByteBuffer`s whose remaining bytes are read.
+  *
+  * Cloud options are global and can be set by methods like [[option option[s]()]], see
+  * [[Scannable]] for the supported keys.
+  */
+class Readable private[polars] () {
+  import com.github.chitralverma.polars.jsonMapper
+
+  private val _options: MutableMap[String, String] = MutableMap.empty[String, String]
+
+  /** Adds options for the underlying dataset. */
+  def options(opts: Iterable[(String, String)]): Readable = synchronized {
+    opts.foreach { case (key, value) => option(key, value) }
+    this
+  }
+
+  /** Adds options for the underlying dataset. */
+  def options(opts: java.util.Map[String, String]): Readable = synchronized {
+    opts.asScala.foreach { case (key, value) => option(key, value) }
+    this
+  }
+
+  /** Adds an option for the underlying dataset. */
+  def option(key: String, value: String): Readable = synchronized {
+    if (Option(key).exists(_.trim.isEmpty) || Option(value).exists(_.trim.isEmpty)) {
+      throw new IllegalArgumentException("Option key or value cannot be null or empty.")
+    }
+
+    _options.put(key.trim, value.trim)
+    this
+  }
+
+  private def optionsJson: String = jsonMapper.writeValueAsString(_options)
+
+  /** Reads a dataset in the Arrow IPC stream format from the specified path(s) (local or cloud).
+    * Multiple streams are concatenated vertically.
+    *
+    * Supported options:
+    *   - `read_ipc_stream_n_rows`: Maximum number of rows to read. Default: `null`.
+    *   - `read_ipc_stream_columns`: Comma separated names of the columns to read. Default:
+    *     `null`.
+    *   - `read_ipc_stream_row_index_name`: Adds a row index column with the specified name.
+    *     Default: `null`.
+    *   - `read_ipc_stream_row_index_offset`: Offset (≥0) for row index column (used only if
+    *     `read_ipc_stream_row_index_name` is set). Default: `0`.
+    *   - `read_ipc_stream_rechunk`: Re-chunks the final DataFrame for memory contiguity.
+    *     Default: `false`.
+    *
+    * @param path
+    *   Main input file location.
+    * @param paths
+    *   Additional input file locations.
+    *
+    * @note
+    *   All provided paths must belong to the same object store.
+    */
+  @varargs
+  def ipcStream(path: String, paths: String*): DataFrame =
+    DataFrame.withPtr(readIPCStream(paths.+:(path).toArray[String], null, optionsJson))
+
+  /** Reads a dataset in the Arrow IPC stream format from in-memory buffers, with the same
+    * options as the path-based `ipcStream`.
+    *
+    * @param buffer
+    *   Main input stream content.
+    * @param buffers
+    *   Additional input stream contents.
+    */
+  @varargs
+  def ipcStream(buffer: Array[Byte], buffers: Array[Byte]*): DataFrame =
+    DataFrame.withPtr(readIPCStream(null, toBuffers(buffer, buffers), optionsJson))
+
+  /** Reads a dataset in the Arrow IPC stream format from the remaining bytes of `ByteBuffer`s,
+    * with the same options as the path-based `ipcStream`.
+    *
+    * @param buffer
+    *   Main input stream content.
+    * @param buffers
+    *   Additional input stream contents.
+    */
+  @varargs
+  def ipcStream(buffer: ByteBuffer, buffers: ByteBuffer*): DataFrame =
+    DataFrame.withPtr(readIPCStream(null, toBuffers(buffer, buffers), optionsJson))
+
+  /** Reads a dataset in the Avro object container format from the specified path(s) (local or
+    * cloud). Multiple files are concatenated vertically.
+    *
+    * Supported options:
+    *   - `read_avro_n_rows`: Maximum number of rows to read. Default: `null`.
+    *   - `read_avro_columns`: Comma separated names of the columns to read. Default: `null`.
+    *   - `read_avro_projection`: Comma separated indices (starting at 0) of the columns to read.
+    *     Ignored if `read_avro_columns` is set. Default: `null`.
+    *   - `read_avro_row_index_name`: Adds a row index column with the specified name. Default:
+    *     `null`.
+    *   - `read_avro_row_index_offset`: Offset (≥0) for row index column (used only if
+    *     `read_avro_row_index_name` is set). Default: `0`.
+    *   - `read_avro_rechunk`: Re-chunks the final DataFrame for memory contiguity. Default:
+    *     `false`.
+    *
+    * @param path
+    *   Main input file location.
+    * @param paths
+    *   Additional input file locations.
+    *
+    * @note
+    *   All provided paths must belong to the same object store.
+    */
+  @varargs
+  def avro(path: String, paths: String*): DataFrame =
+    DataFrame.withPtr(readAvro(paths.+:(path).toArray[String], null, optionsJson))
+
+  /** Reads a dataset in the Avro object container format from in-memory buffers, with the same
+    * options as the path-based `avro`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def avro(buffer: Array[Byte], buffers: Array[Byte]*): DataFrame =
+    DataFrame.withPtr(readAvro(null, toBuffers(buffer, buffers), optionsJson))
+
+  /** Reads a dataset in the Avro object container format from the remaining bytes of
+    * `ByteBuffer`s, with the same options as the path-based `avro`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def avro(buffer: ByteBuffer, buffers: ByteBuffer*): DataFrame =
+    DataFrame.withPtr(readAvro(null, toBuffers(buffer, buffers), optionsJson))
+
+  /** Reads whole JSON documents, each an array of objects such as those written by
+    * `DataFrame.write().json(...)`, from the specified path(s) (local or cloud). Multiple
+    * documents are concatenated vertically. Compressed inputs (gzip, zlib and zstd) are detected
+    * from their content and decompressed.
+    *
+    * Supported options:
+    *   - `read_json_schema`: Full schema of the documents, in the format returned by
+    *     `schemaJson`. Skips schema inference. Default: `null`.
+    *   - `read_json_schema_overrides`: JSON object mapping column names to data types that
+    *     override the given or inferred ones, e.g. `{"a": "int64", "b": "datetime[ms]"}`.
+    *     Default: `null`.
+    *   - `read_json_infer_schema_length`: Number of records used to infer the schema, `0` to
+    *     use all records. Default: `100`.
+    *   - `read_json_ignore_errors`: Continues parsing despite errors in some records. Only
+    *     supported with the `json_lines` format. Default: `false`.
+    *   - `read_json_format`: `json` for whole documents or `json_lines` for NDJSON. Default:
+    *     `json`.
+    *   - `read_json_columns`: Comma separated names of the columns to read. Default: `null`.
+    *   - `read_json_n_rows`: Maximum number of rows to return. Default: `null`.
+    *   - `read_json_row_index_name`: Adds a row index column with the specified name. Default:
+    *     `null`.
+    *   - `read_json_row_index_offset`: Offset (≥0) for row index column (used only if
+    *     `read_json_row_index_name` is set). Default: `0`.
+    *   - `read_json_rechunk`: Re-chunks the final DataFrame for memory contiguity. Default:
+    *     `false`.
+    *
+    * @param path
+    *   Main input file location.
+    * @param paths
+    *   Additional input file locations.
+    *
+    * @note
+    *   All provided paths must belong to the same object store.
+    */
+  @varargs
+  def json(path: String, paths: String*): DataFrame =
+    DataFrame.withPtr(readJson(paths.+:(path).toArray[String], null, optionsJson))
+
+  /** Reads whole JSON documents from in-memory buffers, with the same options as the path-based
+    * `json`.
+    *
+    * @param buffer
+    *   Main input document.
+    * @param buffers
+    *   Additional input documents.
+    */
+  @varargs
+  def json(buffer: Array[Byte], buffers: Array[Byte]*): DataFrame =
+    DataFrame.withPtr(readJson(null, toBuffers(buffer, buffers), optionsJson))
+
+  /** Reads whole JSON documents from the remaining bytes of `ByteBuffer`s, with the same options
+    * as the path-based `json`.
+    *
+    * @param buffer
+    *   Main input document.
+    * @param buffers
+    *   Additional input documents.
+    */
+  @varargs
+  def json(buffer: ByteBuffer, buffers: ByteBuffer*): DataFrame =
+    DataFrame.withPtr(readJson(null, toBuffers(buffer, buffers), optionsJson))
+}
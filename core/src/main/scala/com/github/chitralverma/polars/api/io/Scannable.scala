@@ -1,15 +1,23 @@
 package com.github.chitralverma.polars.api.io
 
+import java.nio.ByteBuffer
+
 import scala.annotation.varargs
 import scala.collection.mutable.{Map => MutableMap}
 import scala.jdk.CollectionConverters._
 
 import com.github.chitralverma.polars.Polars
 import com.github.chitralverma.polars.api.LazyFrame
+import com.github.chitralverma.polars.api.io.Scannable.toBuffers
 import com.github.chitralverma.polars.internal.jni.io.scan._
 
 /** Interface used to scan datasets of various formats from local filesystems and cloud object
-  * stores (aws, gcp and azure). Use [[Polars.scan scan()]] to access this.
+  * stores (aws, gcp and azure), or from in-memory buffers. Use [[Polars.scan scan()]] to access
+  * this.
+  *
+  * In-memory buffers are given either as byte arrays, which are copied, or as `ByteBuffer`s whose
+  * remaining bytes are read. Direct `ByteBuffer`s are read in place, so they must not be modified
+  * while the resulting [[LazyFrame]] is in use.
   *
   * Cloud options are global and can be set by methods like [[option option[s]()]]
   *   - For amazon s3 options, see
@@ -46,6 +54,8 @@ class Scannable private[polars] () {
     this
   }
 
+  private def optionsJson: String = jsonMapper.writeValueAsString(_options)
+
   /** Scans a dataset in Parquet format from the specified path(s) (local or cloud). Supports
     * globbing and path expansion.
     *
@@ -91,10 +101,35 @@ class Scannable private[polars] () {
     LazyFrame.withPtr(
       scanParquet(
         paths = paths.+:(path).toArray[String],
+        buffers = null,
         options = jsonMapper.writeValueAsString(_options)
       )
     )
 
+  /** Scans a dataset in Parquet format from in-memory buffers, with the same options as the
+    * path-based `parquet`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def parquet(buffer: Array[Byte], buffers: Array[Byte]*): LazyFrame =
+    LazyFrame.withPtr(scanParquet(null, toBuffers(buffer, buffers), optionsJson))
+
+  /** Scans a dataset in Parquet format from the remaining bytes of `ByteBuffer`s, with the same
+    * options as the path-based `parquet`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def parquet(buffer: ByteBuffer, buffers: ByteBuffer*): LazyFrame =
+    LazyFrame.withPtr(scanParquet(null, toBuffers(buffer, buffers), optionsJson))
+
   /** Scans a dataset in IPC format from the specified path(s) (local or cloud). Supports globbing
     * and path expansion.
     *
@@ -125,12 +160,38 @@ class Scannable private[polars] () {
     LazyFrame.withPtr(
       scanIPC(
         paths = paths.+:(path).toArray[String],
+        buffers = null,
         options = jsonMapper.writeValueAsString(_options)
       )
     )
 
+  /** Scans a dataset in IPC format from in-memory buffers, with the same options as the
+    * path-based `ipc`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def ipc(buffer: Array[Byte], buffers: Array[Byte]*): LazyFrame =
+    LazyFrame.withPtr(scanIPC(null, toBuffers(buffer, buffers), optionsJson))
+
+  /** Scans a dataset in IPC format from the remaining bytes of `ByteBuffer`s, with the same
+    * options as the path-based `ipc`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def ipc(buffer: ByteBuffer, buffers: ByteBuffer*): LazyFrame =
+    LazyFrame.withPtr(scanIPC(null, toBuffers(buffer, buffers), optionsJson))
+
   /** Scans a dataset in CSV format from the specified path(s) (local or cloud). Supports globbing
-    * and path expansion.
+    * and path expansion. Compressed files (gzip, zlib and zstd, e.g. `.csv.gz`) are detected from
+    * their content and decompressed.
     *
     * Supported options:
     *   - `scan_csv_n_rows`: Maximum number of rows to read. Default: `null`.
@@ -170,7 +231,22 @@ class Scannable private[polars] () {
     *   - `scan_csv_quote_char`: Quote character for values. Default: `"`.
     *   - `scan_csv_eol_char`: End-of-line character. Default: `\n`.
     *   - `scan_csv_null_value`: Value to interpret as null. Default: `null`.
+    *   - `scan_csv_null_values`: Values to interpret as null, either a JSON array of values for
+    *     all columns, e.g. `["NA", ""]`, or a JSON object mapping column names to their null
+    *     value, e.g. `{"zip": "00000"}`. Takes precedence over `scan_csv_null_value`. Default:
+    *     `null`.
     *   - `scan_csv_comment_prefix`: Prefix for comment lines. Default: `null`.
+    *   - `scan_csv_schema`: Full schema of the files, in the format returned by `schemaJson`.
+    *     Skips schema inference. Default: `null`.
+    *   - `scan_csv_schema_overrides`: JSON object mapping column names to data types that
+    *     override the given or inferred ones, e.g. `{"zip": "string", "id": "int64"}`. Default:
+    *     `null`.
+    *   - `scan_csv_dtypes`: JSON array of data types applied to the columns by position, e.g.
+    *     `["string", "int64"]`. Only for files without a header. Default: `null`.
+    *   - `scan_csv_columns`: Comma separated names of the columns to read. Default: `null`.
+    *   - `scan_csv_compression`: Compression of the input, 'auto' to detect it from the
+    *     content, or one of 'uncompressed', 'gzip', 'zlib', 'zstd' to also check that in-memory
+    *     and local file inputs use it. Default: auto.
     *
     * @param path
     *   Main input file location.
@@ -185,12 +261,38 @@ class Scannable private[polars] () {
     LazyFrame.withPtr(
       scanCSV(
         paths = paths.+:(path).toArray[String],
+        buffers = null,
         options = jsonMapper.writeValueAsString(_options)
       )
     )
 
+  /** Scans a dataset in CSV format from in-memory buffers, with the same options as the
+    * path-based `csv`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def csv(buffer: Array[Byte], buffers: Array[Byte]*): LazyFrame =
+    LazyFrame.withPtr(scanCSV(null, toBuffers(buffer, buffers), optionsJson))
+
+  /** Scans a dataset in CSV format from the remaining bytes of `ByteBuffer`s, with the same
+    * options as the path-based `csv`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def csv(buffer: ByteBuffer, buffers: ByteBuffer*): LazyFrame =
+    LazyFrame.withPtr(scanCSV(null, toBuffers(buffer, buffers), optionsJson))
+
   /** Scans the contents of a dataset in Newline Delimited JSON (NDJSON) format from the specified
-    * path(s) (local and cloud). Provided paths support globbing and expansion.
+    * path(s) (local and cloud). Provided paths support globbing and expansion. Compressed files
+    * (gzip, zlib and zstd, e.g. `.ndjson.zst`) are detected from their content and decompressed.
     *
     * Supported options:
     *   - `scan_ndjson_n_rows`: Maximum number of rows to read. Default: `null`.
@@ -210,6 +312,15 @@ class Scannable private[polars] () {
     *     Reduce this for memory efficiency at the cost of performance. Default: `null`
     *   - `scan_ndjson_infer_schema_length`: Number of rows to use for schema inference. Default:
     *     `100`.
+    *   - `scan_ndjson_schema`: Full schema of the files, in the format returned by `schemaJson`.
+    *     Skips schema inference. Default: `null`.
+    *   - `scan_ndjson_schema_overrides`: JSON object mapping column names to data types that
+    *     override the given or inferred ones, e.g. `{"zip": "string", "id": "int64"}`. Default:
+    *     `null`.
+    *   - `scan_ndjson_columns`: Comma separated names of the columns to read. Default: `null`.
+    *   - `scan_ndjson_compression`: Compression of the input, 'auto' to detect it from the
+    *     content, or one of 'uncompressed', 'gzip', 'zlib', 'zstd' to also check that in-memory
+    *     and local file inputs use it. Default: auto.
     *
     * @param path
     *   Main input file location.
@@ -223,7 +334,106 @@ class Scannable private[polars] () {
     LazyFrame.withPtr(
       scanJsonLines(
         paths = paths.+:(path).toArray[String],
+        buffers = null,
         options = jsonMapper.writeValueAsString(_options)
       )
     )
+
+  /** Scans a dataset in NDJSON format from in-memory buffers, with the same options as the
+    * path-based `jsonLines`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def jsonLines(buffer: Array[Byte], buffers: Array[Byte]*): LazyFrame =
+    LazyFrame.withPtr(scanJsonLines(null, toBuffers(buffer, buffers), optionsJson))
+
+  /** Scans a dataset in NDJSON format from the remaining bytes of `ByteBuffer`s, with the same
+    * options as the path-based `jsonLines`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def jsonLines(buffer: ByteBuffer, buffers: ByteBuffer*): LazyFrame =
+    LazyFrame.withPtr(scanJsonLines(null, toBuffers(buffer, buffers), optionsJson))
+
+  /** Scans plain text from the specified path(s) (local and cloud) as a single string column
+    * with one row per line, e.g. to parse custom log formats with the string expressions.
+    * Provided paths support globbing and expansion. Compressed inputs (gzip, zlib and zstd) are
+    * detected from their content and decompressed.
+    *
+    * Supported options:
+    *   - `scan_lines_name`: Name of the string column. Default: `lines`.
+    *   - `scan_lines_n_rows`: Maximum number of lines to read. Default: `null`.
+    *   - `scan_lines_cache`: Caches the scan result. Default: `true`.
+    *   - `scan_lines_glob`: Expands globs in the provided paths. Default: `true`.
+    *   - `scan_lines_rechunk`: Re-chunks the final DataFrame for memory contiguity when reading
+    *     multiple files. Default: `false`.
+    *   - `scan_lines_row_index_name`: Adds a row index column with the specified name. Default:
+    *     `null`.
+    *   - `scan_lines_row_index_offset`: Offset (≥0) for row index column (used only if
+    *     `scan_lines_row_index_name` is set). Default: `0`.
+    *   - `scan_lines_include_file_paths`: Includes source file paths as a column with the
+    *     specified name. Default: `null`.
+    *
+    * @param path
+    *   Main input file location.
+    * @param paths
+    *   Additional input file locations.
+    * @note
+    *   All provided paths must belong to the same object store.
+    */
+  @varargs
+  def lines(path: String, paths: String*): LazyFrame =
+    LazyFrame.withPtr(scanLines(paths.+:(path).toArray[String], null, optionsJson))
+
+  /** Scans plain text from in-memory buffers, with the same options as the path-based `lines`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def lines(buffer: Array[Byte], buffers: Array[Byte]*): LazyFrame =
+    LazyFrame.withPtr(scanLines(null, toBuffers(buffer, buffers), optionsJson))
+
+  /** Scans plain text from the remaining bytes of `ByteBuffer`s, with the same options as the
+    * path-based `lines`.
+    *
+    * @param buffer
+    *   Main input file content.
+    * @param buffers
+    *   Additional input file contents.
+    */
+  @varargs
+  def lines(buffer: ByteBuffer, buffers: ByteBuffer*): LazyFrame =
+    LazyFrame.withPtr(scanLines(null, toBuffers(buffer, buffers), optionsJson))
+}
+
+private[io] object Scannable {
+
+  /** Collects in-memory sources for the native readers: byte arrays as they are, and the
+    * remaining bytes of `ByteBuffer`s, as a slice for direct buffers or copied otherwise.
+    */
+  def toBuffers(buffer: Array[Byte], buffers: Seq[Array[Byte]]): Array[Object] =
+    buffers.+:(buffer).toArray[Object]
+
+  def toBuffers(buffer: ByteBuffer, buffers: Seq[ByteBuffer]): Array[Object] =
+    buffers.+:(buffer).toArray.map { b =>
+      val source: Object =
+        if (b.isDirect) b
+        else {
+          val bytes = new Array[Byte](b.remaining())
+          b.duplicate().get(bytes)
+          bytes
+        }
+      source
+    }
 }
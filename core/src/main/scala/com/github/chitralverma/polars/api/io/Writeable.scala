@@ -7,7 +7,8 @@ import com.github.chitralverma.polars.api.DataFrame
 import com.github.chitralverma.polars.internal.jni.io.write._
 
 /** Interface used to write a [[DataFrame]] in various formats to local filesystems and cloud
-  * object stores (aws, gcp and azure). Use [[DataFrame.write write()]] to access this.
+  * object stores (aws, gcp and azure), or into memory. Use [[DataFrame.write write()]] to access
+  * this.
   *
   * Cloud options are global and can be set by methods like [[option option[s]()]]
   *   - For amazon s3 options, see
@@ -26,11 +27,6 @@ import com.github.chitralverma.polars.internal.jni.io.write._
 class Writeable private[polars] (df: DataFrame) {
   import com.github.chitralverma.polars.jsonMapper
 
-  private def ptr: Long = {
-    df.checkClosed()
-    df.ptr
-  }
-
   private val _options: MutableMap[String, String] = MutableMap("write_mode" -> "error")
 
   /** Adds options for the underlying output format. */
@@ -68,20 +64,56 @@ class Writeable private[polars] (df: DataFrame) {
     *   - `write_compression`: Sets the compression codec used for pages, for more compatibility
     *     guarantees, consider using Snappy. Supported values 'uncompressed', 'snappy', 'gzip',
     *     'brotli', 'lz4', 'zstd'. Default: zstd.
-    *   - `write_compression_level`: Sets a valid level for codecs like 'gzip', 'brotli', 'zstd'.
-    *     Defaults to compression default.
+    *   - `write_compression_level`: Sets the level for the codec ('gzip': 0-9, 'brotli': 0-11,
+    *     'zstd': 1-22). Unknown codecs and levels outside these ranges are an error. Defaults to
+    *     compression default.
     *   - `write_parquet_stats`: Allows computation and writing of column statistics. Supported
     *     values 'full', 'none', 'some'. Default: some
+    *   - `write_parquet_metadata`: Custom file-level key-value metadata, as a JSON object of
+    *     string values, e.g. `{"source": "orders", "version": "3"}`. Default: `null`.
+    *   - `write_parquet_sorting_columns`: Declares the order of the rows in every row group, as
+    *     a JSON array of column names sorted ascending with nulls last, or of objects like
+    *     `{"column": "a", "descending": true, "nulls_first": true}`. The order is recorded, not
+    *     checked, and nested columns cannot be listed. Default: `null`.
+    *   - `write_parquet_field_overwrites`: Per-column settings, as a JSON object mapping column
+    *     names to objects with an `encoding` ('plain', 'rle', 'delta_binary_packed',
+    *     'delta_length_byte_array'), `dictionary` (true or false), `compression` and
+    *     `compression_level`, e.g. `{"id": {"encoding": "delta_binary_packed"}, "body":
+    *     {"compression": "gzip"}}`. Encodings cannot be set for nested columns. Default: `null`.
+    *   - `write_parquet_int96_columns`: Comma-separated datetime columns stored as INT96
+    *     timestamps with nanosecond precision, as Spark and Hive write them. Files with INT96
+    *     columns have no column statistics for them and no page index. Default: `null`.
+    *   - `write_parquet_spark_compat`: Writes files that Spark and Hive readers accept by using
+    *     the legacy list layout and storing nanosecond timestamps, including those nested in
+    *     lists and structs, with microsecond precision. Sub-microsecond precision is truncated
+    *     with a warning, except in `write_parquet_int96_columns`. Default: `false`.
     *
     * @param filePath
     *   output file location
     */
   def parquet(filePath: String): Unit =
-    writeParquet(
-      ptr = ptr,
-      filePath = filePath,
-      options = jsonMapper.writeValueAsString(_options)
-    )
+    df.locked { ptr =>
+      writeParquet(
+        ptr = ptr,
+        filePath = filePath,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
+
+  /** Encodes the content of the [[DataFrame]] in Parquet format into memory, with the same
+    * options as [[parquet]] except `write_mode`.
+    *
+    * @return
+    *   the encoded bytes
+    */
+  def parquetBytes(): Array[Byte] =
+    df.locked { ptr =>
+      writeParquet(
+        ptr = ptr,
+        filePath = null,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
 
   /** Saves the content of the [[DataFrame]] in IPC format at the specified path (local and
     * cloud).
@@ -98,11 +130,60 @@ class Writeable private[polars] (df: DataFrame) {
     *   output file location
     */
   def ipc(filePath: String): Unit =
-    writeIPC(
-      ptr = ptr,
-      filePath = filePath,
-      options = jsonMapper.writeValueAsString(_options)
-    )
+    df.locked { ptr =>
+      writeIPC(
+        ptr = ptr,
+        filePath = filePath,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
+
+  /** Encodes the content of the [[DataFrame]] in IPC format into memory, with the same options
+    * as [[ipc]] except `write_mode`.
+    *
+    * @return
+    *   the encoded bytes
+    */
+  def ipcBytes(): Array[Byte] =
+    df.locked { ptr =>
+      writeIPC(
+        ptr = ptr,
+        filePath = null,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
+
+  /** Saves the content of the [[DataFrame]] in the Arrow IPC stream format at the specified path
+    * (local and cloud), with the same options as [[ipc]].
+    *
+    * To write several DataFrames to one stream, see [[IpcStreamWriter]].
+    *
+    * @param filePath
+    *   output file location
+    */
+  def ipcStream(filePath: String): Unit =
+    df.locked { ptr =>
+      writeIPCStream(
+        ptr = ptr,
+        filePath = filePath,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
+
+  /** Encodes the content of the [[DataFrame]] in the Arrow IPC stream format into memory, with
+    * the same options as [[ipc]] except `write_mode`.
+    *
+    * @return
+    *   the encoded bytes
+    */
+  def ipcStreamBytes(): Array[Byte] =
+    df.locked { ptr =>
+      writeIPCStream(
+        ptr = ptr,
+        filePath = null,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
 
   /** Saves the content of the [[DataFrame]] in Avro format at the specified path (local and
     * cloud).
@@ -116,11 +197,28 @@ class Writeable private[polars] (df: DataFrame) {
     *   output file location
     */
   def avro(filePath: String): Unit =
-    writeAvro(
-      ptr = ptr,
-      filePath = filePath,
-      options = jsonMapper.writeValueAsString(_options)
-    )
+    df.locked { ptr =>
+      writeAvro(
+        ptr = ptr,
+        filePath = filePath,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
+
+  /** Encodes the content of the [[DataFrame]] in Avro format into memory, with the same options
+    * as [[avro]] except `write_mode`.
+    *
+    * @return
+    *   the encoded bytes
+    */
+  def avroBytes(): Array[Byte] =
+    df.locked { ptr =>
+      writeAvro(
+        ptr = ptr,
+        filePath = null,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
 
   /** Saves the content of the [[DataFrame]] in CSV format at the specified path (local and
     * cloud).
@@ -162,39 +260,82 @@ class Writeable private[polars] (df: DataFrame) {
     *     - non_numeric: This puts quotes around all fields that are non-numeric. Namely, when
     *       writing a field that does not parse as a valid float or integer, then quotes will be
     *       used even if they aren't strictly necessary.
+    *   - `write_compression`: Compresses the whole output. Supported values 'uncompressed',
+    *     'gzip', 'zstd'. Default: uncompressed.
+    *   - `write_compression_level`: Sets the level for the codec ('gzip': 0-9, 'zstd': 1-22).
+    *     Unknown codecs and levels outside these ranges are an error. Defaults to compression
+    *     default.
     *
-    * @note
-    *   compression is not supported for this format.
     * @param filePath
     *   output file location
     */
   def csv(filePath: String): Unit =
-    writeCSV(
-      ptr = ptr,
-      filePath = filePath,
-      options = jsonMapper.writeValueAsString(_options)
-    )
+    df.locked { ptr =>
+      writeCSV(
+        ptr = ptr,
+        filePath = filePath,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
+
+  /** Encodes the content of the [[DataFrame]] in CSV format into memory, with the same options
+    * as [[csv]] except `write_mode`.
+    *
+    * @return
+    *   the encoded bytes
+    */
+  def csvBytes(): Array[Byte] =
+    df.locked { ptr =>
+      writeCSV(
+        ptr = ptr,
+        filePath = null,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
 
   /** Saves the content of the [[DataFrame]] in JSON format at the specified path (local and
     * cloud).
     *
     * A single JSON array containing each DataFrame row as an object. The length of the array is
     * the number of rows in the DataFrame. Use this to create valid JSON that can be deserialized
-    * back into an array in one fell swoop.
+    * back into an array in one fell swoop, e.g. with `Polars.read.json`.
     *
-    * @note
-    *   compression is not supported for this format.
+    * Supported options:
+    *   - `write_compression`: Compresses the whole output. Supported values 'uncompressed',
+    *     'gzip', 'zstd'. Default: uncompressed.
+    *   - `write_compression_level`: Sets the level for the codec ('gzip': 0-9, 'zstd': 1-22).
+    *     Unknown codecs and levels outside these ranges are an error. Defaults to compression
+    *     default.
     *
     * @param filePath
     *   output file location
     */
   def json(filePath: String): Unit = {
     option("write_json_format", "json")
-    writeJson(
-      ptr = ptr,
-      filePath = filePath,
-      options = jsonMapper.writeValueAsString(_options)
-    )
+    df.locked { ptr =>
+      writeJson(
+        ptr = ptr,
+        filePath = filePath,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
+  }
+
+  /** Encodes the content of the [[DataFrame]] in JSON format into memory, with the same options
+    * as [[json]] except `write_mode`.
+    *
+    * @return
+    *   the encoded bytes
+    */
+  def jsonBytes(): Array[Byte] = {
+    option("write_json_format", "json")
+    df.locked { ptr =>
+      writeJson(
+        ptr = ptr,
+        filePath = null,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
   }
 
   /** Saves the content of the [[DataFrame]] in Newline Delimited JSON (ndjson) format at the
@@ -208,17 +349,41 @@ class Writeable private[polars] (df: DataFrame) {
     * output in its entirety is not valid JSON; only the individual lines are. It is recommended
     * to use the file extension `.jsonl` when saving as JSON Lines.
     *
-    * @note
-    *   compression is not supported for this format.
+    * Supported options:
+    *   - `write_compression`: Compresses the whole output. Supported values 'uncompressed',
+    *     'gzip', 'zstd'. Default: uncompressed.
+    *   - `write_compression_level`: Sets the level for the codec ('gzip': 0-9, 'zstd': 1-22).
+    *     Unknown codecs and levels outside these ranges are an error. Defaults to compression
+    *     default.
+    *
     * @param filePath
     *   output file location
     */
   def jsonLines(filePath: String): Unit = {
     option("write_json_format", "json_lines")
-    writeJson(
-      ptr = ptr,
-      filePath = filePath,
-      options = jsonMapper.writeValueAsString(_options)
-    )
+    df.locked { ptr =>
+      writeJson(
+        ptr = ptr,
+        filePath = filePath,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
+  }
+
+  /** Encodes the content of the [[DataFrame]] in NDJSON format into memory, with the same options
+    * as [[jsonLines]] except `write_mode`.
+    *
+    * @return
+    *   the encoded bytes
+    */
+  def jsonLinesBytes(): Array[Byte] = {
+    option("write_json_format", "json_lines")
+    df.locked { ptr =>
+      writeJson(
+        ptr = ptr,
+        filePath = null,
+        options = jsonMapper.writeValueAsString(_options)
+      )
+    }
   }
 }
@@ -1,8 +1,9 @@
 package com.github.chitralverma.polars
 
 import java.time.format.DateTimeFormatter
-import java.time.{LocalDate, LocalTime, ZonedDateTime}
+import java.time.{Instant, LocalDate, LocalTime, ZoneId, ZoneOffset, ZonedDateTime}
 
+import com.github.chitralverma.polars.api.Series
 import com.github.chitralverma.polars.api.expressions.{Column, Expression, When}
 import com.github.chitralverma.polars.internal.jni.expressions.{column_expr, literal_expr}
 
@@ -16,6 +17,8 @@ object functions {
       val ptr = value match {
         case null => literal_expr.nullLit()
         case v: Boolean => literal_expr.fromBool(v)
+        case v: Byte => literal_expr.fromInt8(v)
+        case v: Short => literal_expr.fromInt16(v)
         case v: Int => literal_expr.fromInt(v)
         case v: Long => literal_expr.fromLong(v)
         case v: Float => literal_expr.fromFloat(v)
@@ -26,6 +29,14 @@ object functions {
           literal_expr.fromTime(DateTimeFormatter.ISO_LOCAL_TIME.format(v))
         case v: ZonedDateTime =>
           literal_expr.fromDateTime(DateTimeFormatter.ISO_LOCAL_DATE_TIME.format(v))
+        case v: Instant =>
+          literal_expr.fromEpochDateTime(toNanos(v.getEpochSecond, v.getNano, v), "ns", "UTC")
+        case v: java.time.Duration =>
+          literal_expr.fromDuration(toNanos(v.getSeconds, v.getNano, v), "ns")
+        case v: java.math.BigDecimal => decimalLit(v)
+        case v: BigDecimal => decimalLit(v.bigDecimal)
+        case v: Array[Byte] => literal_expr.fromBinary(v)
+        case v: Series => literal_expr.fromSeries(v.ptr)
         case v: String => literal_expr.fromString(v)
         case _ =>
           throw new IllegalArgumentException(
@@ -36,6 +47,87 @@ object functions {
       Expression.withPtr(ptr)
   }
 
+  private def toNanos(seconds: Long, nanos: Int, value: Any): Long =
+    try Math.addExact(Math.multiplyExact(seconds, 1000000000L), nanos.toLong)
+    catch {
+      case e: ArithmeticException =>
+        throw new IllegalArgumentException(
+          s"Value `$value` is out of range for nanosecond precision.",
+          e
+        )
+    }
+
+  /** polars has no `Z` zone, and fixed offsets are only recognised in their `+hh:mm` form. */
+  private def zoneName(zone: ZoneId): String = zone.normalized() match {
+    case ZoneOffset.UTC => "UTC"
+    case z => z.getId
+  }
+
+  private def decimalLit(value: java.math.BigDecimal): Long = {
+    // polars decimals cannot have a negative scale, e.g. `1E+3`
+    val v = if (value.scale() < 0) value.setScale(0) else value
+    literal_expr.fromDecimal(v.unscaledValue().toString, v.precision().max(v.scale()), v.scale())
+  }
+
+  /** Create a datetime literal by parsing `value` with a chrono `format` (e.g.
+    * `%Y-%m-%d %H:%M:%S%z`).
+    *
+    * @param timeUnit
+    *   one of `ns`, `us` or `ms`
+    * @param timeZone
+    *   time zone of the literal; values parsed without an offset are read as wall-clock time in
+    *   this zone
+    */
+  def litDateTime(
+      value: String,
+      format: String,
+      timeUnit: String = "us",
+      timeZone: String = null
+  ): Expression =
+    Expression.withPtr(literal_expr.fromDateTimeWithOptions(value, format, timeUnit, timeZone))
+
+  /** Create a nanosecond datetime literal in the zone of `value`. Unlike `lit`, which drops the
+    * zone and keeps the wall-clock time, this keeps both the instant and the zone.
+    */
+  def litDateTime(value: ZonedDateTime): Expression = {
+    val instant = value.toInstant
+    Expression.withPtr(
+      literal_expr.fromEpochDateTime(
+        toNanos(instant.getEpochSecond, instant.getNano, value),
+        "ns",
+        zoneName(value.getZone)
+      )
+    )
+  }
+
+  /** Create a single list literal holding all values of `series`. */
+  def litList(series: Series): Expression = Expression.withPtr(literal_expr.fromList(series.ptr))
+
+  /** Create a single fixed-size array literal holding all values of `series`. */
+  def litArray(series: Series): Expression =
+    Expression.withPtr(literal_expr.fromArray(series.ptr))
+
+  /** Create a struct from named fields, each given as an expression or a literal value. */
+  @annotation.varargs
+  def litStruct(fields: (String, Any)*): Expression = {
+    val (names, values) = fields.unzip
+    Expression.withPtr(literal_expr.fromStruct(names.toArray, values.map(lit(_).ptr).toArray))
+  }
+
+  /** Create an unsigned 8-bit literal; `value` must be between `0` and `255`. */
+  def litUInt8(value: Int): Expression = Expression.withPtr(literal_expr.fromUInt8(value))
+
+  /** Create an unsigned 16-bit literal; `value` must be between `0` and `65535`. */
+  def litUInt16(value: Int): Expression = Expression.withPtr(literal_expr.fromUInt16(value))
+
+  /** Create an unsigned 32-bit literal; `value` must be between `0` and `4294967295`. */
+  def litUInt32(value: Long): Expression = Expression.withPtr(literal_expr.fromUInt32(value))
+
+  /** Create an unsigned 64-bit literal from the bits of `value`, so negative values stand for
+    * those above `Long.MaxValue` as with `java.lang.Long.toUnsignedString`.
+    */
+  def litUInt64(value: Long): Expression = Expression.withPtr(literal_expr.fromUInt64(value))
+
   /** Start a `when / otherwise` conditional expression.
     *
     * Provide a condition and the value to use where that condition is true. The returned
@@ -6,6 +6,8 @@ private[polars] object data_frame extends Natively {
 
   @native def schemaString(ptr: Long): String
 
+  @native def schemaJson(ptr: Long): String
+
   @native def toLazy(ptr: Long): Long
 
   @native def show(ptr: Long): Unit
@@ -18,6 +20,43 @@ private[polars] object data_frame extends Natively {
 
   @native def fromSeries(ptrs: Array[Long]): Long
 
+  @native def fromRows(
+      rows: Array[Array[Object]],
+      names: Array[String],
+      dataTypes: Array[String],
+      inferLength: Int
+  ): Long
+
+  @native def shape(ptr: Long): Array[Long]
+
+  @native def columnNames(ptr: Long): Array[String]
+
+  @native def dtypes(ptr: Long): Array[String]
+
+  @native def getColumn(ptr: Long, name: String): Long
+
+  @native def getColumnAt(ptr: Long, index: Int): Long
+
+  @native def getColumns(ptr: Long): Array[Long]
+
+  @native def row(ptr: Long, index: Long): Array[Object]
+
+  @native def selectColumns(ptr: Long, names: Array[String]): Long
+
+  @native def dropInPlace(ptr: Long, name: String): Long
+
+  @native def rename(ptr: Long, oldName: String, newName: String): Long
+
+  @native def hstack(ptr: Long, seriesPtrs: Array[Long]): Long
+
+  @native def vstack(ptr: Long, otherPtr: Long): Long
+
+  @native def extend(ptr: Long, otherPtr: Long): Unit
+
+  @native def withColumn(ptr: Long, seriesPtr: Long): Long
+
+  @native def equals(ptr: Long, otherPtr: Long, nullEqual: Boolean): Boolean
+
   @native def free(ptr: Long): Unit
 
 }
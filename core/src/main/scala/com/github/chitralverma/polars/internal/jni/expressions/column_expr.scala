@@ -14,6 +14,13 @@ private[polars] object column_expr extends Natively {
 
   @native def cast(ptr: Long, dataType: String): Long
 
+  @native def castWithOptions(
+      ptr: Long,
+      dataType: String,
+      strict: Boolean,
+      wrapNumerical: Boolean
+  ): Long
+
   @native def isIn(ptr: Long, values: Array[Any]): Long
 
   @native def isBetween(ptr: Long, lower: Any, upper: Any): Long
@@ -178,6 +185,14 @@ private[polars] object column_expr extends Natively {
 
   @native def reinterpret(ptr: Long, signed: Boolean): Long
 
+  @native def serializeJson(ptr: Long): String
+
+  @native def serializeBinary(ptr: Long): Array[Byte]
+
+  @native def deserializeJson(json: String): Long
+
+  @native def deserializeBinary(bytes: Array[Byte]): Long
+
   @native def free(ptr: Long): Unit
 
 }
@@ -14,6 +14,18 @@ private[polars] object literal_expr extends Natively {
 
   @native def fromLong(value: Long): Long
 
+  @native def fromInt8(value: Byte): Long
+
+  @native def fromInt16(value: Short): Long
+
+  @native def fromUInt8(value: Int): Long
+
+  @native def fromUInt16(value: Int): Long
+
+  @native def fromUInt32(value: Long): Long
+
+  @native def fromUInt64(value: Long): Long
+
   @native def fromFloat(value: Float): Long
 
   @native def fromDouble(value: Double): Long
@@ -24,4 +36,27 @@ private[polars] object literal_expr extends Natively {
 
   @native def fromDateTime(value: String): Long
 
+  @native def fromDateTimeWithOptions(
+      value: String,
+      format: String,
+      timeUnit: String,
+      timeZone: String
+  ): Long
+
+  @native def fromEpochDateTime(value: Long, timeUnit: String, timeZone: String): Long
+
+  @native def fromDuration(value: Long, timeUnit: String): Long
+
+  @native def fromDecimal(unscaled: String, precision: Int, scale: Int): Long
+
+  @native def fromBinary(value: Array[Byte]): Long
+
+  @native def fromSeries(seriesPtr: Long): Long
+
+  @native def fromList(seriesPtr: Long): Long
+
+  @native def fromArray(seriesPtr: Long): Long
+
+  @native def fromStruct(names: Array[String], values: Array[Long]): Long
+
 }
@@ -0,0 +1,15 @@
+package com.github.chitralverma.polars.internal.jni.io
+
+import com.github.chitralverma.polars.internal.jni.Natively
+
+private[polars] object ipc_stream_writer extends Natively {
+
+  @native def open(filePath: String, options: String): Long
+
+  @native def write(ptr: Long, dfPtr: Long): Unit
+
+  @native def finish(ptr: Long): Array[Byte]
+
+  @native def free(ptr: Long): Unit
+
+}
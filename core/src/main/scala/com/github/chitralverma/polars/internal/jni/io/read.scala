@@ -0,0 +1,25 @@
+package com.github.chitralverma.polars.internal.jni.io
+
+import com.github.chitralverma.polars.internal.jni.Natively
+
+private[polars] object read extends Natively {
+
+  @native def readIPCStream(
+      paths: Array[String],
+      buffers: Array[Object],
+      options: String
+  ): Long
+
+  @native def readAvro(
+      paths: Array[String],
+      buffers: Array[Object],
+      options: String
+  ): Long
+
+  @native def readJson(
+      paths: Array[String],
+      buffers: Array[Object],
+      options: String
+  ): Long
+
+}
@@ -4,12 +4,34 @@ import com.github.chitralverma.polars.internal.jni.Natively
 
 private[polars] object scan extends Natively {
 
-  @native def scanParquet(paths: Array[String], options: String): Long
+  @native def scanParquet(
+      paths: Array[String],
+      buffers: Array[Object],
+      options: String
+  ): Long
 
-  @native def scanIPC(paths: Array[String], options: String): Long
+  @native def scanIPC(
+      paths: Array[String],
+      buffers: Array[Object],
+      options: String
+  ): Long
 
-  @native def scanCSV(paths: Array[String], options: String): Long
+  @native def scanCSV(
+      paths: Array[String],
+      buffers: Array[Object],
+      options: String
+  ): Long
 
-  @native def scanJsonLines(paths: Array[String], options: String): Long
+  @native def scanJsonLines(
+      paths: Array[String],
+      buffers: Array[Object],
+      options: String
+  ): Long
+
+  @native def scanLines(
+      paths: Array[String],
+      buffers: Array[Object],
+      options: String
+  ): Long
 
 }
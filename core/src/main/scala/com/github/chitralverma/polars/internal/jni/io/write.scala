@@ -8,30 +8,36 @@ object write extends Natively {
       ptr: Long,
       filePath: String,
       options: String
-  ): Unit
+  ): Array[Byte]
 
   @native def writeIPC(
       ptr: Long,
       filePath: String,
       options: String
-  ): Unit
+  ): Array[Byte]
+
+  @native def writeIPCStream(
+      ptr: Long,
+      filePath: String,
+      options: String
+  ): Array[Byte]
 
   @native def writeAvro(
       ptr: Long,
       filePath: String,
       options: String
-  ): Unit
+  ): Array[Byte]
 
   @native def writeCSV(
       ptr: Long,
       filePath: String,
       options: String
-  ): Unit
+  ): Array[Byte]
 
   @native def writeJson(
       ptr: Long,
       filePath: String,
       options: String
-  ): Unit
+  ): Array[Byte]
 
 }
@@ -6,6 +6,10 @@ private[polars] object lazy_frame extends Natively {
 
   @native def schemaString(ptr: Long): String
 
+  @native def schemaJson(ptr: Long): String
+
+  @native def castToSchema(ptr: Long, schemaJson: String, strict: Boolean): Long
+
   @native def selectFromStrings(ptr: Long, cols: Array[String]): Long
 
   @native def selectFromExprs(ptr: Long, exprs: Array[Long]): Long
@@ -43,6 +47,14 @@ private[polars] object lazy_frame extends Natively {
 
   @native def explain(ptr: Long, optimized: Boolean, tree_format: Boolean): String
 
+  @native def toDot(ptr: Long, optimized: Boolean): String
+
+  @native def planTree(ptr: Long, optimized: Boolean): String
+
+  @native def profile(ptr: Long): Array[Long]
+
+  @native def profileStreaming(ptr: Long): Array[Long]
+
   @native def setSorted(
       ptr: Long,
       column: String,
@@ -54,6 +66,17 @@ private[polars] object lazy_frame extends Natively {
 
   @native def collect(ptr: Long): Long
 
+  @native def collectAsync(ptr: Long): Long
+
+  @native def collectAll(ptrs: Array[Long]): Array[Long]
+
+  @native def sinkAll(
+      ptrs: Array[Long],
+      paths: Array[String],
+      formats: Array[String],
+      options: String
+  ): Unit
+
   @native def optimizationToggle(
       ptr: Long,
       typeCoercion: Boolean,
@@ -66,6 +89,14 @@ private[polars] object lazy_frame extends Natively {
       streaming: Boolean
   ): Long
 
+  @native def serializeJson(ptr: Long, allowInMemory: Boolean): String
+
+  @native def serializeBinary(ptr: Long, allowInMemory: Boolean): Array[Byte]
+
+  @native def deserializeJson(json: String): Long
+
+  @native def deserializeBinary(bytes: Array[Byte]): Long
+
   @native def free(ptr: Long): Unit
 
 }
@@ -0,0 +1,15 @@
+package com.github.chitralverma.polars.internal.jni
+
+private[polars] object query extends Natively {
+
+  @native def poll(ptr: Long): Long
+
+  @native def awaitResult(ptr: Long, timeoutMillis: Long): Long
+
+  @native def cancel(ptr: Long): Unit
+
+  @native def isDone(ptr: Long): Boolean
+
+  @native def free(ptr: Long): Unit
+
+}
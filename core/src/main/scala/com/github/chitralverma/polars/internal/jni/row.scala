@@ -4,10 +4,16 @@ private[polars] object row extends Natively {
 
   @native def createIterator(dfPtr: Long, nRows: Long): Long
 
+  @native def createLazyIterator(ldfPtr: Long, nRows: Long, streaming: Boolean): Long
+
   @native def advanceIterator(ptr: Long): Array[Object]
 
+  @native def advanceBatch(ptr: Long, n: Int): Array[Array[Object]]
+
   @native def schemaString(ptr: Long): String
 
+  @native def schemaJson(ptr: Long): String
+
   @native def free(ptr: Long): Unit
 
 }
@@ -26,6 +26,149 @@ private[polars] object series extends Natively {
 
   @native def newStructSeries(name: String, ptrs: Array[Long]): Long
 
+  @native def newTypedSeries(name: String, values: Array[Object], dataType: String): Long
+
+  @native def newNullableIntSeries(name: String, data: Array[Int], validity: AnyRef): Long
+
+  @native def newNullableLongSeries(name: String, data: Array[Long], validity: AnyRef): Long
+
+  @native def newNullableFloatSeries(name: String, data: Array[Float], validity: AnyRef): Long
+
+  @native def newFloat16Series(name: String, data: Array[Float], validity: AnyRef): Long
+
+  @native def newNullableDoubleSeries(name: String, data: Array[Double], validity: AnyRef): Long
+
+  @native def newNullableBooleanSeries(name: String, data: Array[Boolean], validity: AnyRef): Long
+
+  @native def newInt8Series(name: String, data: Array[Byte], validity: AnyRef): Long
+
+  @native def newInt16Series(name: String, data: Array[Short], validity: AnyRef): Long
+
+  @native def newUInt8Series(name: String, data: Array[Byte], validity: AnyRef): Long
+
+  @native def newUInt16Series(name: String, data: Array[Short], validity: AnyRef): Long
+
+  @native def newUInt32Series(name: String, data: Array[Int], validity: AnyRef): Long
+
+  @native def newUInt64Series(name: String, data: Array[Long], validity: AnyRef): Long
+
+  @native def newCategoricalSeries(
+      name: String,
+      data: Array[String],
+      categories: Array[String]
+  ): Long
+
+  @native def newDecimalSeries(
+      name: String,
+      unscaled: Array[Long],
+      precision: Int,
+      scale: Int,
+      validity: AnyRef
+  ): Long
+
+  @native def newBigDecimalSeries(
+      name: String,
+      unscaled: Array[Object],
+      precision: Int,
+      scale: Int
+  ): Long
+
+  @native def newBinarySeries(name: String, data: Array[Array[Byte]]): Long
+
+  @native def newDurationSeries(
+      name: String,
+      data: Array[Long],
+      timeUnit: String,
+      validity: AnyRef
+  ): Long
+
+  @native def newEpochDatetimeSeries(
+      name: String,
+      data: Array[Long],
+      timeUnit: String,
+      timeZone: String,
+      validity: AnyRef
+  ): Long
+
+  @native def newDirectBufferSeries(
+      name: String,
+      dataType: String,
+      data: java.nio.ByteBuffer,
+      length: Long,
+      validity: java.nio.ByteBuffer
+  ): Long
+
+  @native def toLongArray(ptr: Long): Array[Long]
+
+  @native def toIntArray(ptr: Long): Array[Int]
+
+  @native def toFloatArray(ptr: Long): Array[Float]
+
+  @native def toDoubleArray(ptr: Long): Array[Double]
+
+  @native def toBooleanArray(ptr: Long): Array[Boolean]
+
+  @native def toStringArray(ptr: Long): Array[String]
+
+  @native def validity(ptr: Long): Array[Long]
+
+  @native def fillDirectBuffer(
+      ptr: Long,
+      dataType: String,
+      data: java.nio.ByteBuffer,
+      validity: java.nio.ByteBuffer
+  ): Long
+
+  @native def name(ptr: Long): String
+
+  @native def rename(ptr: Long, name: String): Long
+
+  @native def dtype(ptr: Long): String
+
+  @native def len(ptr: Long): Long
+
+  @native def nullCount(ptr: Long): Long
+
+  @native def get(ptr: Long, index: Long): Object
+
+  @native def slice(ptr: Long, offset: Long, length: Long): Long
+
+  @native def head(ptr: Long, n: Long): Long
+
+  @native def tail(ptr: Long, n: Long): Long
+
+  @native def sort(ptr: Long, descending: Boolean, nullsLast: Boolean): Long
+
+  @native def argSort(ptr: Long, descending: Boolean, nullsLast: Boolean): Long
+
+  @native def unique(ptr: Long, maintainOrder: Boolean): Long
+
+  @native def nUnique(ptr: Long): Long
+
+  @native def valueCounts(ptr: Long, sort: Boolean, normalize: Boolean): Long
+
+  @native def filter(ptr: Long, maskPtr: Long): Long
+
+  @native def take(ptr: Long, indices: Array[Long]): Long
+
+  @native def cast(ptr: Long, dataType: String, strict: Boolean): Long
+
+  @native def applyBinary(leftPtr: Long, rightPtr: Long, operator: Int): Long
+
+  @native def applyBinaryScalar(leftPtr: Long, right: Any, operator: Int): Long
+
+  @native def reduce(ptr: Long, aggregation: String): Object
+
+  @native def std(ptr: Long, ddof: Int): Object
+
+  @native def variance(ptr: Long, ddof: Int): Object
+
+  @native def quantile(ptr: Long, quantile: Double, method: String): Object
+
+  @native def equals(ptr: Long, otherPtr: Long, nullEqual: Boolean): Boolean
+
+  @native def toFrame(ptr: Long): Long
+
   @native def free(ptr: Long): Unit
 
 }
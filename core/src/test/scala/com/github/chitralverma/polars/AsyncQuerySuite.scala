@@ -0,0 +1,60 @@
+package com.github.chitralverma.polars
+
+import scala.util.Try
+
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests `LazyFrame.collectAsync` and the `AsyncQuery` lifecycle: awaiting, polling, timeouts,
+  * cancellation and freeing a query that is still running.
+  */
+class AsyncQuerySuite extends PolarsTestBase {
+
+  test("await returns the same result as collect") {
+    val ldf = intFrame("a", 1, 2, 3).toLazy.withColumn("b", col("a") * lit(2))
+
+    val query = ldf.collectAsync()
+    try {
+      val df = query.await()
+      assertColumns(df, "a", "b")
+      assertColumnValues(df, "b", 2, 4, 6)
+      query.isDone shouldBe true
+    } finally query.close()
+  }
+
+  test("poll and timed await return the retained result once done") {
+    val query = intFrame("a", 1, 2).toLazy.collectAsync()
+    try {
+      query.await(60000L).map(_.count()) shouldBe Some(2L)
+      query.poll().map(_.count()) shouldBe Some(2L)
+    } finally query.close()
+  }
+
+  test("a cancelled query either finished first or reports the cancellation") {
+    val query = longFrame("a", (1L to 100000L): _*).toLazy
+      .withColumn("b", col("a") * lit(3))
+      .collectAsync()
+    try {
+      query.cancel()
+      val outcome = Try(query.await())
+      outcome.isSuccess || outcome.failed.get.isInstanceOf[RuntimeException] shouldBe true
+    } finally query.close()
+  }
+
+  test("closing a running query does not abort and leaves the engine usable") {
+    (1 to 20).foreach { _ =>
+      longFrame("a", (1L to 100000L): _*).toLazy
+        .withColumn("b", col("a") * lit(3))
+        .collectAsync()
+        .close()
+    }
+
+    assertColumnValues(intFrame("a", 1).toLazy.collect, "a", 1)
+  }
+
+  test("a closed query rejects further use") {
+    val query = intFrame("a", 1).toLazy.collectAsync()
+    query.close()
+    an[IllegalStateException] should be thrownBy query.poll()
+  }
+}
@@ -0,0 +1,36 @@
+package com.github.chitralverma.polars
+
+import com.github.chitralverma.polars.api.DataFrame
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests the eager Avro reader. */
+class AvroReadSuite extends PolarsTestBase {
+
+  private def frame: DataFrame =
+    longFrame("a", 1L, 2L, 3L).hstack(stringFrame("b", "x", "y", "z").getColumn("b"))
+
+  test("avroBytes round-trips through read.avro") {
+    val df = frame
+
+    Polars.read.avro(df.write().avroBytes()).frameEquals(df) shouldBe true
+  }
+
+  test("several sources are concatenated before the row limit and index") {
+    val bytes = frame.write().avroBytes()
+
+    val df = Polars.read
+      .option("read_avro_n_rows", "4")
+      .option("read_avro_row_index_name", "idx")
+      .option("read_avro_rechunk", "true")
+      .avro(bytes, bytes)
+
+    assertColumns(df, "idx", "a", "b")
+    assertColumnValues(df, "a", 1L, 2L, 3L, 1L)
+  }
+
+  test("columns are projected by name") {
+    val df = Polars.read.option("read_avro_columns", "b").avro(frame.write().avroBytes())
+
+    assertColumns(df, "b")
+  }
+}
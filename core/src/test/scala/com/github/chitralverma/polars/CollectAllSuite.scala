@@ -0,0 +1,52 @@
+package com.github.chitralverma.polars
+
+import java.nio.file.Files
+
+import com.github.chitralverma.polars.api.LazyFrame
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests evaluating several LazyFrames in one pass with `LazyFrame.collectAll` and `sinkAll`. */
+class CollectAllSuite extends PolarsTestBase {
+
+  test("collectAll returns one DataFrame per input in order") {
+    val base = intFrame("a", 1, 2, 3).toLazy
+
+    val results = LazyFrame.collectAll(
+      Array(base.withColumn("b", col("a") * lit(2)), base.filter(col("a") > lit(1)))
+    )
+
+    results should have length 2
+    assertColumnValues(results(0), "b", 2, 4, 6)
+    assertColumnValues(results(1), "a", 2, 3)
+    LazyFrame.collectAll(Array.empty[LazyFrame]) shouldBe empty
+  }
+
+  test("sinkAll writes every input in its own format") {
+    val dir = Files.createTempDirectory("sink_all")
+    val base = intFrame("a", 1, 2, 3).toLazy
+    val parquetPath = dir.resolve("out.parquet").toString
+    val csvPath = dir.resolve("out.csv").toString
+    val jsonPath = dir.resolve("out.jsonl").toString
+
+    LazyFrame.sinkAll(
+      Array(base, base.filter(col("a") > lit(1)), base.limit(1)),
+      Array(parquetPath, csvPath, jsonPath),
+      Array("parquet", "csv", "json")
+    )
+
+    assertColumnValues(Polars.scan.parquet(parquetPath).collect, "a", 1, 2, 3)
+    assertColumnValues(Polars.scan.csv(csvPath).collect, "a", 2L, 3L)
+    assertColumnValues(Polars.scan.jsonLines(jsonPath).collect, "a", 1L)
+  }
+
+  test("sinkAll rejects unknown formats and mismatched arguments") {
+    val ldf = intFrame("a", 1).toLazy
+    val path = Files.createTempDirectory("sink_all").resolve("out").toString
+
+    a[RuntimeException] should be thrownBy
+      LazyFrame.sinkAll(Array(ldf), Array(path), Array("xml"))
+    an[IllegalArgumentException] should be thrownBy
+      LazyFrame.sinkAll(Array(ldf, ldf), Array(path), Array("csv"))
+  }
+}
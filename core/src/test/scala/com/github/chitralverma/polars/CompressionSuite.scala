@@ -0,0 +1,59 @@
+package com.github.chitralverma.polars
+
+import java.nio.file.Files
+
+import com.github.chitralverma.polars.api.DataFrame
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests compressed CSV and NDJSON output and input. */
+class CompressionSuite extends PolarsTestBase {
+
+  private def frame: DataFrame = longFrame("a", 1L, 2L, 3L)
+
+  test("gzip CSV bytes are read back by the CSV scan") {
+    val bytes = frame.write().option("write_compression", "gzip").csvBytes()
+
+    bytes.take(2) shouldBe Array(0x1f.toByte, 0x8b.toByte)
+    Polars.scan.csv(bytes).collect.frameEquals(frame) shouldBe true
+  }
+
+  test("zstd NDJSON files are read back by the NDJSON scan") {
+    val path = Files.createTempFile("polars", ".ndjson.zst")
+    try {
+      frame
+        .write()
+        .option("write_compression", "zstd")
+        .option("write_compression_level", "19")
+        .option("write_mode", "overwrite")
+        .jsonLines(path.toString)
+
+      val df = Polars.scan
+        .option("scan_ndjson_compression", "zstd")
+        .jsonLines(path.toString)
+        .collect
+      df.frameEquals(frame) shouldBe true
+    } finally Files.deleteIfExists(path)
+  }
+
+  test("unknown codecs and out-of-range levels are rejected") {
+    a[RuntimeException] should be thrownBy
+      frame.write().option("write_compression", "lzma").csvBytes()
+    a[RuntimeException] should be thrownBy
+      frame
+        .write()
+        .option("write_compression", "gzip")
+        .option("write_compression_level", "10")
+        .csvBytes()
+  }
+
+  test("the scan compression option checks the input") {
+    val plain = frame.write().csvBytes()
+
+    a[RuntimeException] should be thrownBy
+      Polars.scan.option("scan_csv_compression", "gzip").csv(plain)
+    a[RuntimeException] should be thrownBy
+      Polars.scan.option("scan_csv_compression", "lz4").csv(plain)
+    val df = Polars.scan.option("scan_csv_compression", "uncompressed").csv(plain).collect
+    assertRowCount(df, 3)
+  }
+}
@@ -0,0 +1,69 @@
+package com.github.chitralverma.polars
+
+import scala.jdk.CollectionConverters._
+
+import com.github.chitralverma.polars.api.{DataFrame, Series}
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests eager DataFrame introspection and column manipulation. */
+class DataFrameIntrospectionSuite extends PolarsTestBase {
+
+  private def frame: DataFrame =
+    DataFrame.fromSeries(
+      Series.ofLong("a", Array(1L, 2L, 3L)),
+      Series.ofType("b", "string", Array[Any]("x", null, "z"))
+    )
+
+  test("shape, columns and dtypes describe the frame") {
+    val df = frame
+
+    df.shape shouldBe ((3L, 2))
+    df.columns shouldBe Array("a", "b")
+    df.dtypes.map(jsonMapper.readTree(_).get("type").asText) shouldBe Array("int64", "string")
+    df.getColumn(1).dtype shouldBe df.dtypes(1)
+  }
+
+  test("columns and rows are read without a lazy query") {
+    val df = frame
+
+    df.getColumn("a").toLongArray shouldBe Array(1L, 2L, 3L)
+    df.getColumns.map(_.name) shouldBe Array("a", "b")
+    df.row(1).toSeq shouldBe Seq(2L, null)
+  }
+
+  test("dropInPlace removes the column from the frame itself") {
+    val df = frame
+
+    df.dropInPlace("b").toStringArray shouldBe Array("x", null, "z")
+    df.columns shouldBe Array("a")
+  }
+
+  test("extend appends the rows of the same frame") {
+    val df = frame
+    df.extend(df)
+
+    df.height shouldBe 6L
+    df.getColumn("a").toLongArray shouldBe Array(1L, 2L, 3L, 1L, 2L, 3L)
+  }
+
+  test("vstack and frameEquals compare frames") {
+    val df = frame
+
+    df.vstack(df).frameEquals(df.vstack(df), nullEqual = true) shouldBe true
+    df.frameEquals(df) shouldBe false
+  }
+
+  test("extend waits for reads running on other threads") {
+    val df = frame
+    val other = frame
+    val heights = new java.util.concurrent.ConcurrentLinkedQueue[Long]()
+    val reader = new Thread(() => (1 to 200).foreach(_ => heights.add(df.getColumn("a").len)))
+
+    reader.start()
+    (1 to 200).foreach(_ => df.extend(other))
+    reader.join()
+
+    df.height shouldBe 603L
+    heights.asScala.forall(_ % 3 == 0) shouldBe true
+  }
+}
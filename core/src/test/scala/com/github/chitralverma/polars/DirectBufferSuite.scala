@@ -0,0 +1,45 @@
+package com.github.chitralverma.polars
+
+import java.nio.{ByteBuffer, ByteOrder}
+
+import com.github.chitralverma.polars.api.{DataFrame, Series}
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests building Series over direct ByteBuffers without copying. */
+class DirectBufferSuite extends PolarsTestBase {
+
+  private def doubles(values: Double*): ByteBuffer = {
+    val buf = ByteBuffer.allocateDirect(values.length * 8).order(ByteOrder.nativeOrder())
+    values.foreach(v => buf.putDouble(v))
+    buf.flip()
+    buf
+  }
+
+  test("ofDirectBuffer reads values and the validity bitmap") {
+    val validity = ByteBuffer.allocateDirect(1)
+    validity.put(0, 5.toByte) // values 0 and 2 are valid
+
+    val df = DataFrame.fromSeries(
+      Series.ofDirectBuffer("a", "float64", doubles(1.5, 2.5, 3.5), 3, validity)
+    )
+
+    assertColumnValues(df, "a", 1.5, null, 3.5)
+  }
+
+  test("ofDirectBuffer starts at the buffer position") {
+    val buf = doubles(1.0, 2.0, 3.0)
+    buf.position(8)
+
+    val df = DataFrame.fromSeries(Series.ofDirectBuffer("a", "float64", buf, 2))
+
+    assertColumnValues(df, "a", 2.0, 3.0)
+  }
+
+  test("ofDirectBuffer rejects lengths beyond the buffer limit") {
+    val buf = doubles(1.0, 2.0)
+    buf.limit(8)
+
+    a[RuntimeException] should be thrownBy Series.ofDirectBuffer("a", "float64", buf, 2)
+    a[RuntimeException] should be thrownBy Series.ofDirectBuffer("a", "string", doubles(1.0), 1)
+  }
+}
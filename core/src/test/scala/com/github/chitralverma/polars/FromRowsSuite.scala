@@ -0,0 +1,42 @@
+package com.github.chitralverma.polars
+
+import com.github.chitralverma.polars.api.DataFrame
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests building DataFrames from rows of JVM values. */
+class FromRowsSuite extends PolarsTestBase {
+
+  private def row(values: Any*): Array[Object] = values.map(_.asInstanceOf[Object]).toArray
+
+  test("fromRows infers column types and names") {
+    val df = DataFrame.fromRows(Array(row(1L, "a", null), row(2L, null, 2.5)))
+
+    df.columns shouldBe Array("column_0", "column_1", "column_2")
+    df.dtypes.map(jsonMapper.readTree(_).get("type").asText) shouldBe
+      Array("int64", "string", "float64")
+    assertColumnValues(df, "column_1", "a", null)
+  }
+
+  test("fromRows infers list and struct columns from collections") {
+    val df =
+      DataFrame.fromRows(Array(row(Seq(1L, 2L), Map("x" -> 1L)), row(None, Map("x" -> 2L))))
+
+    df.dtypes.map(jsonMapper.readTree(_).get("type").asText) shouldBe Array("list", "struct")
+    df.getColumn(0).nullCount shouldBe 1L
+  }
+
+  test("fromRows applies a given schema") {
+    val schema = longFrame("n", 1L).schema
+    val df = DataFrame.fromRows(Array(row(1), row(2)), schema)
+
+    df.columns shouldBe Array("n")
+    df.getColumn("n").toLongArray shouldBe Array(1L, 2L)
+  }
+
+  test("fromProducts reads tuples") {
+    val df = DataFrame.fromProducts(Seq((1L, "a"), (2L, "b")))
+
+    assertRowCount(df, 2)
+    assertColumnValues(df, "column_1", "a", "b")
+  }
+}
@@ -0,0 +1,45 @@
+package com.github.chitralverma.polars
+
+import java.nio.ByteBuffer
+
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests scanning from and writing to in-memory buffers. */
+class InMemoryIOSuite extends PolarsTestBase {
+
+  private def direct(bytes: Array[Byte], padding: Int = 0): ByteBuffer = {
+    val buf = ByteBuffer.allocateDirect(padding + bytes.length)
+    buf.position(padding)
+    buf.put(bytes)
+    buf.position(padding)
+    buf
+  }
+
+  test("parquet and ipc bytes round-trip through byte arrays") {
+    val df = longFrame("a", 1L, 2L, 3L)
+
+    val fromParquet = Polars.scan.parquet(df.write().parquetBytes()).collect
+    val fromIpc = Polars.scan.ipc(df.write().ipcBytes()).collect
+
+    fromParquet.frameEquals(df) shouldBe true
+    fromIpc.frameEquals(df) shouldBe true
+  }
+
+  test("direct buffers are scanned between their position and limit") {
+    val df = stringFrame("a", "x", "y")
+    val csv = df.write().csvBytes()
+
+    val buf = direct(csv ++ "garbage".getBytes("UTF-8"), padding = 5)
+    buf.limit(5 + csv.length)
+
+    Polars.scan.csv(buf).collect.frameEquals(df) shouldBe true
+  }
+
+  test("heap buffers and several sources are concatenated") {
+    val bytes = longFrame("a", 1L).write().jsonLinesBytes()
+
+    val df = Polars.scan.jsonLines(ByteBuffer.wrap(bytes), direct(bytes)).collect
+
+    assertColumnValues(df, "a", 1L, 1L)
+  }
+}
@@ -0,0 +1,47 @@
+package com.github.chitralverma.polars
+
+import java.nio.file.Files
+
+import com.github.chitralverma.polars.api.io.IpcStreamWriter
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests reading and writing the Arrow IPC stream format. */
+class IpcStreamSuite extends PolarsTestBase {
+
+  test("ipcStreamBytes round-trips through read.ipcStream") {
+    val df = longFrame("a", 1L, 2L, 3L)
+
+    Polars.read.ipcStream(df.write().ipcStreamBytes()).frameEquals(df) shouldBe true
+  }
+
+  test("the incremental writer appends frames to one stream") {
+    val writer = IpcStreamWriter.inMemory()
+    writer.write(longFrame("a", 1L)).write(longFrame("a", 2L, 3L))
+    val bytes = writer.finish()
+
+    Polars.read.ipcStream(bytes).frameEquals(longFrame("a", 1L, 2L, 3L)) shouldBe true
+  }
+
+  test("the incremental writer rejects frames with another schema") {
+    val writer = IpcStreamWriter.inMemory()
+    writer.write(longFrame("a", 1L))
+
+    a[RuntimeException] should be thrownBy writer.write(stringFrame("a", "x"))
+    writer.close()
+  }
+
+  test("streams written to a path are read back with options") {
+    val path = Files.createTempFile("polars", ".arrows")
+    try {
+      val writer = IpcStreamWriter.open(path.toString, Map("write_mode" -> "overwrite"))
+      writer.write(longFrame("a", 1L, 2L, 3L))
+      writer.finish()
+
+      val df = Polars.read
+        .option("read_ipc_stream_n_rows", "2")
+        .option("read_ipc_stream_rechunk", "true")
+        .ipcStream(path.toString)
+      assertColumnValues(df, "a", 1L, 2L)
+    } finally Files.deleteIfExists(path)
+  }
+}
@@ -0,0 +1,54 @@
+package com.github.chitralverma.polars
+
+import java.math.{BigDecimal => JBigDecimal, BigInteger}
+import java.time.{Instant, LocalDate, ZoneOffset}
+
+import com.github.chitralverma.polars.api.types.DataTypes
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests decoding of JVM values passed to `isIn` into polars values. */
+class JavaValueSuite extends PolarsTestBase {
+
+  test("isIn matches java.time dates") {
+    val df = stringFrame("a", "2024-01-01", "2024-01-02", "2024-01-03")
+      .select(col("a").cast(DataTypes.Date).alias("d"))
+
+    val result = df.filter(col("d").isIn(Array(LocalDate.of(2024, 1, 2))))
+
+    assertColumnValues(result, "d", LocalDate.of(2024, 1, 2))
+  }
+
+  test("isIn matches BigDecimal and BigInteger values") {
+    val decimals = stringFrame("a", "1.50", "2.25")
+      .select(col("a").cast(DataTypes.decimal(10, 2)).alias("d"))
+    val longs = longFrame("a", 1L, 2L, 3L)
+
+    assertRowCount(decimals.filter(col("d").isIn(Array(new JBigDecimal("2.25")))), 1)
+    assertColumnValues(longs.filter(col("a").isIn(Array(BigInteger.valueOf(3)))), "a", 3L)
+  }
+
+  test("isIn rejects decimals wider than 38 digits and unsupported types") {
+    val df = longFrame("a", 1L)
+
+    a[RuntimeException] should be thrownBy
+      df.filter(col("a").isIn(Array(new JBigDecimal("1" * 39))))
+    a[RuntimeException] should be thrownBy df.filter(col("a").isIn(Array(new Object)))
+  }
+
+  test("isIn matches ZonedDateTime values in UTC") {
+    val df = longFrame("a", 0L, 86400000000000L)
+      .select(col("a").cast("datetime[ns, UTC]").alias("t"))
+    val utc = Instant.ofEpochSecond(86400).atZone(ZoneOffset.UTC)
+
+    assertRowCount(df.filter(col("t").isIn(Array(utc))), 1)
+  }
+
+  test("isIn rejects ZonedDateTime offsets that are not whole hours") {
+    val df = longFrame("a", 0L).select(col("a").cast("datetime[ns, UTC]").alias("t"))
+    val offset = Instant.EPOCH.atZone(ZoneOffset.ofHoursMinutes(5, 30))
+
+    val error = the[RuntimeException] thrownBy df.filter(col("t").isIn(Array(offset)))
+    error.getMessage should include("+05:30")
+  }
+}
@@ -0,0 +1,34 @@
+package com.github.chitralverma.polars
+
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests the eager JSON reader. */
+class JsonReadSuite extends PolarsTestBase {
+
+  private val document = """[{"a": 1, "b": "x"}, {"a": 2, "b": "y"}]""".getBytes("UTF-8")
+
+  test("jsonBytes round-trips through read.json") {
+    val df = longFrame("a", 1L, 2L)
+
+    Polars.read.json(df.write().jsonBytes()).frameEquals(df) shouldBe true
+  }
+
+  test("schema overrides change the inferred column types") {
+    val df = Polars.read
+      .option("read_json_schema_overrides", """{"a": "int32", "b": "categorical"}""")
+      .json(document)
+
+    df.dtypes.map(jsonMapper.readTree(_).get("type").asText) shouldBe
+      Array("int32", "categorical")
+  }
+
+  test("json lines are read with the format option") {
+    val df = Polars.read
+      .option("read_json_format", "json_lines")
+      .option("read_json_columns", "b")
+      .json("{\"a\": 1, \"b\": \"x\"}\n{\"a\": 2, \"b\": \"y\"}\n".getBytes("UTF-8"))
+
+    assertColumns(df, "b")
+    assertColumnValues(df, "b", "x", "y")
+  }
+}
@@ -0,0 +1,116 @@
+package com.github.chitralverma.polars
+
+import java.math.{BigDecimal => JBigDecimal}
+import java.time.{Duration => JDuration, Instant, ZoneId, ZonedDateTime}
+
+import com.github.chitralverma.polars.api.{DataFrame, Series}
+import com.github.chitralverma.polars.api.expressions.Expression
+import com.github.chitralverma.polars.api.types.{DataTypes, DateTimeType}
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.internal.jni.expressions.literal_expr
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests typed literal constructors and the JVM types accepted by `lit`. */
+class LiteralSuite extends PolarsTestBase {
+
+  private def selectOne(expr: Expression): DataFrame = intFrame("a", 1).select(expr.alias("v"))
+
+  private def valueOf(expr: Expression): AnyRef = columnOf(selectOne(expr), "v").head
+
+  private def typeOf(expr: Expression): String =
+    jsonMapper.readTree(selectOne(expr).dtypes.head).get("type").asText
+
+  test("Instant and Duration literals keep nanosecond precision") {
+    val instant = Instant.ofEpochSecond(1, 123456789)
+
+    assertColumnValues(selectOne(lit(instant).cast(DataTypes.Int64)), "v", 1123456789L)
+    assertColumnValues(selectOne(lit(JDuration.ofNanos(1500)).cast(DataTypes.Int64)), "v", 1500L)
+  }
+
+  test("lit drops the zone of ZonedDateTime values and keeps their wall-clock time") {
+    val zdt = ZonedDateTime.of(2024, 6, 1, 12, 0, 0, 0, ZoneId.of("Europe/Paris"))
+    val df = DataFrame.fromSeries(Series.ofDateTime("ts", Array(zdt)))
+
+    assertRowCount(df.filter(col("ts") === lit(zdt)), 1)
+  }
+
+  test("litDateTime keeps the zone of ZonedDateTime values") {
+    val zdt = ZonedDateTime.of(2024, 6, 1, 12, 0, 0, 0, ZoneId.of("Europe/Paris"))
+    val df = selectOne(litDateTime(zdt))
+
+    df.schema.getField("v").get.dataType match {
+      case dt: DateTimeType => dt.timeZone shouldBe Some(ZoneId.of("Europe/Paris"))
+      case other => fail(s"Expected a datetime, found $other")
+    }
+    assertColumnValues(
+      selectOne(litDateTime(zdt).cast(DataTypes.Int64)),
+      "v",
+      zdt.toInstant.getEpochSecond * 1000000000L
+    )
+  }
+
+  test("litDateTime reads values without an offset as wall-clock time in the zone") {
+    val expr = litDateTime("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S", "ms", "Europe/Paris")
+    val expected = ZonedDateTime.of(2024, 1, 1, 0, 0, 0, 0, ZoneId.of("Europe/Paris"))
+
+    assertColumnValues(
+      selectOne(expr.cast(DataTypes.Int64)),
+      "v",
+      expected.toInstant.toEpochMilli
+    )
+  }
+
+  test("unsigned literals are range checked and usable from Scala") {
+    assertColumnValues(selectOne(litUInt8(255).cast(DataTypes.Int32)), "v", 255)
+    assertColumnValues(
+      selectOne(litUInt64(-1L).cast(DataTypes.String)),
+      "v",
+      "18446744073709551615"
+    )
+    a[RuntimeException] should be thrownBy litUInt8(256)
+    a[RuntimeException] should be thrownBy litUInt32(-1L)
+  }
+
+  test("Byte and Short literals keep their width") {
+    typeOf(lit(7.toByte)) shouldBe "int8"
+    typeOf(lit(7.toShort)) shouldBe "int16"
+    valueOf(lit((-3).toByte)) shouldBe -3
+    valueOf(lit(300.toShort)) shouldBe 300
+  }
+
+  test("decimal literals keep their digits and reject values wider than the precision") {
+    valueOf(lit(new JBigDecimal("123.45"))) shouldBe new JBigDecimal("123.45")
+    valueOf(lit(BigDecimal("-1E+3"))) shouldBe new JBigDecimal("-1000")
+
+    a[RuntimeException] should be thrownBy literal_expr.fromDecimal("123456", 3, 0)
+    a[RuntimeException] should be thrownBy literal_expr.fromDecimal("-1000", 3, 0)
+    Expression.withPtr(literal_expr.fromDecimal("-999", 3, 0))
+  }
+
+  test("binary literals keep their bytes") {
+    valueOf(lit(Array[Byte](1, 2, -1))).asInstanceOf[Array[Byte]].toSeq shouldBe
+      Seq[Byte](1, 2, -1)
+  }
+
+  test("list and array literals hold all values of the series") {
+    val series = Series.ofInt("x", Array(1, 2, 3))
+
+    valueOf(litList(series)).toString shouldBe "[1, 2, 3]"
+    valueOf(litArray(series)).asInstanceOf[Array[AnyRef]].toSeq shouldBe Seq(1, 2, 3)
+  }
+
+  test("struct literals hold their named fields") {
+    val value = valueOf(litStruct("a" -> 1L, "b" -> "x", "c" -> col("a")))
+      .asInstanceOf[java.util.Map[String, AnyRef]]
+
+    value.get("a") shouldBe 1L
+    value.get("b") shouldBe "x"
+    value.get("c") shouldBe 1
+  }
+
+  test("Series literals are used as a column of their values") {
+    val df = intFrame("a", 1, 2).select(lit(Series.ofLong("s", Array(3L, 4L))).alias("v"))
+
+    assertColumnValues(df, "v", 3L, 4L)
+  }
+}
@@ -0,0 +1,168 @@
+package com.github.chitralverma.polars
+
+import com.github.chitralverma.polars.api.{DataFrame, Series}
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.testing.{ParquetFooter, PolarsTestBase}
+
+/** Tests Parquet write options by reading back the written file metadata. */
+class ParquetWriteSuite extends PolarsTestBase {
+
+  // Enum values of the Parquet Thrift definitions.
+  private val Int96 = 1L
+  private val Snappy = 1L
+  private val Gzip = 2L
+  private val DeltaBinaryPacked = 5L
+  private val RleDictionary = 8L
+
+  private def struct(value: Any): Map[Int, Any] = value.asInstanceOf[Map[Int, Any]]
+
+  private def list(value: Any): Seq[Map[Int, Any]] = value.asInstanceOf[Seq[Any]].map(struct)
+
+  private def rowGroups(footer: Map[Int, Any]): Seq[Map[Int, Any]] = list(footer(4))
+
+  private def schemaElements(footer: Map[Int, Any]): Seq[Map[Int, Any]] = list(footer(2))
+
+  /** The metadata of each column chunk of the first row group, by column path. */
+  private def columnChunks(footer: Map[Int, Any]): Map[String, Map[Int, Any]] =
+    list(rowGroups(footer).head(1)).map { chunk =>
+      val meta = struct(chunk(3))
+      meta(3).asInstanceOf[Seq[Any]].mkString(".") -> meta
+    }.toMap
+
+  private def frame: DataFrame =
+    DataFrame.fromSeries(
+      Series.ofLong("id", Array(1L, 2L, 3L)),
+      Series.ofString("body", Array("x", "y", "x"))
+    )
+
+  test("custom key-value metadata is written to the footer") {
+    val bytes = frame
+      .write()
+      .option("write_parquet_metadata", """{"source": "orders"}""")
+      .parquetBytes()
+
+    val keyValues = list(ParquetFooter(bytes)(5)).map(kv => kv(1) -> kv(2)).toMap
+    keyValues("source") shouldBe "orders"
+    keyValues should contain key "ARROW:schema"
+    Polars.scan.parquet(bytes).collect.frameEquals(frame) shouldBe true
+  }
+
+  test("categorical, enum, decimal and nested columns round-trip with the default options") {
+    val df = DataFrame
+      .fromSeries(
+        Series.ofLong("id", Array(1L, 2L, 3L)),
+        Series.ofCategorical("cat", Array("x", "y", "x")),
+        Series.ofEnum("enum", Array("b", "a", "b"), Array("a", "b")),
+        Series.ofDecimal("dec", Array(12345L, -1L, 0L), 10, 2),
+        Series.ofList("l", Array(Array(1, 2), Array(3), Array.empty[Int]))
+      )
+      .withColumn("s", litStruct("id" -> col("id"), "l" -> col("l")))
+
+    val bytes = df.write().option("write_parquet_metadata", """{"a": "b"}""").parquetBytes()
+
+    Polars.scan.parquet(bytes).collect.frameEquals(df) shouldBe true
+  }
+
+  test("sorting columns are declared on every row group") {
+    val df = DataFrame.fromSeries(
+      Series.ofLong("id", Array(1L, 2L, 3L)),
+      Series.ofString("body", Array("z", "y", "x"))
+    )
+
+    val bytes = df
+      .write()
+      .option("write_parquet_row_group_size", "2")
+      .option(
+        "write_parquet_sorting_columns",
+        """["id", {"column": "body", "descending": true, "nulls_first": true}]"""
+      )
+      .parquetBytes()
+
+    rowGroups(ParquetFooter(bytes)).foreach { rowGroup =>
+      list(rowGroup(4)) shouldBe Seq(
+        Map(1 -> 0L, 2 -> false, 3 -> false),
+        Map(1 -> 1L, 2 -> true, 3 -> true)
+      )
+    }
+    Polars.scan.parquet(bytes).collect.frameEquals(df) shouldBe true
+  }
+
+  test("field overwrites set the encoding and codec of single columns") {
+    val bytes = frame
+      .write()
+      .option("write_compression", "zstd")
+      .option(
+        "write_parquet_field_overwrites",
+        """{"id": {"encoding": "delta_binary_packed", "compression": "snappy"},
+          | "body": {"dictionary": false, "compression": "gzip", "compression_level": 9}}"""
+          .stripMargin
+      )
+      .parquetBytes()
+
+    val chunks = columnChunks(ParquetFooter(bytes))
+    chunks("id")(4) shouldBe Snappy
+    chunks("id")(2).asInstanceOf[Seq[Any]] should contain(DeltaBinaryPacked)
+    chunks("body")(4) shouldBe Gzip
+    chunks("body")(2).asInstanceOf[Seq[Any]] should not contain RleDictionary
+    Polars.scan.parquet(bytes).collect.frameEquals(frame) shouldBe true
+  }
+
+  test("invalid field overwrites are rejected") {
+    a[RuntimeException] should be thrownBy frame
+      .write()
+      .option("write_parquet_field_overwrites", """{"missing": {"compression": "gzip"}}""")
+      .parquetBytes()
+
+    a[RuntimeException] should be thrownBy frame
+      .write()
+      .option(
+        "write_parquet_field_overwrites",
+        """{"body": {"encoding": "delta_binary_packed"}}"""
+      )
+      .parquetBytes()
+  }
+
+  test("unknown codecs and out-of-range levels are rejected") {
+    def error(options: (String, String)*) =
+      the[RuntimeException] thrownBy frame.write().options(options).parquetBytes()
+
+    error("write_compression" -> "lzma").getMessage should include("lzma")
+    error("write_compression" -> "zstd", "write_compression_level" -> "23").getMessage should
+      include("expected 1 to 22")
+    error("write_compression" -> "snappy", "write_compression_level" -> "1").getMessage should
+      include("snappy")
+    error(
+      "write_parquet_field_overwrites" ->
+        """{"body": {"compression": "gzip", "compression_level": 10}}"""
+    ).getMessage should include("expected 0 to 9")
+  }
+
+  test("INT96 columns keep nanosecond precision") {
+    val df = DataFrame.fromSeries(
+      Series.ofLong("t", Array(-1500000000123L, 0L, 86400000000000123L)).cast("datetime[ns]")
+    )
+
+    val bytes = df
+      .write()
+      .option("write_parquet_int96_columns", "t")
+      .option("write_parquet_spark_compat", "true")
+      .parquetBytes()
+
+    schemaElements(ParquetFooter(bytes)).find(_.get(4).contains("t")).get(1) shouldBe Int96
+    Polars.scan.parquet(bytes).collect.frameEquals(df) shouldBe true
+  }
+
+  test("Spark compatibility writes the legacy list layout") {
+    val df = DataFrame.fromSeries(Series.ofList("l", Array(Array(1, 2), Array(3))))
+
+    val bytes = df.write().option("write_parquet_spark_compat", "true").parquetBytes()
+
+    schemaElements(ParquetFooter(bytes)).flatMap(_.get(4)) shouldBe Seq(
+      "root",
+      "l",
+      "bag",
+      "array"
+    )
+    Polars.scan.parquet(bytes).collect.frameEquals(df) shouldBe true
+  }
+}
@@ -0,0 +1,36 @@
+package com.github.chitralverma.polars
+
+import java.nio.file.Files
+
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests exporting LazyFrame plans as DOT graphs and JSON trees. */
+class PlanExportSuite extends PolarsTestBase {
+
+  test("toDot renders a Graphviz digraph") {
+    val dot = intFrame("a", 1, 2).toLazy.filter(col("a") > lit(1)).toDot()
+
+    dot should include("digraph")
+  }
+
+  test("planTree exposes node types, schemas and inputs") {
+    val tree = jsonMapper.readTree(
+      intFrame("a", 1, 2).toLazy.withColumn("b", col("a") * lit(2)).planTree(optimized = false)
+    )
+
+    tree.get("schema").has("a") shouldBe true
+    tree.get("schema").has("b") shouldBe true
+    tree.get("inputs").get(0).get("type").asText() shouldBe "df"
+  }
+
+  test("the optimized planTree shows predicates pushed into the scan") {
+    val path = Files.createTempDirectory("plan_tree").resolve("in.parquet").toString
+    intFrame("a", 1, 2, 3).write().parquet(path)
+
+    val tree = jsonMapper.readTree(Polars.scan.parquet(path).filter(col("a") > lit(1)).planTree())
+
+    tree.get("sources").get(0).asText() should endWith("in.parquet")
+    tree.get("predicates").size() shouldBe 1
+  }
+}
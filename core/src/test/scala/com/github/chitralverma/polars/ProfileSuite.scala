@@ -0,0 +1,31 @@
+package com.github.chitralverma.polars
+
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests `LazyFrame.profile` and `LazyFrame.profileStreaming`, which return the query result
+  * along with per-node timings or per-operator metrics.
+  */
+class ProfileSuite extends PolarsTestBase {
+
+  test("profile returns the result and a timing per executed node") {
+    val ldf = intFrame("a", 3, 1, 2).toLazy
+      .sort("a", descending = false, nullLast = false, maintainOrder = false)
+
+    val (df, timings) = ldf.profile()
+
+    assertColumnValues(df, "a", 1, 2, 3)
+    assertColumns(timings, "node", "start", "end")
+    columnOf(timings, "node") should contain("optimization")
+    timings.count() should be > 1L
+  }
+
+  test("profileStreaming reports rows in and out per operator") {
+    val (df, metrics) = intFrame("a", 1, 2, 3).toLazy.filter(col("a") > lit(1)).profileStreaming()
+
+    assertColumnValues(df, "a", 2, 3)
+    assertColumns(metrics, "node", "rows_in", "rows_out", "morsels_in", "morsels_out", "time")
+    val filter = rowsOf(metrics).find(_("node") == "filter").get
+    (filter("rows_in"), filter("rows_out")) shouldBe ((3L, 2L))
+  }
+}
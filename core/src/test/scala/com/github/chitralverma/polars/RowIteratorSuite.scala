@@ -0,0 +1,52 @@
+package com.github.chitralverma.polars
+
+import com.github.chitralverma.polars.api.{DataFrame, Series}
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.internal.jni.row
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests batched row iteration over DataFrames and LazyFrames. */
+class RowIteratorSuite extends PolarsTestBase {
+
+  /** More rows than fit in one native batch. */
+  private def longs: DataFrame =
+    DataFrame.fromSeries(Series.ofLong("a", Array.range(0, 2500).map(_.toLong)))
+
+  test("DataFrame rows span several batches") {
+    longs.rows().map(_.getLong(0)).toList shouldBe List.range(0L, 2500L)
+  }
+
+  test("LazyFrame rows are produced by the streaming and in-memory engines") {
+    val ldf = longs.toLazy.filter(col("a") % 2 === 0)
+
+    ldf.rows().map(_.getLong(0)).toList shouldBe List.range(0L, 2500L, 2L)
+    ldf.rows(streaming = false).map(_.getLong(0)).toList shouldBe List.range(0L, 2500L, 2L)
+  }
+
+  test("LazyFrame rows honour nRows and carry the schema JSON") {
+    val ldf = longs.toLazy
+    val rows = ldf.rows(nRows = 3).toList
+
+    rows.map(_.getLong(0)) shouldBe List(0L, 1L, 2L)
+    rows.head.getSchemaJson shouldBe Some(longs.schemaJson)
+  }
+
+  test("closing a partially consumed iterator stops it") {
+    val it = longs.toLazy.rows()
+    it.next().getLong(0) shouldBe 0L
+    it.close()
+
+    it.hasNext shouldBe false
+  }
+
+  test("batches must have a positive size") {
+    val df = longs
+    val it = df.locked(row.createIterator(_, -1))
+
+    try {
+      val e = the[RuntimeException] thrownBy row.advanceBatch(it, 0)
+      e.getMessage should include("Batch size must be positive")
+      row.advanceBatch(it, 2).map(_(0)).toSeq shouldBe Seq(0L, 1L)
+    } finally row.free(it)
+  }
+}
@@ -0,0 +1,40 @@
+package com.github.chitralverma.polars
+
+import java.io.ByteArrayOutputStream
+import java.util.zip.GZIPOutputStream
+
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests scanning plain text with one row per line. */
+class ScanLinesSuite extends PolarsTestBase {
+
+  private val text = "first\nsecond\nthird\n".getBytes("UTF-8")
+
+  test("lines yields one string row per line") {
+    val df = Polars.scan.lines(text).collect
+
+    assertColumns(df, "lines")
+    assertColumnValues(df, "lines", "first", "second", "third")
+  }
+
+  test("lines honours the name, row limit and row index options") {
+    val df = Polars.scan
+      .option("scan_lines_name", "text")
+      .option("scan_lines_n_rows", "2")
+      .option("scan_lines_row_index_name", "idx")
+      .lines(text)
+      .collect
+
+    assertColumns(df, "idx", "text")
+    assertColumnValues(df, "text", "first", "second")
+  }
+
+  test("compressed input is decompressed from its content") {
+    val out = new ByteArrayOutputStream()
+    val gzip = new GZIPOutputStream(out)
+    gzip.write(text)
+    gzip.close()
+
+    assertRowCount(Polars.scan.lines(out.toByteArray).collect, 3)
+  }
+}
@@ -0,0 +1,63 @@
+package com.github.chitralverma.polars
+
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests explicit schemas, overrides, null values and projections in CSV and NDJSON scans. */
+class ScanSchemaSuite extends PolarsTestBase {
+
+  private val csv = "zip,id,note\n01234,1,NA\n98765,2,ok\n".getBytes("UTF-8")
+
+  private def types(json: Array[String]): Seq[String] =
+    json.toSeq.map(jsonMapper.readTree(_).get("type").asText)
+
+  test("CSV schema overrides keep leading zeros") {
+    val df = Polars.scan
+      .option("scan_csv_schema_overrides", """{"zip": "string"}""")
+      .csv(csv)
+      .collect
+
+    types(df.dtypes) shouldBe Seq("string", "int64", "string")
+    assertColumnValues(df, "zip", "01234", "98765")
+  }
+
+  test("CSV null values apply per column and projections select columns") {
+    val df = Polars.scan
+      .option("scan_csv_null_values", """{"note": "NA"}""")
+      .option("scan_csv_columns", "id,note")
+      .csv(csv)
+      .collect
+
+    assertColumns(df, "id", "note")
+    assertColumnValues(df, "note", null, "ok")
+  }
+
+  test("CSV dtypes apply by position to files without a header") {
+    val df = Polars.scan
+      .option("scan_csv_has_header", "false")
+      .option("scan_csv_dtypes", """["string", "int32"]""")
+      .csv("007,1\n008,2\n".getBytes("UTF-8"))
+      .collect
+
+    types(df.dtypes) shouldBe Seq("string", "int32")
+    assertColumnValues(df, df.columns.head, "007", "008")
+  }
+
+  test("CSV schema from schemaJson skips inference") {
+    val schema = stringFrame("zip", "x").schemaJson
+    val df = Polars.scan.option("scan_csv_schema", schema).csv("zip\n01234\n".getBytes("UTF-8"))
+
+    assertColumnValues(df.collect, "zip", "01234")
+  }
+
+  test("NDJSON schema overrides and projections") {
+    val ndjson = "{\"id\": 1, \"zip\": 1234}\n{\"id\": 2, \"zip\": 5678}\n".getBytes("UTF-8")
+    val df = Polars.scan
+      .option("scan_ndjson_schema_overrides", """{"id": "int16"}""")
+      .option("scan_ndjson_columns", "id")
+      .jsonLines(ndjson)
+      .collect
+
+    assertColumns(df, "id")
+    types(df.dtypes) shouldBe Seq("int16")
+  }
+}
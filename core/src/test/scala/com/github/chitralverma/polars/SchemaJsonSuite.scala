@@ -0,0 +1,33 @@
+package com.github.chitralverma.polars
+
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests the polars-native schema JSON contract exposed by frames and rows. */
+class SchemaJsonSuite extends PolarsTestBase {
+
+  test("schemaJson keeps decimal precision and round-trips through castToSchema") {
+    val typed = stringFrame("a", "1.25").select(col("a").cast("decimal(10, 2)").alias("a"))
+    val json = typed.schemaJson
+
+    jsonMapper.readTree(json).get("fields").get(0).get("dtype").get("precision").asInt shouldBe 10
+
+    val recast = stringFrame("a", "1.25").toLazy.castToSchema(json).collect
+    recast.schemaJson shouldBe json
+  }
+
+  test("rows read from a DataFrame carry its schema JSON") {
+    val df = intFrame("a", 1, 2)
+
+    df.rows().map(_.getSchemaJson).toList.distinct shouldBe List(Some(df.schemaJson))
+  }
+
+  test("decimal types in JSON are validated") {
+    val df = stringFrame("a", "1")
+
+    a[RuntimeException] should be thrownBy
+      df.select(col("a").cast("""{"type": "decimal", "precision": 40, "scale": 2}"""))
+    a[RuntimeException] should be thrownBy
+      df.select(col("a").cast("""{"type": "decimal", "precision": 5, "scale": 6}"""))
+  }
+}
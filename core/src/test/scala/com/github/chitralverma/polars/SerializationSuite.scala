@@ -0,0 +1,53 @@
+package com.github.chitralverma.polars
+
+import java.nio.file.Files
+
+import com.github.chitralverma.polars.api.LazyFrame
+import com.github.chitralverma.polars.api.expressions.Column
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests the versioned JSON and binary serialization of LazyFrame plans and expressions. */
+class SerializationSuite extends PolarsTestBase {
+
+  private def scannedFrame(): LazyFrame = {
+    val path = Files.createTempDirectory("serde").resolve("in.parquet").toString
+    intFrame("a", 1, 2, 3).write().parquet(path)
+    Polars.scan.parquet(path)
+  }
+
+  test("a scanned plan round-trips through JSON and binary") {
+    val ldf = scannedFrame().filter(col("a") > lit(1)).withColumn("b", col("a") * lit(10))
+
+    val fromJson = LazyFrame.deserializeJson(ldf.serializeJson())
+    val fromBinary = LazyFrame.deserializeBinary(ldf.serializeBinary())
+
+    assertColumnValues(fromJson.collect, "b", 20, 30)
+    assertColumnValues(fromBinary.collect, "b", 20, 30)
+  }
+
+  test("in-memory data is only serialized when explicitly allowed") {
+    val ldf = intFrame("a", 1, 2).toLazy
+
+    a[RuntimeException] should be thrownBy ldf.serializeJson()
+    a[RuntimeException] should be thrownBy ldf.serializeBinary()
+
+    assertColumnValues(LazyFrame.deserializeJson(ldf.serializeJson(true)).collect, "a", 1, 2)
+    assertColumnValues(LazyFrame.deserializeBinary(ldf.serializeBinary(true)).collect, "a", 1, 2)
+  }
+
+  test("expressions round-trip through JSON and binary") {
+    val expr = (col("a") + lit(1)).alias("c")
+    val df = intFrame("a", 1, 2)
+
+    assertColumnValues(df.select(Column.deserializeJson(expr.serializeJson())), "c", 2, 3)
+    assertColumnValues(df.select(Column.deserializeBinary(expr.serializeBinary())), "c", 2, 3)
+  }
+
+  test("malformed or unversioned input is rejected") {
+    a[RuntimeException] should be thrownBy LazyFrame.deserializeJson("{}")
+    a[RuntimeException] should be thrownBy LazyFrame.deserializeBinary(Array[Byte](1, 2, 3))
+    a[RuntimeException] should be thrownBy Column.deserializeJson("""{"expr": {}}""")
+    a[RuntimeException] should be thrownBy Column.deserializeBinary("EXPR".getBytes)
+  }
+}
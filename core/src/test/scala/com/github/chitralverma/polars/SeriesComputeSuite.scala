@@ -0,0 +1,51 @@
+package com.github.chitralverma.polars
+
+import com.github.chitralverma.polars.api.Series
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests the eager Series compute API. */
+class SeriesComputeSuite extends PolarsTestBase {
+
+  private def longs: Series = Series.ofLong("a", Array(3L, 1L, 2L, 4L))
+
+  test("reductions return boxed scalars") {
+    val s = longs
+
+    s.sum shouldBe 10L
+    s.min shouldBe 1L
+    s.max shouldBe 4L
+    s.mean shouldBe 2.5
+    s.median shouldBe 2.5
+    s.variance(ddof = 0) shouldBe 1.25
+    s.std(ddof = 0) shouldBe math.sqrt(1.25)
+    s.quantile(0.5, "lower") shouldBe 2.0
+  }
+
+  test("invalid quantile methods and ddof are rejected") {
+    a[RuntimeException] should be thrownBy longs.quantile(0.5, "average")
+    a[RuntimeException] should be thrownBy longs.std(ddof = -1)
+  }
+
+  test("dtype round-trips through cast") {
+    val dt = Series.ofType("a", "datetime[ms, UTC]", Array[Any](null)).dtype
+
+    jsonMapper.readTree(dt).get("time_zone").asText shouldBe "UTC"
+    longs.cast(dt).dtype shouldBe dt
+  }
+
+  test("binary operators work with series and scalars") {
+    val s = longs
+
+    (s + 1L).toLongArray shouldBe Array(4L, 2L, 3L, 5L)
+    (s * s).toLongArray shouldBe Array(9L, 1L, 4L, 16L)
+    s.filter(s > 2L).toLongArray shouldBe Array(3L, 4L)
+    s.sort().seriesEquals(Series.ofLong("a", Array(1L, 2L, 3L, 4L))) shouldBe true
+  }
+
+  test("head and tail with a negative n drop values from the other end") {
+    longs.head(2).toLongArray shouldBe Array(3L, 1L)
+    longs.head(-1).toLongArray shouldBe Array(3L, 1L, 2L)
+    longs.tail(-3).toLongArray shouldBe Array(4L)
+    longs.tail(-10).toLongArray shouldBe Array.empty[Long]
+  }
+}
@@ -0,0 +1,45 @@
+package com.github.chitralverma.polars
+
+import java.math.{BigDecimal => JBigDecimal}
+import java.time.{Duration => JDuration}
+
+import com.github.chitralverma.polars.api.{DataFrame, Series}
+import com.github.chitralverma.polars.api.types.DataTypes
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests the nullable and typed Series constructors. */
+class SeriesConstructorSuite extends PolarsTestBase {
+
+  test("ofDuration keeps nanoseconds and agrees with lit") {
+    val duration = JDuration.ofSeconds(1, 234)
+    val df = DataFrame.fromSeries(Series.ofDuration("d", Array(duration, null)))
+
+    assertColumnValues(df.select(col("d").cast(DataTypes.Int64)), "d", 1000000234L, null)
+    assertRowCount(df.filter(col("d") === lit(duration)), 1)
+  }
+
+  test("ofDecimal builds decimals from unscaled longs with a validity mask") {
+    val df = DataFrame.fromSeries(
+      Series.ofDecimal("d", Array(12345L, 0L), 10, 2, Array(true, false))
+    )
+
+    assertColumnValues(df, "d", new JBigDecimal("123.45"), null)
+  }
+
+  test("ofDecimal rejects values with more digits than the precision") {
+    a[RuntimeException] should be thrownBy Series.ofDecimal("d", Array(123456L), 5, 2)
+    a[RuntimeException] should be thrownBy
+      Series.ofDecimal("d", Array(new JBigDecimal("1234.5")), 4, 1)
+
+    val masked = Series.ofDecimal("d", Array(99999L, 123456L), 5, 2, Array(true, false))
+    assertColumnValues(DataFrame.fromSeries(masked), "d", new JBigDecimal("999.99"), null)
+  }
+
+  test("ofFloat16 rounds to half precision") {
+    val df = DataFrame.fromSeries(Series.ofFloat16("f", Array(1.5f, 0f), Array(true, false)))
+
+    df.schemaJson should include("float16")
+    assertColumnValues(df, "f", 1.5f, null)
+  }
+}
@@ -0,0 +1,46 @@
+package com.github.chitralverma.polars
+
+import java.nio.{ByteBuffer, ByteOrder}
+
+import com.github.chitralverma.polars.api.Series
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests bulk export of Series values to Java arrays and direct buffers. */
+class SeriesExportSuite extends PolarsTestBase {
+
+  private def nullableLongs: Series =
+    Series.ofType("a", "int64", Array[Any](1L, null, 3L))
+
+  test("primitive arrays hold the values with nulls as zero") {
+    val s = nullableLongs
+
+    s.toLongArray shouldBe Array(1L, 0L, 3L)
+    s.toDoubleArray shouldBe Array(1.0, 0.0, 3.0)
+    s.toStringArray shouldBe Array("1", null, "3")
+  }
+
+  test("validity marks the non-null values") {
+    nullableLongs.validity.map(_.toString) shouldBe Some("{0, 2}")
+    Series.ofLong("a", Array(1L, 2L)).validity shouldBe None
+  }
+
+  test("fillDirectBuffer round-trips through ofDirectBuffer") {
+    val data = ByteBuffer.allocateDirect(8 + 3 * 8).order(ByteOrder.nativeOrder())
+    val validity = ByteBuffer.allocateDirect(1)
+    data.position(8)
+
+    nullableLongs.fillDirectBuffer("int64", data, validity) shouldBe 3L
+    data.position() shouldBe 8
+    validity.get(0) shouldBe 5.toByte
+
+    val back = Series.ofDirectBuffer("a", "int64", data, 3, validity)
+    back.toStringArray shouldBe Array("1", null, "3")
+  }
+
+  test("fillDirectBuffer rejects buffers without room for the values") {
+    val data = ByteBuffer.allocateDirect(3 * 8).order(ByteOrder.nativeOrder())
+    data.limit(16)
+
+    a[RuntimeException] should be thrownBy nullableLongs.fillDirectBuffer("int64", data)
+  }
+}
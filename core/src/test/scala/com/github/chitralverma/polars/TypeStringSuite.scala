@@ -0,0 +1,53 @@
+package com.github.chitralverma.polars
+
+import java.math.{BigDecimal => JBigDecimal, BigInteger}
+
+import com.github.chitralverma.polars.api.{DataFrame, Series}
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests the shared DataType string grammar through casts and typed Series constructors. */
+class TypeStringSuite extends PolarsTestBase {
+
+  test("cast accepts type strings, including float16 and uint128") {
+    val df = longFrame("a", 3L).select(
+      col("a").cast("decimal(10, 2)").alias("d"),
+      col("a").cast("float16").alias("f16"),
+      col("a").cast("uint128").alias("u128")
+    )
+
+    assertColumnValues(df, "d", new JBigDecimal("3.00"))
+    assertColumnValues(df, "f16", 3.0f)
+    assertColumnValues(df, "u128", BigInteger.valueOf(3))
+  }
+
+  test("cast rejects unknown types and honours strictness") {
+    val df = stringFrame("a", "1", "x")
+
+    a[RuntimeException] should be thrownBy df.select(col("a").cast("int33"))
+    a[RuntimeException] should be thrownBy df.select(col("a").cast("int32", strict = true))
+    assertColumnValues(df.select(col("a").cast("int32", strict = false)), "a", 1, null)
+  }
+
+  test("ofType builds a series of the requested type") {
+    val df = DataFrame.fromSeries(
+      Series.ofType("u", "uint64", Array[Any](1L, null)),
+      Series.ofType("d", "decimal(5, 1)", Array[Any](new JBigDecimal("1.5"), null))
+    )
+
+    assertColumnValues(df, "u", BigInteger.ONE, null)
+    assertColumnValues(df, "d", new JBigDecimal("1.5"), null)
+    a[RuntimeException] should be thrownBy Series.ofType("s", "int8", Array[Any]("x"))
+  }
+
+  test("datetime types accept fixed offsets and struct fields need no space after `:`") {
+    val df = longFrame("a", 0L).select(
+      col("a").cast("datetime[ms, +02:00]").alias("t"),
+      litStruct("a" -> 1L).cast("struct<a:int32>").alias("s")
+    )
+
+    val types = df.dtypes.map(jsonMapper.readTree(_))
+    types.head.get("time_zone").asText shouldBe "Etc/GMT-2"
+    types(1).get("fields").get(0).get("dtype").get("type").asText shouldBe "int32"
+  }
+}
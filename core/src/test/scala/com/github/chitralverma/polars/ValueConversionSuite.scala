@@ -0,0 +1,35 @@
+package com.github.chitralverma.polars
+
+import java.math.{BigDecimal => JBigDecimal, BigInteger}
+import java.time.{Duration => JDuration}
+
+import com.github.chitralverma.polars.api.types.DataTypes
+import com.github.chitralverma.polars.functions._
+import com.github.chitralverma.polars.testing.PolarsTestBase
+
+/** Tests that values read back from rows keep their full precision as JVM objects. */
+class ValueConversionSuite extends PolarsTestBase {
+
+  test("unsigned integers widen to the next JVM type that holds them") {
+    val df = longFrame("a", 7L).select(
+      col("a").cast(DataTypes.UInt32).alias("u32"),
+      col("a").cast(DataTypes.UInt64).alias("u64")
+    )
+
+    assertColumnValues(df, "u32", 7L)
+    assertColumnValues(df, "u64", BigInteger.valueOf(7))
+  }
+
+  test("decimals convert to BigDecimal without going through a double") {
+    val df = stringFrame("a", "12345678901234567.89")
+      .select(col("a").cast(DataTypes.decimal(38, 2)).alias("d"))
+
+    assertColumnValues(df, "d", new JBigDecimal("12345678901234567.89"))
+  }
+
+  test("durations convert to java.time.Duration") {
+    val df = longFrame("a", 1500L).select(col("a").cast(DataTypes.duration("ms")).alias("d"))
+
+    assertColumnValues(df, "d", JDuration.ofMillis(1500))
+  }
+}
@@ -0,0 +1,92 @@
+package com.github.chitralverma.polars.testing
+
+import java.nio.{ByteBuffer, ByteOrder}
+import java.nio.charset.StandardCharsets
+
+/** Decodes the Thrift footer of a Parquet file so suites can assert on written metadata. Structs
+  * become maps from field ids to values, lists become `Seq`s, integers `Long`s and binary
+  * fields `String`s.
+  */
+object ParquetFooter {
+
+  def apply(file: Array[Byte]): Map[Int, Any] = {
+    val length = ByteBuffer
+      .wrap(file, file.length - 8, 4)
+      .order(ByteOrder.LITTLE_ENDIAN)
+      .getInt
+    new Decoder(file, file.length - 8 - length).struct()
+  }
+
+  /** Reads the Thrift compact protocol. */
+  private class Decoder(bytes: Array[Byte], private var pos: Int) {
+
+    private def byte(): Int = {
+      val b = bytes(pos) & 0xff
+      pos += 1
+      b
+    }
+
+    private def varint(): Long = {
+      var result = 0L
+      var shift = 0
+      var more = true
+      while (more) {
+        val b = byte()
+        result |= (b & 0x7fL) << shift
+        shift += 7
+        more = (b & 0x80) != 0
+      }
+      result
+    }
+
+    private def zigzag(): Long = {
+      val n = varint()
+      (n >>> 1) ^ -(n & 1)
+    }
+
+    def struct(): Map[Int, Any] = {
+      var fields = Map.empty[Int, Any]
+      var id = 0
+      var header = byte()
+      while (header != 0) {
+        val delta = header >> 4
+        id = if (delta == 0) zigzag().toInt else id + delta
+        fields += id -> ((header & 0x0f) match {
+          case 1 => true
+          case 2 => false
+          case tpe => value(tpe)
+        })
+        header = byte()
+      }
+      fields
+    }
+
+    private def value(tpe: Int): Any = tpe match {
+      case 1 | 2 => byte() == 1
+      case 3 => byte().toByte.toLong
+      case 4 | 5 | 6 => zigzag()
+      case 7 =>
+        val d = ByteBuffer.wrap(bytes, pos, 8).order(ByteOrder.LITTLE_ENDIAN).getDouble
+        pos += 8
+        d
+      case 8 =>
+        val n = varint().toInt
+        val s = new String(bytes, pos, n, StandardCharsets.UTF_8)
+        pos += n
+        s
+      case 9 | 10 =>
+        val header = byte()
+        val size = if ((header >> 4) == 15) varint().toInt else header >> 4
+        Seq.fill(size)(value(header & 0x0f))
+      case 11 =>
+        val size = varint().toInt
+        if (size == 0) Map.empty
+        else {
+          val types = byte()
+          Seq.fill(size)(value(types >> 4) -> value(types & 0x0f)).toMap
+        }
+      case 12 => struct()
+      case other => throw new IllegalArgumentException(s"Unknown Thrift type $other")
+    }
+  }
+}
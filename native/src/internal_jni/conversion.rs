@@ -4,8 +4,6 @@ use jni::objects::*;
 use jni::sys::*;
 use jni::{Env, jni_sig, jni_str};
 use polars::prelude::*;
-use rust_decimal::Decimal;
-use rust_decimal::prelude::ToPrimitive;
 
 use crate::internal_jni::utils::string_to_j_string;
 
@@ -49,6 +47,22 @@ impl JavaArrayToVec for JBooleanArray<'_> {
     }
 }
 
+impl JavaArrayToVec for JByteArray<'_> {
+    type Output = i8;
+
+    fn to_vec(env: &mut Env, array: Self) -> anyhow::Result<Vec<Self::Output>> {
+        Ok(read_primitive_array(env, &array, |&v| v))
+    }
+}
+
+impl JavaArrayToVec for JShortArray<'_> {
+    type Output = i16;
+
+    fn to_vec(env: &mut Env, array: Self) -> anyhow::Result<Vec<Self::Output>> {
+        Ok(read_primitive_array(env, &array, |&v| v))
+    }
+}
+
 impl JavaArrayToVec for JIntArray<'_> {
     type Output = i32;
 
@@ -193,14 +207,18 @@ impl<'a> IntoJava<'a> for Series {
 impl<'a> IntoJava<'a> for AnyValueWrapper<'_> {
     fn try_into_java(self, env: &mut Env<'a>) -> anyhow::Result<JObject<'a>> {
         let obj = match self.0 {
+            AnyValue::Null => JObject::null(),
             AnyValue::Int8(v) => box_integer(env, v as i32)?,
             AnyValue::Int16(v) => box_integer(env, v as i32)?,
             AnyValue::Int32(v) => box_integer(env, v)?,
             AnyValue::UInt8(v) => box_integer(env, v as i32)?,
             AnyValue::UInt16(v) => box_integer(env, v as i32)?,
-            AnyValue::UInt32(v) => box_integer(env, v as i32)?,
+            AnyValue::UInt32(v) => box_long(env, v as i64)?,
             AnyValue::Int64(v) => box_long(env, v)?,
-            AnyValue::UInt64(v) => box_long(env, v as i64)?,
+            AnyValue::UInt64(v) => box_big_integer(env, v)?,
+            AnyValue::Int128(v) => box_big_integer(env, v)?,
+            AnyValue::UInt128(v) => box_big_integer(env, v)?,
+            AnyValue::Float16(v) => box_float(env, f32::from(v))?,
             AnyValue::Float32(v) => box_float(env, v)?,
             AnyValue::Float64(v) => box_double(env, v)?,
             AnyValue::Date(days) => box_date(env, days as i64)?,
@@ -209,17 +227,13 @@ impl<'a> IntoJava<'a> for AnyValueWrapper<'_> {
                 box_datetime(env, nanos, tu, tz.as_ref().map(|v| *v))?
             },
             AnyValue::DatetimeOwned(nanos, tu, tz) => box_datetime(env, nanos, tu, tz.as_deref())?,
+            AnyValue::Duration(v, tu) => box_duration(env, v, tu)?,
             AnyValue::List(s) => s.try_into_java(env)?,
-            AnyValue::Array(s, _) => s.try_into_java(env)?,
+            AnyValue::Array(s, width) => box_fixed_size_array(env, s, width)?,
             AnyValue::Binary(slice) => slice.try_into_java(env)?,
             AnyValue::BinaryOwned(v) => v.as_slice().try_into_java(env)?,
             AnyValue::Boolean(v) => box_boolean(env, v)?,
-            AnyValue::Decimal(num, _precision, scale) => {
-                match Decimal::from_i128_with_scale(num, scale as u32).to_f64() {
-                    Some(v) => box_double(env, v)?,
-                    None => JObject::null(),
-                }
-            },
+            AnyValue::Decimal(num, _precision, scale) => box_big_decimal(env, num, scale)?,
             AnyValue::String(s) => {
                 let jstr = string_to_j_string(env, s)
                     .context(format!("Failed to parse string value `{s}` in row"))?;
@@ -230,10 +244,25 @@ impl<'a> IntoJava<'a> for AnyValueWrapper<'_> {
                     .context(format!("Failed to parse string value `{s}` in row"))?;
                 unsafe { JObject::from_raw(env, jstr) }
             },
+            ref av @ (AnyValue::Categorical(..)
+            | AnyValue::CategoricalOwned(..)
+            | AnyValue::Enum(..)
+            | AnyValue::EnumOwned(..)) => {
+                let category = av
+                    .get_str()
+                    .context(format!("Failed to get category of value `{av}` in row"))?;
+                let jstr = string_to_j_string(env, category).context(format!(
+                    "Failed to parse category value `{category}` in row"
+                ))?;
+                unsafe { JObject::from_raw(env, jstr) }
+            },
             AnyValue::Struct(row_idx, arr, _) => {
                 arr.clone().sliced(row_idx, 1).try_into_java(env)?
             },
-            _ => JObject::null(),
+            AnyValue::StructOwned(payload) => {
+                let (values, fields) = *payload;
+                box_struct_values(env, values, &fields)?
+            },
         };
         Ok(obj)
     }
@@ -308,6 +337,119 @@ pub fn box_double<'a, T: Into<JValue<'a>>>(
     )
 }
 
+/// Boxes any integer (including `u64`/`i128`, which overflow `java.lang.Long`) into a
+/// `java.math.BigInteger` via its decimal string form.
+pub fn box_big_integer<'a>(
+    env: &mut Env<'a>,
+    value: impl std::fmt::Display,
+) -> anyhow::Result<JObject<'a>> {
+    let digits = env
+        .new_string(value.to_string())
+        .context("Failed to build digits of BigInteger value")?;
+
+    env.new_object(
+        jni_str!("java/math/BigInteger"),
+        jni_sig!("(Ljava/lang/String;)V"),
+        &[JValue::Object(&digits)],
+    )
+    .context(format!("Failed to box value `{value}` as BigInteger"))
+}
+
+/// Boxes a polars decimal, stored as an unscaled `i128` plus `scale`, into a `java.math.BigDecimal`
+/// without going through a lossy floating-point representation.
+pub fn box_big_decimal<'a>(
+    env: &mut Env<'a>,
+    unscaled: i128,
+    scale: usize,
+) -> anyhow::Result<JObject<'a>> {
+    let unscaled_obj = box_big_integer(env, unscaled)?;
+
+    env.new_object(
+        jni_str!("java/math/BigDecimal"),
+        jni_sig!("(Ljava/math/BigInteger;I)V"),
+        &[JValue::Object(&unscaled_obj), JValue::Int(scale as jint)],
+    )
+    .context(format!(
+        "Failed to box value `{unscaled}` with scale `{scale}` as BigDecimal"
+    ))
+}
+
+pub fn box_duration<'a>(
+    env: &mut Env<'a>,
+    value: i64,
+    time_unit: TimeUnit,
+) -> anyhow::Result<JObject<'a>> {
+    let units_per_second = match time_unit {
+        TimeUnit::Nanoseconds => 1_000_000_000,
+        TimeUnit::Microseconds => 1_000_000,
+        TimeUnit::Milliseconds => 1_000,
+    };
+    let nanos_per_unit = 1_000_000_000 / units_per_second;
+
+    env.call_static_method(
+        jni_str!("java/time/Duration"),
+        jni_str!("ofSeconds"),
+        jni_sig!("(JJ)Ljava/time/Duration;"),
+        &[
+            JValue::Long(value.div_euclid(units_per_second)),
+            JValue::Long(value.rem_euclid(units_per_second) * nanos_per_unit),
+        ],
+    )
+    .and_then(|x| x.l())
+    .context(format!("Failed to box value `{value}` as Duration"))
+}
+
+/// Converts a fixed-size array value into an `Object[]` of exactly `width` elements, keeping it
+/// distinguishable from the `java.util.List` produced for variable-length lists.
+pub fn box_fixed_size_array<'a>(
+    env: &mut Env<'a>,
+    series: Series,
+    width: usize,
+) -> anyhow::Result<JObject<'a>> {
+    let j_array = JObjectArray::<JObject>::new(env, width, JObject::null())
+        .context("Failed to initialize array for fixed-size array values")?;
+
+    for (i, any_value) in series.iter().take(width).enumerate() {
+        let element_obj = AnyValueWrapper(any_value.clone()).try_into_java(env)?;
+        let element = element_obj.auto();
+        j_array.set_element(env, i, &element).context(format!(
+            "Failed to set value `{any_value}` in fixed-size array"
+        ))?;
+    }
+
+    Ok(j_array.into())
+}
+
+/// Converts the field values of an owned struct value into a `java.util.HashMap` keyed by field
+/// name, matching the representation of borrowed struct values.
+pub fn box_struct_values<'a>(
+    env: &mut Env<'a>,
+    values: Vec<AnyValue<'_>>,
+    fields: &[Field],
+) -> anyhow::Result<JObject<'a>> {
+    let map = env
+        .new_object(jni_str!("java/util/HashMap"), jni_sig!("()V"), &[])
+        .context("Failed to initialize map for struct field")?;
+
+    let j_map = JMap::cast_local(env, map).context("Failed to initialize map for struct field")?;
+
+    for (field, value) in fields.iter().zip(values) {
+        let name = field.name();
+        let name_jstr = string_to_j_string(env, name)
+            .context(format!("Failed to parse value `{name}` as a series name"))?;
+        let key_obj = unsafe { JObject::from_raw(env, name_jstr) };
+        let key = key_obj.auto();
+
+        let value_obj = AnyValueWrapper(value).try_into_java(env)?;
+        let value = value_obj.auto();
+
+        j_map
+            .put(env, &key, &value)
+            .context("Failed to put entry in map for struct field")?;
+    }
+    Ok(j_map.into())
+}
+
 pub fn box_date<'a, T: Into<JValue<'a>>>(
     env: &mut Env<'a>,
     value: T,
@@ -0,0 +1,307 @@
+//! Zero-copy construction of [`Series`] over the memory of direct `java.nio.ByteBuffer`s.
+//!
+//! Each buffer is wrapped in a polars [`Buffer`] whose owner holds a JNI global reference to the
+//! `ByteBuffer`, which keeps its memory alive. The reference is dropped once the last chunk
+//! referencing the memory is dropped, which may be long after the series that created it.
+//!
+//! In the other direction, a series can be written into caller-provided direct buffers in the same
+//! layout, so that the JVM reads the values without going through Java arrays.
+//!
+//! Direct buffers holding encoded files (parquet, CSV, ...) are likewise scanned in place, see
+//! [`buffer_from_direct_buffer`].
+
+use std::marker::PhantomData;
+
+use anyhow::{Context, bail, ensure};
+use jni::objects::JByteBuffer;
+use jni::refs::Global;
+use jni::{Env, jni_sig, jni_str};
+use polars::prelude::*;
+use polars_arrow::array::{Array, PrimitiveArray};
+use polars_arrow::bitmap::Bitmap;
+use polars_arrow::types::NativeType;
+use polars_buffer::{Buffer, SharedStorage};
+
+/// The values between the position and limit of a direct buffer, kept alive by a global
+/// reference to the buffer.
+pub(crate) struct DirectSlice<T> {
+    ptr: *const T,
+    len: usize,
+    _buffer: Global<JByteBuffer<'static>>,
+    _values: PhantomData<T>,
+}
+
+// SAFETY: the memory of a direct buffer is not tied to the thread that created it, and the global
+// reference keeping it alive may be dropped from any thread.
+unsafe impl<T> Send for DirectSlice<T> {}
+
+impl<T> AsRef<[T]> for DirectSlice<T> {
+    fn as_ref(&self) -> &[T] {
+        // SAFETY: `ptr` points to `len` aligned values that stay alive as long as `_buffer`.
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<T> DirectSlice<T> {
+    /// Borrows the bytes between the position and limit of the direct buffer `buf` as values of
+    /// `T`, checking that they start at an address aligned to `T`.
+    pub(crate) fn new(env: &mut Env, buf: &JByteBuffer, what: &str) -> anyhow::Result<Self> {
+        let address = env.get_direct_buffer_address(buf).context(format!(
+            "Failed to get the address of the {what} buffer, is it a direct buffer?"
+        ))?;
+        let capacity = env
+            .get_direct_buffer_capacity(buf)
+            .context(format!("Failed to get the capacity of the {what} buffer"))?;
+        let position = buffer_index(env, buf, jni_str!("position"), what)?;
+        let limit = buffer_index(env, buf, jni_str!("limit"), what)?;
+        ensure!(
+            position <= limit && limit <= capacity,
+            "The {what} buffer has an invalid position {position} and limit {limit}"
+        );
+
+        let ptr = address.wrapping_add(position) as *const T;
+        let width = size_of::<T>();
+        ensure!(
+            ptr.is_aligned(),
+            "The {what} buffer address is not aligned to the {width} byte width of its values"
+        );
+
+        let buffer = env.new_global_ref(buf).context(format!(
+            "Failed to create a global reference to the {what} buffer"
+        ))?;
+
+        Ok(Self {
+            ptr,
+            len: (limit - position) / width,
+            _buffer: buffer,
+            _values: PhantomData,
+        })
+    }
+}
+
+/// Reads `position()` or `limit()` of `buf`.
+fn buffer_index(
+    env: &mut Env,
+    buf: &JByteBuffer,
+    method: &'static jni::strings::JNIStr,
+    what: &str,
+) -> anyhow::Result<usize> {
+    let index = env
+        .call_method(buf, method, jni_sig!("()I"), &[])
+        .and_then(|v| v.i())
+        .context(format!("Failed to call {method} on the {what} buffer"))?;
+    Ok(index as usize)
+}
+
+/// Byte width of the values of `dtype`, for the fixed-width types that can be backed by a direct
+/// buffer: numeric types and the temporal types stored as integers.
+fn value_width(dtype: &DataType) -> Option<usize> {
+    match dtype {
+        DataType::Int8 | DataType::UInt8 => Some(1),
+        DataType::Int16 | DataType::UInt16 => Some(2),
+        DataType::Int32 | DataType::UInt32 | DataType::Float32 | DataType::Date => Some(4),
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Datetime(_, _)
+        | DataType::Duration(_)
+        | DataType::Time => Some(8),
+        _ => None,
+    }
+}
+
+/// Wraps the first `len` values of `data` in an array of `dtype`, whose physical type is `T`.
+fn direct_array<T: NativeType>(
+    env: &mut Env,
+    dtype: &DataType,
+    data: &JByteBuffer,
+    len: usize,
+    validity: Option<Bitmap>,
+) -> anyhow::Result<ArrayRef> {
+    let values = DirectSlice::<T>::new(env, data, "data")?;
+    let available = values.as_ref().len();
+    ensure!(
+        available >= len,
+        "Data buffer holds {available} values, but {len} values of type `{dtype}` were requested"
+    );
+
+    let values = Buffer::from_owner(values).sliced(..len);
+    let array = PrimitiveArray::try_new(dtype.to_arrow(CompatLevel::newest()), values, validity)
+        .context("Failed to wrap the direct buffers as an arrow array")?;
+    Ok(array.boxed())
+}
+
+/// Wraps `len` values of `dtype` stored in native byte order in the direct buffer `data`, with an
+/// optional Arrow validity bitmap (one bit per value, least significant bit first, set for valid
+/// values) in `validity`, as a [`Series`] without copying either buffer. Both buffers are read
+/// from their position.
+///
+/// The data must start at an address aligned to the value width. The Java side must not modify
+/// the buffers afterwards.
+pub fn series_from_direct_buffers(
+    env: &mut Env,
+    name: PlSmallStr,
+    dtype: &DataType,
+    data: &JByteBuffer,
+    len: usize,
+    validity: Option<&JByteBuffer>,
+) -> anyhow::Result<Series> {
+    value_width(dtype).context(format!(
+        "Type `{dtype}` cannot be backed by a direct buffer, expected a fixed-width numeric or \
+         temporal type"
+    ))?;
+
+    let validity = match validity {
+        Some(validity) => {
+            let bytes = DirectSlice::<u8>::new(env, validity, "validity")?;
+            let available = bytes.as_ref().len();
+            ensure!(
+                available >= len.div_ceil(8),
+                "Validity buffer holds {available} bytes, but {len} values need {}",
+                len.div_ceil(8)
+            );
+            // SAFETY: the storage was checked to hold at least `len` bits.
+            Some(unsafe {
+                Bitmap::from_inner_unchecked(SharedStorage::from_owner(bytes), 0, len, None)
+            })
+        },
+        None => None,
+    };
+
+    let array = match dtype.to_physical() {
+        DataType::Int8 => direct_array::<i8>(env, dtype, data, len, validity)?,
+        DataType::Int16 => direct_array::<i16>(env, dtype, data, len, validity)?,
+        DataType::Int32 => direct_array::<i32>(env, dtype, data, len, validity)?,
+        DataType::Int64 => direct_array::<i64>(env, dtype, data, len, validity)?,
+        DataType::UInt8 => direct_array::<u8>(env, dtype, data, len, validity)?,
+        DataType::UInt16 => direct_array::<u16>(env, dtype, data, len, validity)?,
+        DataType::UInt32 => direct_array::<u32>(env, dtype, data, len, validity)?,
+        DataType::UInt64 => direct_array::<u64>(env, dtype, data, len, validity)?,
+        DataType::Float32 => direct_array::<f32>(env, dtype, data, len, validity)?,
+        DataType::Float64 => direct_array::<f64>(env, dtype, data, len, validity)?,
+        physical => bail!("Unexpected physical type `{physical}` for type `{dtype}`"),
+    };
+
+    Series::from_arrow(name, array).context("Failed to create series from the direct buffers")
+}
+
+/// Returns the address of the position of the direct buffer `buf` after checking that at least
+/// `required` bytes remain before its limit.
+fn writable_address(
+    env: &mut Env,
+    buf: &JByteBuffer,
+    required: usize,
+    what: &str,
+) -> anyhow::Result<*mut u8> {
+    let address = env.get_direct_buffer_address(buf).context(format!(
+        "Failed to get the address of the {what} buffer, is it a direct buffer?"
+    ))?;
+    let position = buffer_index(env, buf, jni_str!("position"), what)?;
+    let limit = buffer_index(env, buf, jni_str!("limit"), what)?;
+    let remaining = limit.saturating_sub(position);
+    ensure!(
+        remaining >= required,
+        "The {what} buffer has {remaining} bytes remaining, but {required} are needed"
+    );
+    Ok(address.wrapping_add(position))
+}
+
+/// Copies the values of `ca` into `dst`, with nulls as zero.
+///
+/// # Safety
+/// `dst` must be valid for writes of `ca.len()` values and aligned to the value width.
+unsafe fn write_values<T: PolarsNumericType>(ca: &ChunkedArray<T>, dst: *mut u8) {
+    let dst = unsafe { std::slice::from_raw_parts_mut(dst as *mut T::Native, ca.len()) };
+
+    let mut offset = 0;
+    for arr in ca.downcast_iter() {
+        let out = &mut dst[offset..offset + arr.len()];
+        if arr.null_count() == 0 {
+            out.copy_from_slice(arr.values());
+        } else {
+            for (o, v) in out.iter_mut().zip(arr.iter()) {
+                *o = v.copied().unwrap_or_default();
+            }
+        }
+        offset += arr.len();
+    }
+}
+
+/// Calls `f` with the index of every non-null value of `series`, reading the validity bitmaps of
+/// its chunks.
+pub(crate) fn for_each_valid(series: &Series, mut f: impl FnMut(usize)) {
+    let mut offset = 0;
+    for arr in series.chunks() {
+        match arr.validity() {
+            Some(bitmap) => bitmap.true_idx_iter().for_each(|i| f(offset + i)),
+            None => (offset..offset + arr.len()).for_each(&mut f),
+        }
+        offset += arr.len();
+    }
+}
+
+/// Writes the values of `series` cast to the fixed-width `dtype` into the direct buffer `data` in
+/// native byte order, with nulls as zero, and if given, an Arrow validity bitmap into `validity`.
+/// Both buffers are written from their position, which is left unchanged. This is the inverse of
+/// [`series_from_direct_buffers`]. Returns the number of values written.
+pub fn fill_direct_buffers(
+    env: &mut Env,
+    series: &Series,
+    dtype: &DataType,
+    data: &JByteBuffer,
+    validity: Option<&JByteBuffer>,
+) -> anyhow::Result<usize> {
+    let width = value_width(dtype).context(format!(
+        "Type `{dtype}` cannot be written to a direct buffer, expected a fixed-width numeric or \
+         temporal type"
+    ))?;
+
+    let cast = series.strict_cast(dtype).context(format!(
+        "Failed to cast series `{}` of type `{}` to `{dtype}`",
+        series.name(),
+        series.dtype()
+    ))?;
+    let physical = cast.to_physical_repr();
+    let len = physical.len();
+
+    let data_ptr = writable_address(env, data, len * width, "data")?;
+    ensure!(
+        (data_ptr as usize).is_multiple_of(width),
+        "Data buffer address is not aligned to the {width} byte width of type `{dtype}`"
+    );
+
+    // SAFETY: the buffer was checked to hold `len` values of the physical type, and its alignment.
+    unsafe {
+        match physical.dtype() {
+            DataType::Int8 => write_values(physical.i8()?, data_ptr),
+            DataType::Int16 => write_values(physical.i16()?, data_ptr),
+            DataType::Int32 => write_values(physical.i32()?, data_ptr),
+            DataType::Int64 => write_values(physical.i64()?, data_ptr),
+            DataType::UInt8 => write_values(physical.u8()?, data_ptr),
+            DataType::UInt16 => write_values(physical.u16()?, data_ptr),
+            DataType::UInt32 => write_values(physical.u32()?, data_ptr),
+            DataType::UInt64 => write_values(physical.u64()?, data_ptr),
+            DataType::Float32 => write_values(physical.f32()?, data_ptr),
+            DataType::Float64 => write_values(physical.f64()?, data_ptr),
+            dt => bail!("Unexpected physical type `{dt}` for type `{dtype}`"),
+        }
+    }
+
+    if let Some(validity) = validity {
+        let n_bytes = len.div_ceil(8);
+        let ptr = writable_address(env, validity, n_bytes, "validity")?;
+        let bitmap = unsafe { std::slice::from_raw_parts_mut(ptr, n_bytes) };
+
+        bitmap.fill(0);
+        for_each_valid(&physical, |i| bitmap[i / 8] |= 1 << (i % 8));
+    }
+
+    Ok(len)
+}
+
+/// Wraps the bytes between the position and limit of the direct buffer `buf` as a [`Buffer`]
+/// without copying them. The Java side must not modify the buffer afterwards.
+pub fn buffer_from_direct_buffer(env: &mut Env, buf: &JByteBuffer) -> anyhow::Result<Buffer<u8>> {
+    let bytes = DirectSlice::<u8>::new(env, buf, "source")?;
+    Ok(Buffer::from_owner(bytes))
+}
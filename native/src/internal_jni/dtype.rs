@@ -0,0 +1,466 @@
+//! A compact grammar for polars data types, shared by every entry point that accepts a type from
+//! the JVM (casts, schema overrides and Series constructors).
+//!
+//! Names are case-insensitive and follow polars' own display names where one exists:
+//!
+//! ```text
+//! int8 .. int128, uint8 .. uint128 (or i8 .., u8 ..), float16 .. float64 (or f16 ..),
+//! boolean, string (or str, utf8), binary, date, time, null, categorical
+//! datetime, datetime[ms], datetime[ms, UTC], datetime[ms, +02:00]
+//! duration, duration[us]
+//! decimal, decimal(18, 4), decimal[18, 4]
+//! list[int64]
+//! array[float32, 128]
+//! struct<a: int64, b: list[string]>
+//! enum[a, b, 'c d']
+//! ```
+//!
+//! Input starting with `{` or `"` is instead read as JSON: either the stable contract produced by
+//! [`dtype_to_json`] (an object with a `type` key), or polars' serde representation, so types
+//! already serialized by the Scala `DataType.ffiName` keep working.
+//!
+//! The JSON contract describes polars' own type tree rather than its Arrow mapping, so it keeps
+//! categorical vs string, enum categories, decimal precision/scale and time zones:
+//!
+//! ```text
+//! {"type": "int64"}
+//! {"type": "datetime", "time_unit": "ms", "time_zone": "UTC"}
+//! {"type": "duration", "time_unit": "us"}
+//! {"type": "decimal", "precision": 18, "scale": 4}
+//! {"type": "list", "inner": {...}}
+//! {"type": "array", "inner": {...}, "width": 128}
+//! {"type": "struct", "fields": [{"name": "a", "dtype": {...}}]}
+//! {"type": "categorical"}
+//! {"type": "enum", "categories": ["a", "b"]}
+//! {"type": "extension", "display": "...", "serde": ...}
+//! ```
+//!
+//! A schema is `{"fields": [{"name": "a", "dtype": {...}}, ...]}`, in column order.
+
+use anyhow::{Context, bail, ensure};
+use polars::prelude::*;
+use serde_json::{Value, json};
+
+use crate::internal_jni::utils::parse_time_unit;
+
+/// Parses `input` into a [`DataType`], see the module documentation for the grammar.
+pub fn parse_dtype(input: &str) -> anyhow::Result<DataType> {
+    let trimmed = input.trim();
+    if trimmed.starts_with('{') || trimmed.starts_with('"') {
+        let value: Value = serde_json::from_str(trimmed)
+            .context(format!("Failed to parse DataType JSON: {trimmed}"))?;
+        if value.get("type").is_some() {
+            return dtype_from_json(&value);
+        }
+        return serde_json::from_value(value)
+            .context(format!("Failed to deserialize DataType JSON: {trimmed}"));
+    }
+
+    let mut parser = Parser {
+        input: trimmed,
+        pos: 0,
+    };
+    let dtype = parser
+        .dtype()
+        .context(format!("Failed to parse DataType `{trimmed}`"))?;
+
+    parser.skip_whitespace();
+    ensure!(
+        parser.pos == trimmed.len(),
+        "Failed to parse DataType `{trimmed}`: unexpected input `{}`",
+        parser.rest()
+    );
+    Ok(dtype)
+}
+
+fn time_unit_name(time_unit: TimeUnit) -> &'static str {
+    match time_unit {
+        TimeUnit::Nanoseconds => "ns",
+        TimeUnit::Microseconds => "us",
+        TimeUnit::Milliseconds => "ms",
+    }
+}
+
+/// Describes `dtype` in the JSON contract documented on this module.
+pub fn dtype_to_json(dtype: &DataType) -> anyhow::Result<Value> {
+    let value = match dtype {
+        DataType::Boolean => json!({"type": "boolean"}),
+        DataType::UInt8 => json!({"type": "uint8"}),
+        DataType::UInt16 => json!({"type": "uint16"}),
+        DataType::UInt32 => json!({"type": "uint32"}),
+        DataType::UInt64 => json!({"type": "uint64"}),
+        DataType::UInt128 => json!({"type": "uint128"}),
+        DataType::Int8 => json!({"type": "int8"}),
+        DataType::Int16 => json!({"type": "int16"}),
+        DataType::Int32 => json!({"type": "int32"}),
+        DataType::Int64 => json!({"type": "int64"}),
+        DataType::Int128 => json!({"type": "int128"}),
+        DataType::Float16 => json!({"type": "float16"}),
+        DataType::Float32 => json!({"type": "float32"}),
+        DataType::Float64 => json!({"type": "float64"}),
+        DataType::String => json!({"type": "string"}),
+        DataType::Binary => json!({"type": "binary"}),
+        DataType::Date => json!({"type": "date"}),
+        DataType::Time => json!({"type": "time"}),
+        DataType::Null => json!({"type": "null"}),
+        DataType::Datetime(time_unit, time_zone) => json!({
+            "type": "datetime",
+            "time_unit": time_unit_name(*time_unit),
+            "time_zone": time_zone.as_ref().map(|tz| tz.as_str()),
+        }),
+        DataType::Duration(time_unit) => json!({
+            "type": "duration",
+            "time_unit": time_unit_name(*time_unit),
+        }),
+        DataType::Decimal(precision, scale) => json!({
+            "type": "decimal",
+            "precision": precision,
+            "scale": scale,
+        }),
+        DataType::List(inner) => json!({
+            "type": "list",
+            "inner": dtype_to_json(inner)?,
+        }),
+        DataType::Array(inner, width) => json!({
+            "type": "array",
+            "inner": dtype_to_json(inner)?,
+            "width": width,
+        }),
+        DataType::Struct(fields) => json!({
+            "type": "struct",
+            "fields": fields_to_json(fields.iter().map(|f| (f.name(), f.dtype())))?,
+        }),
+        DataType::Categorical(..) => json!({"type": "categorical"}),
+        DataType::Enum(categories, _) => json!({
+            "type": "enum",
+            "categories": categories.categories().values_iter().collect::<Vec<_>>(),
+        }),
+        // Extension types are only reconstructible through polars' own representation.
+        dtype @ DataType::Extension(..) => json!({
+            "type": "extension",
+            "display": dtype.to_string(),
+            "serde": serde_json::to_value(dtype).context("Failed to serialize extension type")?,
+        }),
+        dtype => bail!("Data type `{dtype}` cannot be described in the schema contract"),
+    };
+    Ok(value)
+}
+
+/// Reads a [`DataType`] back from the JSON contract documented on this module.
+pub fn dtype_from_json(value: &Value) -> anyhow::Result<DataType> {
+    let field = |key: &str| {
+        value
+            .get(key)
+            .context(format!("Missing `{key}` in DataType JSON: {value}"))
+    };
+    let number = |key: &str| -> anyhow::Result<usize> {
+        let n = field(key)?.as_u64().context(format!(
+            "Expected `{key}` to be a number in DataType JSON: {value}"
+        ))?;
+        Ok(n as usize)
+    };
+    let time_unit = || -> anyhow::Result<TimeUnit> {
+        parse_time_unit(field("time_unit")?.as_str().unwrap_or_default())
+    };
+
+    let type_name = field("type")?.as_str().context(format!(
+        "Expected `type` to be a string in DataType JSON: {value}"
+    ))?;
+
+    let dtype = match type_name {
+        "datetime" => {
+            let time_zone = match value.get("time_zone").and_then(Value::as_str) {
+                Some(tz) => TimeZone::opt_try_new(Some(tz))?,
+                None => None,
+            };
+            DataType::Datetime(time_unit()?, time_zone)
+        },
+        "duration" => DataType::Duration(time_unit()?),
+        "decimal" => {
+            let (precision, scale) = (number("precision")?, number("scale")?);
+            ensure!(
+                (1..=38).contains(&precision) && scale <= precision,
+                "invalid decimal precision `{precision}` and scale `{scale}` in DataType JSON, \
+                 expected 0 <= scale <= precision <= 38"
+            );
+            DataType::Decimal(precision, scale)
+        },
+        "list" => DataType::List(Box::new(dtype_from_json(field("inner")?)?)),
+        "array" => DataType::Array(
+            Box::new(dtype_from_json(field("inner")?)?),
+            number("width")?,
+        ),
+        "struct" => DataType::Struct(
+            schema_fields_from_json(field("fields")?)?
+                .into_iter()
+                .map(|(name, dtype)| Field::new(name.into(), dtype))
+                .collect(),
+        ),
+        "enum" => {
+            let categories = field("categories")?
+                .as_array()
+                .context(format!(
+                    "Expected `categories` to be an array in DataType JSON: {value}"
+                ))?
+                .iter()
+                .map(|c| c.as_str().context("Expected enum categories to be strings"))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            DataType::from_frozen_categories(FrozenCategories::new(categories)?)
+        },
+        "extension" => serde_json::from_value(field("serde")?.clone())
+            .context("Failed to deserialize extension type")?,
+        // Every other type is a plain name understood by the type string grammar.
+        name => parse_dtype(name)?,
+    };
+    Ok(dtype)
+}
+
+fn fields_to_json<'a>(
+    fields: impl Iterator<Item = (&'a PlSmallStr, &'a DataType)>,
+) -> anyhow::Result<Value> {
+    fields
+        .map(|(name, dtype)| Ok(json!({"name": name.as_str(), "dtype": dtype_to_json(dtype)?})))
+        .collect::<anyhow::Result<Vec<_>>>()
+        .map(Value::Array)
+}
+
+fn schema_fields_from_json(value: &Value) -> anyhow::Result<Vec<(String, DataType)>> {
+    value
+        .as_array()
+        .context(format!("Expected a list of fields, got: {value}"))?
+        .iter()
+        .map(|field| {
+            let name = field
+                .get("name")
+                .and_then(Value::as_str)
+                .context(format!("Missing field `name` in: {field}"))?;
+            let dtype = field
+                .get("dtype")
+                .context(format!("Missing field `dtype` in: {field}"))?;
+            Ok((name.to_string(), dtype_from_json(dtype)?))
+        })
+        .collect()
+}
+
+/// Serializes `schema` as `{"fields": [{"name": ..., "dtype": ...}, ...]}`.
+pub fn schema_to_json(schema: &Schema) -> anyhow::Result<String> {
+    let value = json!({ "fields": fields_to_json(schema.iter())? });
+    serde_json::to_string(&value).context("Failed to serialize schema")
+}
+
+/// Parses a schema written by [`schema_to_json`].
+pub fn schema_from_json(json: &str) -> anyhow::Result<Schema> {
+    let value: Value = serde_json::from_str(json).context("Failed to parse schema JSON")?;
+    let fields = value
+        .get("fields")
+        .context(format!("Missing `fields` in schema JSON: {json}"))?;
+
+    Ok(schema_fields_from_json(fields)?
+        .into_iter()
+        .map(|(name, dtype)| (name.into(), dtype))
+        .collect())
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.rest().chars().next()
+    }
+
+    /// Consumes `c` if it is the next non-whitespace character.
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> anyhow::Result<()> {
+        ensure!(self.eat(c), "expected `{c}` at `{}`", self.rest());
+        Ok(())
+    }
+
+    /// Reads a bare word (letters, digits, `_`, `/`, `+`, `-`, `.` and `μ`, enough for type
+    /// names and time zones) or a single- or double-quoted string.
+    fn word(&mut self) -> anyhow::Result<String> {
+        self.word_with(&[])
+    }
+
+    /// Reads a time zone like [`Parser::word`], also allowing the `:` of offsets like `+02:00`.
+    /// Other words exclude it since it separates struct field names from their types.
+    fn time_zone(&mut self) -> anyhow::Result<String> {
+        self.word_with(&[':'])
+    }
+
+    fn word_with(&mut self, extra: &[char]) -> anyhow::Result<String> {
+        match self.peek() {
+            Some(quote @ ('\'' | '"')) => {
+                self.pos += 1;
+                let rest = self.rest();
+                let end = rest
+                    .find(quote)
+                    .context(format!("unterminated quoted string at `{rest}`"))?;
+                self.pos += end + 1;
+                Ok(rest[..end].to_string())
+            },
+            _ => {
+                let rest = self.rest();
+                let end = rest
+                    .find(|c: char| {
+                        !(c.is_alphanumeric()
+                            || matches!(c, '_' | '/' | '+' | '-' | 'μ' | '.')
+                            || extra.contains(&c))
+                    })
+                    .unwrap_or(rest.len());
+                ensure!(end > 0, "expected a name at `{rest}`");
+                self.pos += end;
+                Ok(rest[..end].to_string())
+            },
+        }
+    }
+
+    fn number(&mut self) -> anyhow::Result<usize> {
+        let word = self.word()?;
+        word.parse()
+            .context(format!("expected a non-negative number, got `{word}`"))
+    }
+
+    fn dtype(&mut self) -> anyhow::Result<DataType> {
+        let name = self.word()?;
+
+        let dtype = match name.to_ascii_lowercase().as_str() {
+            "int8" | "i8" => DataType::Int8,
+            "int16" | "i16" => DataType::Int16,
+            "int32" | "i32" => DataType::Int32,
+            "int64" | "i64" => DataType::Int64,
+            "int128" | "i128" => DataType::Int128,
+            "uint8" | "u8" => DataType::UInt8,
+            "uint16" | "u16" => DataType::UInt16,
+            "uint32" | "u32" => DataType::UInt32,
+            "uint64" | "u64" => DataType::UInt64,
+            "uint128" | "u128" => DataType::UInt128,
+            "float16" | "f16" => DataType::Float16,
+            "float32" | "f32" => DataType::Float32,
+            "float64" | "f64" => DataType::Float64,
+            "boolean" | "bool" => DataType::Boolean,
+            "string" | "str" | "utf8" => DataType::String,
+            "binary" => DataType::Binary,
+            "date" => DataType::Date,
+            "time" => DataType::Time,
+            "null" => DataType::Null,
+            "categorical" | "cat" => DataType::from_categories(Categories::global()),
+            "datetime" => {
+                let (time_unit, time_zone) = if self.eat('[') {
+                    let time_unit = parse_time_unit(&self.word()?)?;
+                    let time_zone = if self.eat(',') {
+                        TimeZone::opt_try_new(Some(self.time_zone()?))?
+                    } else {
+                        None
+                    };
+                    self.expect(']')?;
+                    (time_unit, time_zone)
+                } else {
+                    (TimeUnit::Microseconds, None)
+                };
+                DataType::Datetime(time_unit, time_zone)
+            },
+            "duration" => {
+                let time_unit = if self.eat('[') {
+                    let time_unit = parse_time_unit(&self.word()?)?;
+                    self.expect(']')?;
+                    time_unit
+                } else {
+                    TimeUnit::Microseconds
+                };
+                DataType::Duration(time_unit)
+            },
+            "decimal" => {
+                let close = match self.peek() {
+                    Some('(') => Some(')'),
+                    Some('[') => Some(']'),
+                    _ => None,
+                };
+                let (precision, scale) = match close {
+                    Some(close) => {
+                        self.pos += 1;
+                        let precision = self.number()?;
+                        let scale = if self.eat(',') { self.number()? } else { 0 };
+                        self.expect(close)?;
+                        (precision, scale)
+                    },
+                    None => (38, 0),
+                };
+                ensure!(
+                    (1..=38).contains(&precision) && scale <= precision,
+                    "invalid decimal precision `{precision}` and scale `{scale}`, expected \
+                     0 <= scale <= precision <= 38"
+                );
+                DataType::Decimal(precision, scale)
+            },
+            "list" => {
+                self.expect('[')?;
+                let inner = self.dtype()?;
+                self.expect(']')?;
+                DataType::List(Box::new(inner))
+            },
+            "array" => {
+                self.expect('[')?;
+                let inner = self.dtype()?;
+                self.expect(',')?;
+                let width = self.number()?;
+                self.expect(']')?;
+                DataType::Array(Box::new(inner), width)
+            },
+            "struct" => {
+                self.expect('<')?;
+                let mut fields = Vec::new();
+                if !self.eat('>') {
+                    loop {
+                        let name = self.word()?;
+                        self.expect(':')?;
+                        fields.push(Field::new(name.into(), self.dtype()?));
+                        if self.eat('>') {
+                            break;
+                        }
+                        self.expect(',')?;
+                    }
+                }
+                DataType::Struct(fields)
+            },
+            "enum" => {
+                self.expect('[')?;
+                let mut categories = Vec::new();
+                if !self.eat(']') {
+                    loop {
+                        categories.push(self.word()?);
+                        if self.eat(']') {
+                            break;
+                        }
+                        self.expect(',')?;
+                    }
+                }
+                let categories = FrozenCategories::new(categories.iter().map(String::as_str))?;
+                DataType::from_frozen_categories(categories)
+            },
+            other => bail!("unknown data type `{other}`"),
+        };
+
+        Ok(dtype)
+    }
+}
@@ -1,16 +1,19 @@
 use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
-use anyhow::Context;
-use jni::objects::{JObject, JObjectArray, JString};
-use jni::sys::{jdouble, jint, jlong};
+use anyhow::{Context, bail};
+use jni::objects::{IntoAuto, JByteArray, JList, JMap, JObject, JObjectArray, JString, JValue};
+use jni::sys::{JNI_TRUE, jboolean, jdouble, jint, jlong};
 use jni::{Env, NativeMethod, jni_sig, jni_str, native_method};
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive;
+use polars::chunked_array::cast::CastOptions;
 use polars::prelude::*;
 use polars_core::series::ops::NullBehavior;
 
+use crate::internal_jni::dtype::parse_dtype;
 use crate::internal_jni::handle::{ExprHandle, Handle};
 use crate::internal_jni::macros::decl_free;
+use crate::internal_jni::serialization;
 use crate::internal_jni::utils::{j_object_ref_to_string, j_string_to_string};
 use crate::utils::error::ThrowRuntimeException;
 
@@ -217,36 +220,41 @@ fn cast<'local>(
         Some("Failed to parse provided DataType as string"),
     )?;
 
-    let dtype: DataType = if dt_str.starts_with('{') {
-        serde_json::from_str(&dt_str)
-            .context(format!("Failed to deserialize DataType JSON: {dt_str}"))?
+    let dtype = parse_dtype(&dt_str)?;
+
+    Ok(ExprHandle::alloc(l_expr.cast(dtype)))
+}
+
+const CAST_WITH_OPTIONS_METHOD: NativeMethod = col_method!(extern fn cast_with_options(expr: ExprHandle, data_type: java.lang.String, strict: jboolean, wrap_numerical: jboolean) -> ExprHandle, name = "castWithOptions",);
+
+/// Casts with explicit failure semantics: `strict` raises on values that cannot be converted,
+/// otherwise they become null, unless `wrap_numerical` is set in which case out-of-range numbers
+/// wrap around (e.g. `300` cast to `uint8` becomes `44`) instead.
+fn cast_with_options<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    expr: ExprHandle,
+    data_type: JString<'local>,
+    strict: jboolean,
+    wrap_numerical: jboolean,
+) -> anyhow::Result<ExprHandle> {
+    let l_expr = expr.get();
+    let dt_str = j_string_to_string(
+        env,
+        &data_type,
+        Some("Failed to parse provided DataType as string"),
+    )?;
+    let dtype = parse_dtype(&dt_str)?;
+
+    let options = if strict == JNI_TRUE {
+        CastOptions::Strict
+    } else if wrap_numerical == JNI_TRUE {
+        CastOptions::Overflowing
     } else {
-        // Fallback to basic type name parsing
-        match dt_str.to_lowercase().as_str() {
-            "int8" => DataType::Int8,
-            "int16" => DataType::Int16,
-            "int32" => DataType::Int32,
-            "int64" => DataType::Int64,
-            "uint8" => DataType::UInt8,
-            "uint16" => DataType::UInt16,
-            "uint32" => DataType::UInt32,
-            "uint64" => DataType::UInt64,
-            "float32" => DataType::Float32,
-            "float64" => DataType::Float64,
-            "boolean" => DataType::Boolean,
-            "string" | "utf8" => DataType::String,
-            "binary" => DataType::Binary,
-            "date" => DataType::Date,
-            "null" => DataType::Null,
-            _ => {
-                // Try serde_json with quoted string
-                serde_json::from_str(&format!("\"{dt_str}\""))
-                    .context(format!("Failed to parse DataType: {dt_str}"))?
-            },
-        }
+        CastOptions::NonStrict
     };
 
-    Ok(ExprHandle::alloc(l_expr.cast(dtype)))
+    Ok(ExprHandle::alloc(l_expr.cast_with_options(dtype, options)))
 }
 
 /// Decodes a boxed Java number/boolean into an [`AnyValue`] by checking its wrapper
@@ -267,10 +275,73 @@ macro_rules! unbox_java_value {
     };
 }
 
-fn jobject_to_any_value<'local>(
+/// Calls a no-arg Java method returning `long`.
+fn call_long_method(
+    env: &mut Env,
+    obj: &JObject,
+    method: &'static jni::strings::JNIStr,
+) -> anyhow::Result<i64> {
+    env.call_method(obj, method, jni_sig!("()J"), &[])
+        .and_then(|v| v.j())
+        .context(format!("Failed to call {method}"))
+}
+
+/// Calls a no-arg Java method returning `int`.
+fn call_int_method(
+    env: &mut Env,
+    obj: &JObject,
+    method: &'static jni::strings::JNIStr,
+) -> anyhow::Result<i32> {
+    env.call_method(obj, method, jni_sig!("()I"), &[])
+        .and_then(|v| v.i())
+        .context(format!("Failed to call {method}"))
+}
+
+/// Parses the decimal digits of a `java.math.BigInteger` into an `i128`.
+fn big_integer_to_i128(env: &mut Env, obj: &JObject) -> anyhow::Result<i128> {
+    let digits = env
+        .call_method(
+            obj,
+            jni_str!("toString"),
+            jni_sig!("()Ljava/lang/String;"),
+            &[],
+        )
+        .and_then(|v| v.l())
+        .context("Failed to call toString on BigInteger")?;
+    let digits = j_object_ref_to_string(env, &digits, Some("Invalid BigInteger"))?;
+
+    digits.parse::<i128>().context(format!(
+        "Value `{digits}` does not fit in a 128-bit integer"
+    ))
+}
+
+/// Combines an epoch second and nano adjustment, as exposed by `java.time`, into nanoseconds.
+fn epoch_nanos(seconds: i64, nanos: i64) -> anyhow::Result<i64> {
+    seconds
+        .checked_mul(1_000_000_000)
+        .and_then(|v| v.checked_add(nanos))
+        .context(format!(
+            "Timestamp `{seconds}s + {nanos}ns` is out of range for nanosecond precision"
+        ))
+}
+
+/// Decodes a `java.time.Instant` into nanoseconds since the epoch.
+fn instant_to_nanos(env: &mut Env, obj: &JObject) -> anyhow::Result<i64> {
+    let seconds = call_long_method(env, obj, jni_str!("getEpochSecond"))?;
+    let nanos = call_int_method(env, obj, jni_str!("getNano"))?;
+    epoch_nanos(seconds, nanos as i64)
+}
+
+/// Decodes a Java value into an owned [`AnyValue`].
+///
+/// Besides boxed primitives and `String`, this understands `java.time` dates, times and
+/// timestamps (decoded with nanosecond precision), `java.math.BigDecimal`/`BigInteger`, `byte[]`,
+/// and, recursively, `java.util.List` (as a list) and `java.util.Map` with string keys (as a
+/// struct with fields sorted by key). Any other type is an error rather than a silent null.
+pub(crate) fn jobject_to_any_value<'local>(
     env: &mut Env<'local>,
     obj: &JObject<'local>,
-) -> anyhow::Result<AnyValue<'local>> {
+) -> anyhow::Result<AnyValue<'static>> {
     if obj.is_null() {
         return Ok(AnyValue::Null);
     }
@@ -322,30 +393,257 @@ fn jobject_to_any_value<'local>(
         ),
     );
 
-    if env
-        .is_instance_of(obj, jni_str!("java/lang/String"))
-        .unwrap_or(false)
-    {
+    let is_instance = |env: &mut Env<'local>, class: &'static jni::strings::JNIStr| {
+        env.is_instance_of(obj, class).unwrap_or(false)
+    };
+
+    if is_instance(env, jni_str!("java/lang/String")) {
         let s = j_object_ref_to_string(env, obj, Some("Invalid String"))?;
         return Ok(AnyValue::StringOwned(s.into()));
     }
 
-    Ok(AnyValue::Null)
+    if is_instance(env, jni_str!("java/time/LocalDate")) {
+        let days = call_long_method(env, obj, jni_str!("toEpochDay"))?;
+        let days = i32::try_from(days).context(format!("Date `{days}` is out of range"))?;
+        return Ok(AnyValue::Date(days));
+    }
+
+    if is_instance(env, jni_str!("java/time/LocalTime")) {
+        let nanos = call_long_method(env, obj, jni_str!("toNanoOfDay"))?;
+        return Ok(AnyValue::Time(nanos));
+    }
+
+    if is_instance(env, jni_str!("java/time/LocalDateTime")) {
+        // Interpreted as wall-clock time without a zone, i.e. as if it were UTC.
+        let date = env
+            .call_method(
+                obj,
+                jni_str!("toLocalDate"),
+                jni_sig!("()Ljava/time/LocalDate;"),
+                &[],
+            )
+            .and_then(|v| v.l())
+            .context("Failed to call toLocalDate")?;
+        let time = env
+            .call_method(
+                obj,
+                jni_str!("toLocalTime"),
+                jni_sig!("()Ljava/time/LocalTime;"),
+                &[],
+            )
+            .and_then(|v| v.l())
+            .context("Failed to call toLocalTime")?;
+
+        let days = call_long_method(env, &date, jni_str!("toEpochDay"))?;
+        let nanos_of_day = call_long_method(env, &time, jni_str!("toNanoOfDay"))?;
+        let nanos = epoch_nanos(days * 86_400, nanos_of_day)?;
+        return Ok(AnyValue::DatetimeOwned(nanos, TimeUnit::Nanoseconds, None));
+    }
+
+    if is_instance(env, jni_str!("java/time/Instant")) {
+        let nanos = instant_to_nanos(env, obj)?;
+        return Ok(AnyValue::DatetimeOwned(
+            nanos,
+            TimeUnit::Nanoseconds,
+            Some(Arc::new(TimeZone::UTC)),
+        ));
+    }
+
+    if is_instance(env, jni_str!("java/time/ZonedDateTime")) {
+        let instant = env
+            .call_method(
+                obj,
+                jni_str!("toInstant"),
+                jni_sig!("()Ljava/time/Instant;"),
+                &[],
+            )
+            .and_then(|v| v.l())
+            .context("Failed to call toInstant")?;
+        let nanos = instant_to_nanos(env, &instant)?;
+
+        let zone = env
+            .call_method(
+                obj,
+                jni_str!("getZone"),
+                jni_sig!("()Ljava/time/ZoneId;"),
+                &[],
+            )
+            .and_then(|v| v.l())
+            .context("Failed to call getZone")?;
+        // Fixed-rule zones normalize to their offset, e.g. `UTC` and `Etc/UTC` to `Z`.
+        let zone = env
+            .call_method(
+                &zone,
+                jni_str!("normalized"),
+                jni_sig!("()Ljava/time/ZoneId;"),
+                &[],
+            )
+            .and_then(|v| v.l())
+            .context("Failed to call normalized")?;
+        let zone_id = env
+            .call_method(
+                &zone,
+                jni_str!("getId"),
+                jni_sig!("()Ljava/lang/String;"),
+                &[],
+            )
+            .and_then(|v| v.l())
+            .context("Failed to call getId")?;
+        let zone_id = j_object_ref_to_string(env, &zone_id, Some("Invalid ZoneId"))?;
+        // polars has no `Z` zone, and only maps whole-hour offsets to `Etc/GMT` zones.
+        let zone_id = if zone_id == "Z" {
+            "UTC".to_string()
+        } else {
+            zone_id
+        };
+        let tz = match TimeZone::opt_try_new(Some(zone_id.as_str())) {
+            Ok(tz) => tz,
+            Err(_) if zone_id.starts_with(['+', '-']) => bail!(
+                "Unsupported time zone offset `{zone_id}`, polars only supports whole-hour \
+                 offsets. Convert the value to UTC or to a region-based zone first."
+            ),
+            Err(e) => return Err(e).context(format!("Unsupported time zone `{zone_id}`")),
+        };
+
+        return Ok(AnyValue::DatetimeOwned(
+            nanos,
+            TimeUnit::Nanoseconds,
+            tz.map(Arc::new),
+        ));
+    }
+
+    if is_instance(env, jni_str!("java/math/BigDecimal")) {
+        // polars decimals cannot have a negative scale, so normalize e.g. `1E+3` to `1000`.
+        let mut decimal = env
+            .new_local_ref(obj)
+            .context("Failed to reference BigDecimal")?;
+        if call_int_method(env, &decimal, jni_str!("scale"))? < 0 {
+            decimal = env
+                .call_method(
+                    &decimal,
+                    jni_str!("setScale"),
+                    jni_sig!("(I)Ljava/math/BigDecimal;"),
+                    &[JValue::Int(0)],
+                )
+                .and_then(|v| v.l())
+                .context("Failed to call setScale")?;
+        }
+
+        let scale = call_int_method(env, &decimal, jni_str!("scale"))? as usize;
+        let precision =
+            (call_int_method(env, &decimal, jni_str!("precision"))? as usize).max(scale);
+        if precision > 38 {
+            bail!(
+                "BigDecimal with precision {precision} exceeds the maximum decimal precision of 38"
+            )
+        }
+        let unscaled = env
+            .call_method(
+                &decimal,
+                jni_str!("unscaledValue"),
+                jni_sig!("()Ljava/math/BigInteger;"),
+                &[],
+            )
+            .and_then(|v| v.l())
+            .context("Failed to call unscaledValue")?;
+        let unscaled = big_integer_to_i128(env, &unscaled)?;
+
+        return Ok(AnyValue::Decimal(unscaled, precision, scale));
+    }
+
+    if is_instance(env, jni_str!("java/math/BigInteger")) {
+        let value = big_integer_to_i128(env, obj)?;
+        return Ok(match i64::try_from(value) {
+            Ok(v) => AnyValue::Int64(v),
+            Err(_) => AnyValue::Int128(value),
+        });
+    }
+
+    if is_instance(env, jni_str!("[B")) {
+        let bytes = env
+            .as_cast::<JByteArray>(obj)
+            .context("Failed to read value as byte array")?;
+        let data = env
+            .convert_byte_array(&*bytes)
+            .context("Failed to read byte array")?;
+        return Ok(AnyValue::BinaryOwned(data));
+    }
+
+    if is_instance(env, jni_str!("java/util/List")) {
+        let list = env
+            .as_cast::<JList>(obj)
+            .context("Failed to read value as List")?;
+        let iterator = list.iter(env).context("Failed to iterate over List")?;
+
+        let mut values = Vec::new();
+        while let Some(element) = iterator
+            .next(env)
+            .context("Failed to read next element of List")?
+        {
+            let element = element.auto();
+            values.push(jobject_to_any_value(env, &element)?);
+        }
+
+        let s = Series::from_any_values(PlSmallStr::EMPTY, &values, false)
+            .context("Failed to build series from List elements")?;
+        return Ok(AnyValue::List(s));
+    }
+
+    if is_instance(env, jni_str!("java/util/Map")) {
+        let map = env
+            .as_cast::<JMap>(obj)
+            .context("Failed to read value as Map")?;
+        let mut iterator = map.iter(env).context("Failed to iterate over Map")?;
+
+        let mut entries = Vec::new();
+        while let Some(entry) = iterator
+            .next(env)
+            .context("Failed to read next entry of Map")?
+        {
+            let key = entry.key(env)?.auto();
+            let name = j_object_ref_to_string(
+                env,
+                &key,
+                Some("Map keys must be strings to be decoded as struct fields"),
+            )?;
+
+            let value = entry.value(env)?.auto();
+            entries.push((name, jobject_to_any_value(env, &value)?));
+        }
+
+        // Hash-based maps iterate in an unspecified order, so fields are sorted by name to give
+        // every map with the same keys the same struct layout.
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let (fields, values) = entries
+            .into_iter()
+            .map(|(name, value)| (Field::new(name.into(), value.dtype()), value))
+            .unzip();
+
+        return Ok(AnyValue::StructOwned(Box::new((values, fields))));
+    }
+
+    let class_name = env
+        .get_object_class(obj)
+        .and_then(|class| {
+            env.call_method(
+                &class,
+                jni_str!("getName"),
+                jni_sig!("()Ljava/lang/String;"),
+                &[],
+            )
+        })
+        .and_then(|v| v.l())
+        .context("Failed to get class of value")?;
+    let class_name = j_object_ref_to_string(env, &class_name, Some("Invalid class name"))?;
+    bail!("Unsupported value of type `{class_name}`")
 }
 
 fn jobject_to_expr<'local>(env: &mut Env<'local>, obj: &JObject<'local>) -> anyhow::Result<Expr> {
-    // Reuse the single boxed-value decoder, then lift the AnyValue into a literal
+    // Reuse the single Java value decoder, then lift the AnyValue into a literal
     // Expr. This keeps the Java type-dispatch ladder in one place.
     let expr = match jobject_to_any_value(env, obj)? {
-        AnyValue::Int8(v) => lit(v),
-        AnyValue::Int16(v) => lit(v),
-        AnyValue::Int32(v) => lit(v),
-        AnyValue::Int64(v) => lit(v),
-        AnyValue::Float32(v) => lit(v),
-        AnyValue::Float64(v) => lit(v),
-        AnyValue::Boolean(v) => lit(v),
-        AnyValue::StringOwned(s) => lit(s.to_string()),
-        _ => NULL.lit(),
+        AnyValue::Null => NULL.lit(),
+        av => lit(Scalar::new(av.dtype(), av)),
     };
     Ok(expr)
 }
@@ -832,6 +1130,70 @@ fn reinterpret<'local>(
     ))
 }
 
+const SERIALIZE_JSON_METHOD: NativeMethod =
+    col_method!(extern fn serialize_json(expr: ExprHandle) -> JString, name = "serializeJson",);
+
+fn serialize_json<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    expr: ExprHandle,
+) -> anyhow::Result<JString<'local>> {
+    let expr_str =
+        serialization::expr_to_json(&expr.get()).context("Failed to serialize expression")?;
+
+    JString::from_str(env, expr_str).context("Failed to build expression string")
+}
+
+const SERIALIZE_BINARY_METHOD: NativeMethod =
+    col_method!(extern fn serialize_binary(expr: ExprHandle) -> [jbyte], name = "serializeBinary",);
+
+fn serialize_binary<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    expr: ExprHandle,
+) -> anyhow::Result<JByteArray<'local>> {
+    let bytes =
+        serialization::expr_to_bytes(&expr.get()).context("Failed to serialize expression")?;
+
+    env.byte_array_from_slice(&bytes)
+        .context("Failed to build expression byte array")
+}
+
+const DESERIALIZE_JSON_METHOD: NativeMethod = col_method!(extern fn deserialize_json(json: java.lang.String) -> ExprHandle, name = "deserializeJson",);
+
+fn deserialize_json<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    json: JString<'local>,
+) -> anyhow::Result<ExprHandle> {
+    let json = j_string_to_string(
+        env,
+        &json,
+        Some("Failed to parse the provided serialized expression as string"),
+    )?;
+
+    let expr = serialization::expr_from_json(&json).context("Failed to deserialize expression")?;
+
+    Ok(ExprHandle::alloc(expr))
+}
+
+const DESERIALIZE_BINARY_METHOD: NativeMethod = col_method!(extern fn deserialize_binary(bytes: [jbyte]) -> ExprHandle, name = "deserializeBinary",);
+
+fn deserialize_binary<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    bytes: JByteArray<'local>,
+) -> anyhow::Result<ExprHandle> {
+    let bytes = env
+        .convert_byte_array(&bytes)
+        .context("Failed to read the provided serialized expression")?;
+
+    let expr =
+        serialization::expr_from_bytes(&bytes).context("Failed to deserialize expression")?;
+
+    Ok(ExprHandle::alloc(expr))
+}
+
 decl_free!(
     FREE_METHOD,
     "com.github.chitralverma.polars.internal.jni.expressions.column_expr$",
@@ -893,6 +1255,7 @@ pub const METHODS: &[NativeMethod] = &[
     SORT_COLUMN_BY_NAME_METHOD,
     APPLY_UNARY_METHOD,
     CAST_METHOD,
+    CAST_WITH_OPTIONS_METHOD,
     IS_IN_METHOD,
     IS_BETWEEN_METHOD,
     LIKE_METHOD,
@@ -926,5 +1289,9 @@ pub const METHODS: &[NativeMethod] = &[
     DIFF_METHOD,
     PCT_CHANGE_METHOD,
     REINTERPRET_METHOD,
+    SERIALIZE_JSON_METHOD,
+    SERIALIZE_BINARY_METHOD,
+    DESERIALIZE_JSON_METHOD,
+    DESERIALIZE_BINARY_METHOD,
     FREE_METHOD,
 ];
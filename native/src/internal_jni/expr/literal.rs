@@ -1,12 +1,15 @@
-use anyhow::Context;
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
-use jni::objects::{JObject, JString};
-use jni::sys::{jboolean, jdouble, jfloat, jint, jlong};
+use anyhow::{Context, ensure};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use jni::objects::{JByteArray, JLongArray, JObject, JObjectArray, JString};
+use jni::sys::{jboolean, jbyte, jdouble, jfloat, jint, jlong, jshort};
 use jni::{Env, NativeMethod, native_method};
 use polars::prelude::*;
 
-use crate::internal_jni::handle::{ExprHandle, Handle};
-use crate::internal_jni::utils::j_string_to_string;
+use crate::internal_jni::conversion::JavaArrayToVec;
+use crate::internal_jni::handle::{ExprHandle, Handle, SeriesHandle};
+use crate::internal_jni::utils::{
+    ensure_decimal_fits, j_string_array_to_vec, j_string_to_string, parse_time_unit,
+};
 use crate::utils::error::ThrowRuntimeException;
 
 /// Injects the shared `literal_expr$` config into [`native_method!`].
@@ -15,7 +18,7 @@ macro_rules! lit_method {
         native_method! {
             java_type = "com.github.chitralverma.polars.internal.jni.expressions.literal_expr$",
             error_policy = ThrowRuntimeException,
-            type_map = { unsafe ExprHandle => long },
+            type_map = { unsafe ExprHandle => long, unsafe SeriesHandle => long },
             $($tt)*
         }
     };
@@ -87,6 +90,92 @@ fn from_long<'local>(
     Ok(ExprHandle::alloc(lit(value)))
 }
 
+const FROM_INT8_METHOD: NativeMethod = lit_method!(
+    extern fn from_int8(value: jbyte) -> ExprHandle,
+    name = "fromInt8",
+);
+
+fn from_int8<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    value: jbyte,
+) -> anyhow::Result<ExprHandle> {
+    Ok(ExprHandle::alloc(lit(value)))
+}
+
+const FROM_INT16_METHOD: NativeMethod = lit_method!(
+    extern fn from_int16(value: jshort) -> ExprHandle,
+    name = "fromInt16",
+);
+
+fn from_int16<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    value: jshort,
+) -> anyhow::Result<ExprHandle> {
+    Ok(ExprHandle::alloc(lit(value)))
+}
+
+const FROM_UINT8_METHOD: NativeMethod = lit_method!(
+    extern fn from_uint8(value: jint) -> ExprHandle,
+    name = "fromUInt8",
+);
+
+fn from_uint8<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    value: jint,
+) -> anyhow::Result<ExprHandle> {
+    let value =
+        u8::try_from(value).context(format!("Value `{value}` is out of range for uint8"))?;
+    Ok(ExprHandle::alloc(lit(value)))
+}
+
+const FROM_UINT16_METHOD: NativeMethod = lit_method!(
+    extern fn from_uint16(value: jint) -> ExprHandle,
+    name = "fromUInt16",
+);
+
+fn from_uint16<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    value: jint,
+) -> anyhow::Result<ExprHandle> {
+    let value =
+        u16::try_from(value).context(format!("Value `{value}` is out of range for uint16"))?;
+    Ok(ExprHandle::alloc(lit(value)))
+}
+
+const FROM_UINT32_METHOD: NativeMethod = lit_method!(
+    extern fn from_uint32(value: jlong) -> ExprHandle,
+    name = "fromUInt32",
+);
+
+fn from_uint32<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    value: jlong,
+) -> anyhow::Result<ExprHandle> {
+    let value =
+        u32::try_from(value).context(format!("Value `{value}` is out of range for uint32"))?;
+    Ok(ExprHandle::alloc(lit(value)))
+}
+
+const FROM_UINT64_METHOD: NativeMethod = lit_method!(
+    extern fn from_uint64(value: jlong) -> ExprHandle,
+    name = "fromUInt64",
+);
+
+/// The bits of `value` are reinterpreted as unsigned, following the JVM convention of
+/// `java.lang.Long.toUnsignedString`, so values above `Long.MAX_VALUE` arrive as negative longs.
+fn from_uint64<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    value: jlong,
+) -> anyhow::Result<ExprHandle> {
+    Ok(ExprHandle::alloc(lit(value as u64)))
+}
+
 const FROM_FLOAT_METHOD: NativeMethod = lit_method!(
     extern fn from_float(value: jfloat) -> ExprHandle,
     name = "fromFloat",
@@ -190,15 +279,336 @@ fn from_date_time<'local>(
     Ok(ExprHandle::alloc(lit(datetime)))
 }
 
+const FROM_DATE_TIME_WITH_OPTIONS_METHOD: NativeMethod = lit_method!(
+    extern fn from_date_time_with_options(
+        value: java.lang.String,
+        format: java.lang.String,
+        time_unit: java.lang.String,
+        time_zone: java.lang.String,
+    ) -> ExprHandle,
+    name = "fromDateTimeWithOptions",
+);
+
+/// Parses `value` with the chrono `format` (defaults to `%FT%T%.f` when null) into a datetime
+/// literal of the given `time_unit`. If the format carries an offset (e.g. `%z`), the instant is
+/// preserved and expressed in `time_zone` (defaults to `UTC`). A value without an offset is read
+/// as wall-clock time in `time_zone`, or kept naive when no `time_zone` is given.
+fn from_date_time_with_options<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    value: JString<'local>,
+    format: JString<'local>,
+    time_unit: JString<'local>,
+    time_zone: JString<'local>,
+) -> anyhow::Result<ExprHandle> {
+    let string_value = j_string_to_string(
+        env,
+        &value,
+        Some("Failed to parse provided literal value as string"),
+    )?;
+
+    let format = if format.is_null() {
+        "%FT%T%.f".to_string()
+    } else {
+        j_string_to_string(
+            env,
+            &format,
+            Some("Failed to parse provided format as string"),
+        )?
+    };
+
+    let time_unit = parse_time_unit(&j_string_to_string(
+        env,
+        &time_unit,
+        Some("Failed to parse provided time unit as string"),
+    )?)?;
+
+    let time_zone = if time_zone.is_null() {
+        None
+    } else {
+        Some(j_string_to_string(
+            env,
+            &time_zone,
+            Some("Failed to parse provided time zone as string"),
+        )?)
+    };
+
+    let time_zone = TimeZone::opt_try_new(time_zone)?;
+    let (datetime, time_zone) = match DateTime::parse_from_str(&string_value, &format) {
+        Ok(dt) => (dt.naive_utc(), time_zone.or(Some(TimeZone::UTC))),
+        Err(_) => {
+            let local = NaiveDateTime::parse_from_str(&string_value, &format).context(format!(
+                "Failed to parse value `{string_value}` as datetime with format `{format}`"
+            ))?;
+            let datetime = match &time_zone {
+                Some(tz) => chrono::TimeZone::from_local_datetime(&tz.to_chrono()?, &local)
+                    .single()
+                    .context(format!(
+                        "Value `{string_value}` is ambiguous or does not exist in time zone `{tz}`"
+                    ))?
+                    .naive_utc(),
+                None => local,
+            };
+            (datetime, time_zone)
+        },
+    };
+
+    let utc = datetime.and_utc();
+    let timestamp = match time_unit {
+        TimeUnit::Nanoseconds => utc.timestamp_nanos_opt().context(format!(
+            "Value `{string_value}` is out of range for nanosecond precision"
+        ))?,
+        TimeUnit::Microseconds => utc.timestamp_micros(),
+        TimeUnit::Milliseconds => utc.timestamp_millis(),
+    };
+
+    Ok(ExprHandle::alloc(datetime_lit(
+        timestamp, time_unit, time_zone,
+    )))
+}
+
+const FROM_EPOCH_DATE_TIME_METHOD: NativeMethod = lit_method!(
+    extern fn from_epoch_date_time(
+        value: jlong,
+        time_unit: java.lang.String,
+        time_zone: java.lang.String,
+    ) -> ExprHandle,
+    name = "fromEpochDateTime",
+);
+
+/// Builds a datetime literal from a timestamp since the epoch in `time_unit`, optionally tagged
+/// with `time_zone`.
+fn from_epoch_date_time<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    value: jlong,
+    time_unit: JString<'local>,
+    time_zone: JString<'local>,
+) -> anyhow::Result<ExprHandle> {
+    let time_unit = parse_time_unit(&j_string_to_string(
+        env,
+        &time_unit,
+        Some("Failed to parse provided time unit as string"),
+    )?)?;
+
+    let time_zone = if time_zone.is_null() {
+        None
+    } else {
+        let tz = j_string_to_string(
+            env,
+            &time_zone,
+            Some("Failed to parse provided time zone as string"),
+        )?;
+        TimeZone::opt_try_new(Some(tz))?
+    };
+
+    Ok(ExprHandle::alloc(datetime_lit(value, time_unit, time_zone)))
+}
+
+fn datetime_lit(timestamp: i64, time_unit: TimeUnit, time_zone: Option<TimeZone>) -> Expr {
+    LiteralValue::Scalar(Scalar::new(
+        DataType::Datetime(time_unit, time_zone.clone()),
+        AnyValue::DatetimeOwned(timestamp, time_unit, time_zone.map(Arc::new)),
+    ))
+    .lit()
+}
+
+const FROM_DURATION_METHOD: NativeMethod = lit_method!(
+    extern fn from_duration(value: jlong, time_unit: java.lang.String) -> ExprHandle,
+    name = "fromDuration",
+);
+
+fn from_duration<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    value: jlong,
+    time_unit: JString<'local>,
+) -> anyhow::Result<ExprHandle> {
+    let time_unit = parse_time_unit(&j_string_to_string(
+        env,
+        &time_unit,
+        Some("Failed to parse provided time unit as string"),
+    )?)?;
+
+    let expr = LiteralValue::Scalar(Scalar::new(
+        DataType::Duration(time_unit),
+        AnyValue::Duration(value, time_unit),
+    ))
+    .lit();
+    Ok(ExprHandle::alloc(expr))
+}
+
+const FROM_DECIMAL_METHOD: NativeMethod = lit_method!(
+    extern fn from_decimal(unscaled: java.lang.String, precision: jint, scale: jint) -> ExprHandle,
+    name = "fromDecimal",
+);
+
+/// Builds a decimal literal from its unscaled value, given as a decimal digit string since it may
+/// not fit in a `long`, e.g. `12345` with scale 2 for `123.45`.
+fn from_decimal<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    unscaled: JString<'local>,
+    precision: jint,
+    scale: jint,
+) -> anyhow::Result<ExprHandle> {
+    let digits = j_string_to_string(
+        env,
+        &unscaled,
+        Some("Failed to parse provided unscaled decimal value as string"),
+    )?;
+    let value = digits.parse::<i128>().context(format!(
+        "Failed to parse value `{digits}` as an unscaled decimal"
+    ))?;
+
+    ensure!(
+        (1..=38).contains(&precision) && (0..=precision).contains(&scale),
+        "Invalid decimal precision `{precision}` and scale `{scale}`, expected \
+         0 <= scale <= precision <= 38"
+    );
+    let (precision, scale) = (precision as usize, scale as usize);
+    ensure_decimal_fits(value, precision)?;
+
+    let expr = LiteralValue::Scalar(Scalar::new(
+        DataType::Decimal(precision, scale),
+        AnyValue::Decimal(value, precision, scale),
+    ))
+    .lit();
+    Ok(ExprHandle::alloc(expr))
+}
+
+const FROM_BINARY_METHOD: NativeMethod = lit_method!(
+    extern fn from_binary(value: [jbyte]) -> ExprHandle,
+    name = "fromBinary",
+);
+
+fn from_binary<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    value: JByteArray<'local>,
+) -> anyhow::Result<ExprHandle> {
+    let bytes = env
+        .convert_byte_array(&value)
+        .context("Failed to read provided binary literal value")?;
+
+    let expr =
+        LiteralValue::Scalar(Scalar::new(DataType::Binary, AnyValue::BinaryOwned(bytes))).lit();
+    Ok(ExprHandle::alloc(expr))
+}
+
+const FROM_SERIES_METHOD: NativeMethod = lit_method!(
+    extern fn from_series(series: SeriesHandle) -> ExprHandle,
+    name = "fromSeries",
+);
+
+/// Uses the whole series as an expression, i.e. a column of its values rather than a scalar.
+fn from_series<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series: SeriesHandle,
+) -> anyhow::Result<ExprHandle> {
+    Ok(ExprHandle::alloc(lit(series.get())))
+}
+
+const FROM_LIST_METHOD: NativeMethod = lit_method!(
+    extern fn from_list(series: SeriesHandle) -> ExprHandle,
+    name = "fromList",
+);
+
+/// Builds a single list scalar holding the values of the series.
+fn from_list<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series: SeriesHandle,
+) -> anyhow::Result<ExprHandle> {
+    let s = series.get();
+
+    let expr = LiteralValue::Scalar(Scalar::new(
+        DataType::List(Box::new(s.dtype().clone())),
+        AnyValue::List(s),
+    ))
+    .lit();
+    Ok(ExprHandle::alloc(expr))
+}
+
+const FROM_ARRAY_METHOD: NativeMethod = lit_method!(
+    extern fn from_array(series: SeriesHandle) -> ExprHandle,
+    name = "fromArray",
+);
+
+/// Builds a single fixed-size array scalar whose width is the length of the series.
+fn from_array<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series: SeriesHandle,
+) -> anyhow::Result<ExprHandle> {
+    let s = series.get();
+    let width = s.len();
+
+    let expr = LiteralValue::Scalar(Scalar::new(
+        DataType::Array(Box::new(s.dtype().clone()), width),
+        AnyValue::Array(s, width),
+    ))
+    .lit();
+    Ok(ExprHandle::alloc(expr))
+}
+
+const FROM_STRUCT_METHOD: NativeMethod = lit_method!(
+    extern fn from_struct(names: [java.lang.String], values: [jlong]) -> ExprHandle,
+    name = "fromStruct",
+);
+
+/// Builds a struct from the expressions in `values`, naming each field after the matching entry
+/// of `names`.
+fn from_struct<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    names: JObjectArray<'local, JString<'local>>,
+    values: JLongArray<'local>,
+) -> anyhow::Result<ExprHandle> {
+    let names = j_string_array_to_vec(env, &names, "Failed to parse the provided field name")?;
+    let values = JavaArrayToVec::to_vec(env, values)?;
+
+    ensure!(
+        names.len() == values.len(),
+        "Got {} field names for {} struct field values",
+        names.len(),
+        values.len()
+    );
+
+    let fields: Vec<Expr> = names
+        .into_iter()
+        .zip(values)
+        .map(|(name, ptr)| ExprHandle::from(ptr).get().alias(name))
+        .collect();
+
+    Ok(ExprHandle::alloc(as_struct(fields)))
+}
+
 pub const METHODS: &[NativeMethod] = &[
     NULL_LIT_METHOD,
     FROM_STRING_METHOD,
     FROM_BOOL_METHOD,
     FROM_INT_METHOD,
     FROM_LONG_METHOD,
+    FROM_INT8_METHOD,
+    FROM_INT16_METHOD,
+    FROM_UINT8_METHOD,
+    FROM_UINT16_METHOD,
+    FROM_UINT32_METHOD,
+    FROM_UINT64_METHOD,
     FROM_FLOAT_METHOD,
     FROM_DOUBLE_METHOD,
     FROM_DATE_METHOD,
     FROM_TIME_METHOD,
     FROM_DATE_TIME_METHOD,
+    FROM_DATE_TIME_WITH_OPTIONS_METHOD,
+    FROM_EPOCH_DATE_TIME_METHOD,
+    FROM_DURATION_METHOD,
+    FROM_DECIMAL_METHOD,
+    FROM_BINARY_METHOD,
+    FROM_SERIES_METHOD,
+    FROM_LIST_METHOD,
+    FROM_ARRAY_METHOD,
+    FROM_STRUCT_METHOD,
 ];
@@ -1,15 +1,20 @@
 use std::iter::Iterator;
 
-use anyhow::Context;
-use jni::objects::{JLongArray, JObject, JString};
-use jni::sys::jlong;
+use anyhow::{Context, ensure};
+use jni::objects::{IntoAuto, JLongArray, JObject, JObjectArray, JString};
+use jni::sys::{jboolean, jint, jlong};
 use jni::{Env, NativeMethod, native_method};
 use polars::prelude::*;
-use polars_core::utils::concat_df;
+use polars_core::utils::{any_values_to_supertype, concat_df};
 
-use crate::internal_jni::conversion::JavaArrayToVec;
+use crate::internal_jni::conversion::{AnyValueWrapper, IntoJava, JavaArrayToVec};
+use crate::internal_jni::dtype::{dtype_to_json, parse_dtype, schema_to_json};
+use crate::internal_jni::expr::column::jobject_to_any_value;
 use crate::internal_jni::handle::{DataFrameHandle, Handle, LazyFrameHandle, SeriesHandle};
 use crate::internal_jni::macros::decl_free;
+use crate::internal_jni::utils::{
+    j_string_array_to_vec, j_string_to_string, vec_to_j_long_array, vec_to_j_string_array,
+};
 use crate::utils::error::ThrowRuntimeException;
 
 /// Injects the shared `data_frame$` config into [`native_method!`].
@@ -42,6 +47,20 @@ fn schema_string<'local>(
     JString::from_str(env, schema_str).context("Failed to build schema string")
 }
 
+const SCHEMA_JSON_METHOD: NativeMethod =
+    df_method!(extern fn schema_json(df: DataFrameHandle) -> JString, name = "schemaJson");
+
+/// Describes the schema with polars' own data types, see [`crate::internal_jni::dtype`].
+fn schema_json<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+) -> anyhow::Result<JString<'local>> {
+    let df = df.get();
+    let schema_str = schema_to_json(df.schema())?;
+    JString::from_str(env, schema_str).context("Failed to build schema string")
+}
+
 const SHOW_METHOD: NativeMethod = df_method!(extern fn show(df: DataFrameHandle));
 
 fn show<'local>(
@@ -135,6 +154,424 @@ fn from_series<'local>(
     Ok(DataFrameHandle::alloc(df))
 }
 
+const FROM_ROWS_METHOD: NativeMethod = df_method!(extern fn from_rows(rows: java.lang.Object[][], names: [java.lang.String], data_types: [java.lang.String], infer_length: jint) -> DataFrameHandle, name = "fromRows");
+
+/// Builds a DataFrame from rows of boxed Java values, decoded as by [`jobject_to_any_value`], so
+/// that nested `java.util.List`s and `java.util.Map`s become list and struct columns.
+///
+/// Columns are named by `names`, or `column_0`, `column_1`, ... if it is `null`. If `data_types`
+/// (see [`parse_dtype`] for the accepted type strings) is `null`, the type of each column is the
+/// supertype of its first `infer_length` values, or of all of them if `infer_length` is not
+/// positive, and later values that do not fit that type become nulls. Otherwise values that cannot
+/// be represented in the given types are an error.
+fn from_rows<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    rows: JObjectArray<'local, JObjectArray<'local, JObject<'local>>>,
+    names: JObjectArray<'local, JString<'local>>,
+    data_types: JObjectArray<'local, JString<'local>>,
+    infer_length: jint,
+) -> anyhow::Result<DataFrameHandle> {
+    let names = if names.is_null() {
+        None
+    } else {
+        Some(j_string_array_to_vec(
+            env,
+            &names,
+            "Failed to parse the provided value as a column name",
+        )?)
+    };
+
+    let dtypes = if data_types.is_null() {
+        None
+    } else {
+        let dtypes = j_string_array_to_vec(
+            env,
+            &data_types,
+            "Failed to parse provided DataType as string",
+        )?
+        .iter()
+        .map(|dt| parse_dtype(dt))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+        Some(dtypes)
+    };
+
+    if let (Some(names), Some(dtypes)) = (&names, &dtypes) {
+        ensure!(
+            names.len() == dtypes.len(),
+            "Got {} column names but {} data types",
+            names.len(),
+            dtypes.len()
+        );
+    }
+
+    let n_rows = rows.len(env).context("Failed to get number of rows")?;
+    let mut columns: Option<Vec<Vec<AnyValue<'static>>>> = names
+        .as_ref()
+        .map(Vec::len)
+        .or(dtypes.as_ref().map(Vec::len))
+        .map(|width| (0..width).map(|_| Vec::with_capacity(n_rows)).collect());
+
+    for i in 0..n_rows {
+        let row = rows
+            .get_element(env, i)
+            .context(format!("Failed to get row {i}"))?
+            .auto();
+        ensure!(!row.is_null(), "Row {i} is null");
+
+        let width = row
+            .len(env)
+            .context(format!("Failed to get length of row {i}"))?;
+        let columns =
+            columns.get_or_insert_with(|| (0..width).map(|_| Vec::with_capacity(n_rows)).collect());
+        ensure!(
+            width == columns.len(),
+            "Row {i} has {width} values, expected {}",
+            columns.len()
+        );
+
+        for (j, column) in columns.iter_mut().enumerate() {
+            let obj = row
+                .get_element(env, j)
+                .context(format!("Failed to get value {j} of row {i}"))?
+                .auto();
+            column.push(jobject_to_any_value(env, &obj)?);
+        }
+    }
+
+    let columns = columns
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+        .map(|(i, values)| {
+            let name = match &names {
+                Some(names) => PlSmallStr::from_str(&names[i]),
+                None => PlSmallStr::from_string(format!("column_{i}")),
+            };
+
+            let series = match &dtypes {
+                Some(dtypes) => Series::from_any_values_and_dtype(name, &values, &dtypes[i], true),
+                None => {
+                    let n_infer = match usize::try_from(infer_length) {
+                        Ok(n) if n > 0 => n.min(values.len()),
+                        _ => values.len(),
+                    };
+                    any_values_to_supertype(&values[..n_infer]).and_then(|dtype| {
+                        Series::from_any_values_and_dtype(name, &values, &dtype, false)
+                    })
+                },
+            }
+            .context(format!("Failed to build column {i} from the provided rows"))?;
+
+            Ok(series.into_column())
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let df = DataFrame::new(n_rows, columns)
+        .context("Failed to instantiate DataFrame from the provided rows")?;
+
+    Ok(DataFrameHandle::alloc(df))
+}
+
+const SHAPE_METHOD: NativeMethod = df_method!(extern fn shape(df: DataFrameHandle) -> [jlong]);
+
+/// Returns the height and width as a two-element array.
+fn shape<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+) -> anyhow::Result<JLongArray<'local>> {
+    let (height, width) = df.get().shape();
+    vec_to_j_long_array(env, &[height as jlong, width as jlong])
+}
+
+const COLUMN_NAMES_METHOD: NativeMethod = df_method!(extern fn column_names(df: DataFrameHandle) -> [java.lang.String], name = "columnNames");
+
+fn column_names<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+) -> anyhow::Result<JObjectArray<'local, JString<'local>>> {
+    vec_to_j_string_array(env, &df.get().get_column_names())
+}
+
+const DTYPES_METHOD: NativeMethod =
+    df_method!(extern fn dtypes(df: DataFrameHandle) -> [java.lang.String]);
+
+/// Returns the column types as [`dtype_to_json`] JSON, which
+/// [`crate::internal_jni::dtype::parse_dtype`] reads back.
+fn dtypes<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+) -> anyhow::Result<JObjectArray<'local, JString<'local>>> {
+    let dtypes = df
+        .get()
+        .dtypes()
+        .iter()
+        .map(|dt| Ok(dtype_to_json(dt)?.to_string()))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    vec_to_j_string_array(env, &dtypes)
+}
+
+const GET_COLUMN_METHOD: NativeMethod = df_method!(extern fn get_column(df: DataFrameHandle, name: java.lang.String) -> SeriesHandle, name = "getColumn");
+
+fn get_column<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    name: JString<'local>,
+) -> anyhow::Result<SeriesHandle> {
+    let name = j_string_to_string(env, &name, Some("Failed to parse column name as string"))?;
+    let series = df
+        .get()
+        .column(&name)
+        .context(format!("Failed to get column `{name}`"))?
+        .as_materialized_series()
+        .clone();
+
+    Ok(SeriesHandle::alloc(series))
+}
+
+const GET_COLUMN_AT_METHOD: NativeMethod = df_method!(extern fn get_column_at(df: DataFrameHandle, index: jint) -> SeriesHandle, name = "getColumnAt");
+
+fn get_column_at<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    index: jint,
+) -> anyhow::Result<SeriesHandle> {
+    let df = df.get();
+    let series = usize::try_from(index)
+        .ok()
+        .and_then(|i| df.select_at_idx(i))
+        .context(format!(
+            "Column index {index} is out of bounds for DataFrame of width {}",
+            df.width()
+        ))?
+        .as_materialized_series()
+        .clone();
+
+    Ok(SeriesHandle::alloc(series))
+}
+
+const GET_COLUMNS_METHOD: NativeMethod =
+    df_method!(extern fn get_columns(df: DataFrameHandle) -> [jlong], name = "getColumns");
+
+/// Returns a series handle for every column, in order.
+fn get_columns<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+) -> anyhow::Result<JLongArray<'local>> {
+    let ptrs: Vec<jlong> = df
+        .get()
+        .columns()
+        .iter()
+        .map(|c| SeriesHandle::alloc(c.as_materialized_series().clone()).into())
+        .collect();
+
+    vec_to_j_long_array(env, &ptrs)
+}
+
+const ROW_METHOD: NativeMethod =
+    df_method!(extern fn row(df: DataFrameHandle, index: jlong) -> [java.lang.Object]);
+
+/// Returns the values of row `index` boxed as Java objects, as a [`crate::internal_jni::row`]
+/// iterator would.
+fn row<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    index: jlong,
+) -> anyhow::Result<JObjectArray<'local>> {
+    let df = df.get();
+    ensure!(
+        index >= 0 && (index as usize) < df.height(),
+        "Row index {index} is out of bounds for DataFrame of height {}",
+        df.height()
+    );
+
+    let row = df
+        .get_row(index as usize)
+        .context(format!("Failed to get row {index}"))?;
+
+    let j_array = JObjectArray::<JObject>::new(env, row.0.len(), JObject::null())
+        .context("Failed to initialize array for row values")?;
+    for (i, any_value) in row.0.into_iter().enumerate() {
+        let java_object = AnyValueWrapper(any_value.clone()).try_into_java(env)?;
+        j_array
+            .set_element(env, i, java_object)
+            .context(format!("Failed to set value `{any_value}` in row"))?;
+    }
+
+    Ok(j_array)
+}
+
+const SELECT_COLUMNS_METHOD: NativeMethod = df_method!(extern fn select_columns(df: DataFrameHandle, names: [java.lang.String]) -> DataFrameHandle, name = "selectColumns");
+
+fn select_columns<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    names: JObjectArray<'local, JString<'local>>,
+) -> anyhow::Result<DataFrameHandle> {
+    let names = j_string_array_to_vec(
+        env,
+        &names,
+        "Failed to parse the provided value as a column name",
+    )?;
+    let selected = df
+        .get()
+        .select(names)
+        .context("Failed to select columns from DataFrame")?;
+
+    Ok(DataFrameHandle::alloc(selected))
+}
+
+const DROP_IN_PLACE_METHOD: NativeMethod = df_method!(extern fn drop_in_place(df: DataFrameHandle, name: java.lang.String) -> SeriesHandle, name = "dropInPlace");
+
+/// Removes the column `name` from the DataFrame behind `df` itself, returning it as a series.
+fn drop_in_place<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    name: JString<'local>,
+) -> anyhow::Result<SeriesHandle> {
+    let name = j_string_to_string(env, &name, Some("Failed to parse column name as string"))?;
+    let column = unsafe { df.as_mut() }
+        .drop_in_place(&name)
+        .context(format!("Failed to drop column `{name}`"))?;
+
+    Ok(SeriesHandle::alloc(column.take_materialized_series()))
+}
+
+const RENAME_METHOD: NativeMethod = df_method!(extern fn rename(df: DataFrameHandle, old_name: java.lang.String, new_name: java.lang.String) -> DataFrameHandle);
+
+fn rename<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    old_name: JString<'local>,
+    new_name: JString<'local>,
+) -> anyhow::Result<DataFrameHandle> {
+    let old_name = j_string_to_string(
+        env,
+        &old_name,
+        Some("Failed to parse column name as string"),
+    )?;
+    let new_name = j_string_to_string(
+        env,
+        &new_name,
+        Some("Failed to parse new column name as string"),
+    )?;
+
+    let mut df = df.get();
+    df.rename(&old_name, PlSmallStr::from_string(new_name))
+        .context(format!("Failed to rename column `{old_name}`"))?;
+
+    Ok(DataFrameHandle::alloc(df))
+}
+
+const HSTACK_METHOD: NativeMethod =
+    df_method!(extern fn hstack(df: DataFrameHandle, series_ptrs: [jlong]) -> DataFrameHandle);
+
+/// Appends the series as new columns, which must match the height of the DataFrame.
+fn hstack<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    series_ptrs: JLongArray<'local>,
+) -> anyhow::Result<DataFrameHandle> {
+    let columns: Vec<Column> = JavaArrayToVec::to_vec(env, series_ptrs)?
+        .into_iter()
+        .map(|ptr| SeriesHandle::from(ptr).get().into_column())
+        .collect();
+
+    let stacked = df
+        .get()
+        .hstack(&columns)
+        .context("Failed to horizontally stack series onto DataFrame")?;
+
+    Ok(DataFrameHandle::alloc(stacked))
+}
+
+const VSTACK_METHOD: NativeMethod =
+    df_method!(extern fn vstack(df: DataFrameHandle, other: DataFrameHandle) -> DataFrameHandle);
+
+/// Appends the rows of `other`, which must have the same schema, as new chunks.
+fn vstack<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    other: DataFrameHandle,
+) -> anyhow::Result<DataFrameHandle> {
+    let stacked = df
+        .get()
+        .vstack(&other.get())
+        .context("Failed to vertically stack DataFrames")?;
+
+    Ok(DataFrameHandle::alloc(stacked))
+}
+
+const EXTEND_METHOD: NativeMethod =
+    df_method!(extern fn extend(df: DataFrameHandle, other: DataFrameHandle));
+
+/// Appends the rows of `other`, which must have the same schema, to the DataFrame behind `df`
+/// itself, copying them into its existing memory where possible.
+fn extend<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    other: DataFrameHandle,
+) -> anyhow::Result<()> {
+    // Clone `other` before borrowing `df` mutably, as both may be the same DataFrame.
+    let other = other.get();
+    unsafe { df.as_mut() }
+        .extend(&other)
+        .context("Failed to extend DataFrame")?;
+    Ok(())
+}
+
+const WITH_COLUMN_METHOD: NativeMethod = df_method!(extern fn with_column(df: DataFrameHandle, series: SeriesHandle) -> DataFrameHandle, name = "withColumn");
+
+/// Adds the series as a column, replacing an existing column of the same name.
+fn with_column<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    series: SeriesHandle,
+) -> anyhow::Result<DataFrameHandle> {
+    let mut df = df.get();
+    df.with_column(series.get().into_column())
+        .context("Failed to add column to DataFrame")?;
+
+    Ok(DataFrameHandle::alloc(df))
+}
+
+const EQUALS_METHOD: NativeMethod = df_method!(extern fn equals(df: DataFrameHandle, other: DataFrameHandle, null_equal: jboolean) -> jboolean);
+
+/// Whether both DataFrames have the same column names, types and values. With `null_equal`,
+/// nulls compare equal to each other, otherwise any null makes the DataFrames unequal.
+fn equals<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    other: DataFrameHandle,
+    null_equal: jboolean,
+) -> anyhow::Result<jboolean> {
+    let (df, other) = (df.get(), other.get());
+    if df.dtypes() != other.dtypes() {
+        return Ok(false);
+    }
+
+    Ok(if null_equal {
+        df.equals_missing(&other)
+    } else {
+        df.equals(&other)
+    })
+}
+
 decl_free!(
     FREE_METHOD,
     "com.github.chitralverma.polars.internal.jni.data_frame$",
@@ -143,6 +580,7 @@ decl_free!(
 
 pub const METHODS: &[NativeMethod] = &[
     SCHEMA_STRING_METHOD,
+    SCHEMA_JSON_METHOD,
     SHOW_METHOD,
     COUNT_METHOD,
     CONCAT_DATA_FRAMES_METHOD,
@@ -150,5 +588,21 @@ pub const METHODS: &[NativeMethod] = &[
     LIMIT_METHOD,
     TAIL_METHOD,
     FROM_SERIES_METHOD,
+    FROM_ROWS_METHOD,
+    SHAPE_METHOD,
+    COLUMN_NAMES_METHOD,
+    DTYPES_METHOD,
+    GET_COLUMN_METHOD,
+    GET_COLUMN_AT_METHOD,
+    GET_COLUMNS_METHOD,
+    ROW_METHOD,
+    SELECT_COLUMNS_METHOD,
+    DROP_IN_PLACE_METHOD,
+    RENAME_METHOD,
+    HSTACK_METHOD,
+    VSTACK_METHOD,
+    EXTEND_METHOD,
+    WITH_COLUMN_METHOD,
+    EQUALS_METHOD,
     FREE_METHOD,
 ];
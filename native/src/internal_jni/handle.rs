@@ -73,12 +73,31 @@ declare_handle!(LazyFrameHandle, LazyFrame);
 declare_handle!(DataFrameHandle, DataFrame);
 declare_handle!(SeriesHandle, Series);
 declare_handle!(RowIteratorHandle, crate::internal_jni::row::RowIterator);
+declare_handle!(QueryHandle, crate::internal_jni::query::AsyncQuery);
+declare_handle!(
+    IpcStreamWriterHandle,
+    crate::internal_jni::io::write::ipc_stream::IpcStreamBatchWriter
+);
 
 impl_clone_handle!(ExprHandle, Expr);
 impl_clone_handle!(LazyFrameHandle, LazyFrame);
 impl_clone_handle!(DataFrameHandle, DataFrame);
 impl_clone_handle!(SeriesHandle, Series);
 
+impl DataFrameHandle {
+    /// Mutable access for the few operations that modify a DataFrame in place rather than
+    /// returning a new one.
+    ///
+    /// # Safety
+    /// Handle must reference a live allocation from [`Handle::alloc`], not aliased. In particular,
+    /// any other handle argument of the same call may point to the same DataFrame, so read it
+    /// through [`Handle::get`] before taking the mutable reference. Calls that read a DataFrame on
+    /// other threads are excluded by the lock the Scala `DataFrame` holds around native calls.
+    pub unsafe fn as_mut<'a>(self) -> &'a mut DataFrame {
+        unsafe { &mut *self.0 }
+    }
+}
+
 impl RowIteratorHandle {
     pub fn alloc(value: crate::internal_jni::row::RowIterator) -> Self {
         RowIteratorHandle(Box::into_raw(Box::new(value)))
@@ -104,3 +123,49 @@ impl RowIteratorHandle {
         }
     }
 }
+
+impl QueryHandle {
+    pub fn alloc(value: crate::internal_jni::query::AsyncQuery) -> Self {
+        QueryHandle(Box::into_raw(Box::new(value)))
+    }
+
+    /// # Safety
+    /// Handle must reference a live allocation from [`QueryHandle::alloc`].
+    pub unsafe fn as_ref<'a>(self) -> &'a crate::internal_jni::query::AsyncQuery {
+        unsafe { &*self.0 }
+    }
+
+    /// Dropping the query cancels it if it is still running.
+    pub fn free_raw(raw: jlong) {
+        if raw != 0 {
+            unsafe {
+                let _ = Box::from_raw(raw as *mut crate::internal_jni::query::AsyncQuery);
+            }
+        }
+    }
+}
+
+impl IpcStreamWriterHandle {
+    pub fn alloc(value: crate::internal_jni::io::write::ipc_stream::IpcStreamBatchWriter) -> Self {
+        IpcStreamWriterHandle(Box::into_raw(Box::new(value)))
+    }
+
+    /// # Safety
+    /// Handle must reference a live allocation from [`IpcStreamWriterHandle::alloc`], not aliased.
+    pub unsafe fn as_mut<'a>(
+        self,
+    ) -> &'a mut crate::internal_jni::io::write::ipc_stream::IpcStreamBatchWriter {
+        unsafe { &mut *self.0 }
+    }
+
+    /// Dropping an unfinished stream discards it without committing a file upload.
+    pub fn free_raw(raw: jlong) {
+        if raw != 0 {
+            unsafe {
+                let _ = Box::from_raw(
+                    raw as *mut crate::internal_jni::io::write::ipc_stream::IpcStreamBatchWriter,
+                );
+            }
+        }
+    }
+}
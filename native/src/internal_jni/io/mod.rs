@@ -4,10 +4,13 @@ use jni::objects::JString;
 use jni::sys::jint;
 use polars::io::RowIndex;
 use polars::io::cloud::CloudOptions;
-use polars::prelude::{CloudScheme, IdxSize, PlHashMap};
+use polars::prelude::{CloudScheme, IdxSize, PlHashMap, Schema};
+use serde_json::Value;
 
+use super::dtype::{parse_dtype, schema_from_json};
 use super::utils::j_string_to_string;
 
+pub mod read;
 pub mod scan;
 pub mod write;
 
@@ -21,6 +24,46 @@ where
     options.remove(key).and_then(|s| s.parse::<T>().ok())
 }
 
+/// Removes `key` from `options` and parses its value as a full schema in the JSON contract of
+/// [`schema_from_json`], as produced by `schemaJson` on the JVM.
+pub(crate) fn opt_parse_schema(
+    options: &mut PlHashMap<String, String>,
+    key: &str,
+) -> anyhow::Result<Option<Schema>> {
+    options
+        .remove(key)
+        .map(|s| schema_from_json(&s).context(format!("Failed to parse `{key}`")))
+        .transpose()
+}
+
+/// Removes `key` from `options` and parses its value as schema overrides, a JSON object mapping
+/// column names to data types in the [`parse_dtype`] grammar, e.g. `{"a": "int64", "b": "date"}`.
+pub(crate) fn opt_parse_schema_overrides(
+    options: &mut PlHashMap<String, String>,
+    key: &str,
+) -> anyhow::Result<Option<Schema>> {
+    let Some(json) = options.remove(key) else {
+        return Ok(None);
+    };
+    let overrides: PlHashMap<String, Value> =
+        serde_json::from_str(&json).context(format!("Failed to parse `{key}` as a JSON object"))?;
+
+    overrides
+        .into_iter()
+        .map(|(name, dtype)| {
+            let dtype = match dtype {
+                Value::String(s) => parse_dtype(&s),
+                other => parse_dtype(&other.to_string()),
+            }
+            .context(format!(
+                "Failed to parse the data type of column `{name}` in `{key}`"
+            ))?;
+            Ok((name.into(), dtype))
+        })
+        .collect::<anyhow::Result<Schema>>()
+        .map(Some)
+}
+
 pub fn get_file_path(env: &mut Env, file_path: &JString) -> anyhow::Result<String> {
     j_string_to_string(env, file_path, Some("Failed to get provided path"))
 }
@@ -40,7 +83,7 @@ where
         .context("Failed to parse the provided cloud options")
 }
 
-fn parse_json_to_options(
+pub(crate) fn parse_json_to_options(
     env: &mut Env,
     options: &JString,
 ) -> anyhow::Result<PlHashMap<String, String>> {
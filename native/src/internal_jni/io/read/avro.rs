@@ -0,0 +1,87 @@
+use std::io::Cursor;
+
+use anyhow::Context;
+use jni::objects::{JObject, JObjectArray, JString};
+use jni::{Env, NativeMethod, native_method};
+use polars::io::avro::AvroReader;
+use polars::prelude::*;
+
+use crate::internal_jni::handle::{DataFrameHandle, Handle};
+use crate::internal_jni::io::read::{combine_read_frames, read_sources};
+use crate::internal_jni::io::{opt_parse, parse_json_to_options};
+use crate::utils::error::ThrowRuntimeException;
+
+/// Injects the shared `io.read$` config into [`native_method!`].
+macro_rules! read_method {
+    ($($tt:tt)*) => {
+        native_method! {
+            java_type = "com.github.chitralverma.polars.internal.jni.io.read$",
+            error_policy = ThrowRuntimeException,
+            type_map = { unsafe DataFrameHandle => long },
+            $($tt)*
+        }
+    };
+}
+
+const READ_AVRO_METHOD: NativeMethod = read_method!(
+    extern fn read_avro(paths: [java.lang.String], buffers: [java.lang.Object], options: java.lang.String) -> DataFrameHandle,
+    name = "readAvro",
+);
+
+/// Reads each source in the Avro object container format and concatenates them vertically.
+fn read_avro<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    paths: JObjectArray<'local, JString<'local>>,
+    buffers: JObjectArray<'local, JObject<'local>>,
+    options: JString<'local>,
+) -> anyhow::Result<DataFrameHandle> {
+    let mut options = parse_json_to_options(env, &options)?;
+
+    let n_rows = opt_parse::<usize>(&mut options, "read_avro_n_rows");
+
+    let columns = options
+        .remove("read_avro_columns")
+        .map(|s| s.split(',').map(|c| c.trim().to_string()).collect());
+
+    let projection = options
+        .remove("read_avro_projection")
+        .map(|s| {
+            s.split(',')
+                .map(|i| i.trim().parse::<usize>())
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .context("Failed to parse Avro column projection")?;
+
+    let row_index_offset =
+        opt_parse::<IdxSize>(&mut options, "read_avro_row_index_offset").unwrap_or(0);
+
+    let row_index = options
+        .remove("read_avro_row_index_name")
+        .map(|name| RowIndex {
+            name: name.into(),
+            offset: row_index_offset,
+        });
+
+    let rechunk = opt_parse::<bool>(&mut options, "read_avro_rechunk").unwrap_or(false);
+
+    let sources = read_sources(env, &paths, &buffers, options)?;
+
+    let mut dfs = Vec::with_capacity(sources.len());
+    for source in sources {
+        let df = AvroReader::new(Cursor::new(source))
+            .with_n_rows(n_rows)
+            .with_columns(columns.clone())
+            .with_projection(projection.clone())
+            .finish()
+            .context("Failed to read Avro data")?;
+        dfs.push(df);
+    }
+
+    let df = combine_read_frames(dfs, n_rows, row_index, rechunk, "Avro")?;
+
+    Ok(DataFrameHandle::alloc(df))
+}
+
+pub const METHODS: &[NativeMethod] = &[READ_AVRO_METHOD];
@@ -0,0 +1,75 @@
+use std::io::Cursor;
+
+use anyhow::Context;
+use jni::objects::{JObject, JObjectArray, JString};
+use jni::{Env, NativeMethod, native_method};
+use polars::prelude::*;
+
+use crate::internal_jni::handle::{DataFrameHandle, Handle};
+use crate::internal_jni::io::read::{combine_read_frames, read_sources};
+use crate::internal_jni::io::{opt_parse, parse_json_to_options};
+use crate::utils::error::ThrowRuntimeException;
+
+/// Injects the shared `io.read$` config into [`native_method!`].
+macro_rules! read_method {
+    ($($tt:tt)*) => {
+        native_method! {
+            java_type = "com.github.chitralverma.polars.internal.jni.io.read$",
+            error_policy = ThrowRuntimeException,
+            type_map = { unsafe DataFrameHandle => long },
+            $($tt)*
+        }
+    };
+}
+
+const READ_IPC_STREAM_METHOD: NativeMethod = read_method!(
+    extern fn read_ipc_stream(paths: [java.lang.String], buffers: [java.lang.Object], options: java.lang.String) -> DataFrameHandle,
+    name = "readIPCStream",
+);
+
+/// Reads each source in the Arrow IPC stream format and concatenates them vertically.
+fn read_ipc_stream<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    paths: JObjectArray<'local, JString<'local>>,
+    buffers: JObjectArray<'local, JObject<'local>>,
+    options: JString<'local>,
+) -> anyhow::Result<DataFrameHandle> {
+    let mut options = parse_json_to_options(env, &options)?;
+
+    let n_rows = opt_parse::<usize>(&mut options, "read_ipc_stream_n_rows");
+
+    let columns = options
+        .remove("read_ipc_stream_columns")
+        .map(|s| s.split(',').map(|c| c.trim().to_string()).collect());
+
+    let row_index_offset =
+        opt_parse::<IdxSize>(&mut options, "read_ipc_stream_row_index_offset").unwrap_or(0);
+
+    let row_index = options
+        .remove("read_ipc_stream_row_index_name")
+        .map(|name| RowIndex {
+            name: name.into(),
+            offset: row_index_offset,
+        });
+
+    let rechunk = opt_parse::<bool>(&mut options, "read_ipc_stream_rechunk").unwrap_or(false);
+
+    let sources = read_sources(env, &paths, &buffers, options)?;
+
+    let mut dfs = Vec::with_capacity(sources.len());
+    for source in sources {
+        let df = IpcStreamReader::new(Cursor::new(source))
+            .with_n_rows(n_rows)
+            .with_columns(columns.clone())
+            .finish()
+            .context("Failed to read IPC stream")?;
+        dfs.push(df);
+    }
+
+    let df = combine_read_frames(dfs, n_rows, row_index, rechunk, "IPC stream")?;
+
+    Ok(DataFrameHandle::alloc(df))
+}
+
+pub const METHODS: &[NativeMethod] = &[READ_IPC_STREAM_METHOD];
@@ -0,0 +1,105 @@
+use std::io::Cursor;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+use anyhow::Context;
+use jni::objects::{JObject, JObjectArray, JString};
+use jni::{Env, NativeMethod, native_method};
+use polars::prelude::*;
+
+use crate::internal_jni::handle::{DataFrameHandle, Handle};
+use crate::internal_jni::io::read::{combine_read_frames, read_sources};
+use crate::internal_jni::io::{
+    opt_parse, opt_parse_schema, opt_parse_schema_overrides, parse_json_to_options,
+};
+use crate::utils::error::ThrowRuntimeException;
+
+/// Injects the shared `io.read$` config into [`native_method!`].
+macro_rules! read_method {
+    ($($tt:tt)*) => {
+        native_method! {
+            java_type = "com.github.chitralverma.polars.internal.jni.io.read$",
+            error_policy = ThrowRuntimeException,
+            type_map = { unsafe DataFrameHandle => long },
+            $($tt)*
+        }
+    };
+}
+
+const READ_JSON_METHOD: NativeMethod = read_method!(
+    extern fn read_json(paths: [java.lang.String], buffers: [java.lang.Object], options: java.lang.String) -> DataFrameHandle,
+    name = "readJson",
+);
+
+/// Reads each source as a whole JSON document (an array of objects, as written by `writeJson`)
+/// and concatenates them vertically. Compressed sources are decompressed based on their content.
+fn read_json<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    paths: JObjectArray<'local, JString<'local>>,
+    buffers: JObjectArray<'local, JObject<'local>>,
+    options: JString<'local>,
+) -> anyhow::Result<DataFrameHandle> {
+    let mut options = parse_json_to_options(env, &options)?;
+
+    let json_lines = options
+        .remove("read_json_format")
+        .is_some_and(|s| s.eq_ignore_ascii_case("json_lines"));
+
+    let schema = opt_parse_schema(&mut options, "read_json_schema")?.map(Arc::new);
+
+    let schema_overrides = opt_parse_schema_overrides(&mut options, "read_json_schema_overrides")?;
+
+    // A length of 0 infers the schema from all records.
+    let infer_schema_length = opt_parse::<usize>(&mut options, "read_json_infer_schema_length")
+        .map_or(NonZeroUsize::new(100), NonZeroUsize::new);
+
+    let ignore_errors = opt_parse::<bool>(&mut options, "read_json_ignore_errors").unwrap_or(false);
+
+    let columns: Option<Vec<PlSmallStr>> = options
+        .remove("read_json_columns")
+        .map(|s| s.split(',').map(|c| c.trim().into()).collect());
+
+    let n_rows = opt_parse::<usize>(&mut options, "read_json_n_rows");
+
+    let row_index_offset =
+        opt_parse::<IdxSize>(&mut options, "read_json_row_index_offset").unwrap_or(0);
+
+    let row_index = options
+        .remove("read_json_row_index_name")
+        .map(|name| RowIndex {
+            name: name.into(),
+            offset: row_index_offset,
+        });
+
+    let rechunk = opt_parse::<bool>(&mut options, "read_json_rechunk").unwrap_or(false);
+
+    let sources = read_sources(env, &paths, &buffers, options)?;
+
+    let mut dfs = Vec::with_capacity(sources.len());
+    for source in sources {
+        let mut reader = JsonReader::new(Cursor::new(source))
+            .with_json_format(if json_lines {
+                JsonFormat::JsonLines
+            } else {
+                JsonFormat::Json
+            })
+            .infer_schema_len(infer_schema_length)
+            .with_ignore_errors(ignore_errors)
+            .with_projection(columns.clone());
+        if let Some(schema) = &schema {
+            reader = reader.with_schema(schema.clone());
+        }
+        if let Some(overrides) = &schema_overrides {
+            reader = reader.with_schema_overwrite(overrides);
+        }
+        let df = reader.finish().context("Failed to read JSON data")?;
+        dfs.push(df);
+    }
+
+    let df = combine_read_frames(dfs, n_rows, row_index, rechunk, "JSON")?;
+
+    Ok(DataFrameHandle::alloc(df))
+}
+
+pub const METHODS: &[NativeMethod] = &[READ_JSON_METHOD];
@@ -0,0 +1,93 @@
+pub mod avro;
+pub mod ipc_stream;
+pub mod json;
+
+use anyhow::Context;
+use jni::Env;
+use jni::objects::{JObject, JObjectArray, JString};
+use object_store::ObjectStoreExt;
+use object_store::path::Path;
+use polars::io::cloud::{CloudOptions, build_object_store};
+use polars::prelude::*;
+use polars_buffer::Buffer;
+use polars_core::runtime::ASYNC;
+use polars_core::utils::concat_df;
+
+use crate::internal_jni::io::scan::build_scan_sources;
+
+async fn fetch_bytes(
+    uri: &str,
+    cloud_options: Option<&CloudOptions>,
+) -> anyhow::Result<Buffer<u8>> {
+    let (cloud_location, object_store) = build_object_store(uri.into(), cloud_options, false)
+        .await
+        .context(format!("Failed to connect to the object store of `{uri}`"))?;
+    let bytes = object_store
+        .to_dyn_object_store()
+        .await
+        .get(&Path::from(cloud_location.prefix.as_ref()))
+        .await
+        .context(format!("Failed to read `{uri}`"))?
+        .bytes()
+        .await
+        .context(format!("Failed to read `{uri}`"))?;
+
+    Ok(Buffer::from_owner(bytes))
+}
+
+/// Loads the whole content of each source, for readers of formats that cannot be scanned lazily.
+/// Sources are given as in [`build_scan_sources`], with paths (local or cloud) read through the
+/// object store and in-memory buffers used as they are.
+pub(crate) fn read_sources(
+    env: &mut Env,
+    paths: &JObjectArray<JString>,
+    buffers: &JObjectArray<JObject>,
+    options: PlHashMap<String, String>,
+) -> anyhow::Result<Vec<Buffer<u8>>> {
+    let (sources, cloud_options) = build_scan_sources(env, paths, buffers, options)?;
+
+    match sources {
+        ScanSources::Buffers(buffers) => Ok(buffers.to_vec()),
+        sources => {
+            let paths: Vec<String> = sources
+                .as_paths()
+                .unwrap_or_default()
+                .iter()
+                .map(|p| p.as_str().to_string())
+                .collect();
+
+            ASYNC.block_on(async {
+                let mut slices = Vec::with_capacity(paths.len());
+                for path in &paths {
+                    slices.push(fetch_bytes(path, cloud_options.as_ref()).await?);
+                }
+                Ok(slices)
+            })
+        },
+    }
+}
+
+/// Concatenates the DataFrames read from each source vertically, then applies the options shared
+/// by the eager readers: the overall row limit, the row index and rechunking.
+pub(crate) fn combine_read_frames(
+    dfs: Vec<DataFrame>,
+    n_rows: Option<usize>,
+    row_index: Option<RowIndex>,
+    rechunk: bool,
+    format: &str,
+) -> anyhow::Result<DataFrame> {
+    let mut df =
+        concat_df(&dfs).with_context(|| format!("Failed to concatenate the read {format} data"))?;
+    if let Some(n) = n_rows {
+        df = df.head(Some(n));
+    }
+    if let Some(RowIndex { name, offset }) = row_index {
+        df = df
+            .with_row_index(name, Some(offset))
+            .context("Failed to add row index")?;
+    }
+    if rechunk {
+        df.rechunk_mut_par();
+    }
+    Ok(df)
+}
@@ -1,12 +1,20 @@
-use anyhow::Context;
+use std::sync::Arc;
+
+use anyhow::{Context, ensure};
 use jni::objects::{JObject, JObjectArray, JString};
 use jni::{Env, NativeMethod, native_method};
 use polars::io::RowIndex;
 use polars::prelude::*;
 
+use crate::internal_jni::dtype::parse_dtype;
 use crate::internal_jni::handle::{Handle, LazyFrameHandle};
-use crate::internal_jni::io::scan::build_scan_sources;
-use crate::internal_jni::io::{opt_parse, parse_json_to_options};
+use crate::internal_jni::io::scan::{
+    build_scan_sources, check_scan_compression, opt_parse_columns, opt_parse_scan_compression,
+    select_scan_columns,
+};
+use crate::internal_jni::io::{
+    opt_parse, opt_parse_schema, opt_parse_schema_overrides, parse_json_to_options,
+};
 use crate::utils::error::ThrowRuntimeException;
 
 /// Injects the shared `io.scan$` config into [`native_method!`].
@@ -21,8 +29,45 @@ macro_rules! scan_method {
     };
 }
 
+/// Parses `scan_csv_null_values`: a string used for all columns, a JSON array of strings used for
+/// all columns, or a JSON object mapping column names to their null value.
+fn parse_null_values(value: &str) -> anyhow::Result<NullValues> {
+    let null_values = match serde_json::from_str::<serde_json::Value>(value) {
+        Ok(serde_json::Value::Array(values)) => NullValues::AllColumns(
+            values
+                .iter()
+                .map(|v| v.as_str().map(PlSmallStr::from))
+                .collect::<Option<_>>()
+                .context("Expected `scan_csv_null_values` to be an array of strings")?,
+        ),
+        Ok(serde_json::Value::Object(values)) => NullValues::Named(
+            values
+                .iter()
+                .map(|(name, v)| Some((name.as_str().into(), v.as_str()?.into())))
+                .collect::<Option<_>>()
+                .context("Expected `scan_csv_null_values` to map columns to strings")?,
+        ),
+        Ok(serde_json::Value::String(single)) => NullValues::AllColumnsSingle(single.into()),
+        _ => NullValues::AllColumnsSingle(value.into()),
+    };
+    Ok(null_values)
+}
+
+/// Parses `scan_csv_dtypes`, a JSON array of data types applied to the columns by position.
+fn parse_positional_dtypes(value: &str) -> anyhow::Result<Vec<DataType>> {
+    let dtypes: Vec<serde_json::Value> = serde_json::from_str(value)
+        .context("Expected `scan_csv_dtypes` to be a JSON array of data types")?;
+    dtypes
+        .iter()
+        .map(|dtype| match dtype {
+            serde_json::Value::String(s) => parse_dtype(s),
+            other => parse_dtype(&other.to_string()),
+        })
+        .collect()
+}
+
 const SCAN_CSV_METHOD: NativeMethod = scan_method!(
-    extern fn scan_csv(paths: [java.lang.String], options: java.lang.String) -> LazyFrameHandle,
+    extern fn scan_csv(paths: [java.lang.String], buffers: [java.lang.Object], options: java.lang.String) -> LazyFrameHandle,
     name = "scanCSV",
 );
 
@@ -30,6 +75,7 @@ fn scan_csv<'local>(
     env: &mut Env<'local>,
     _this: JObject<'local>,
     paths: JObjectArray<'local, JString<'local>>,
+    buffers: JObjectArray<'local, JObject<'local>>,
     options: JString<'local>,
 ) -> anyhow::Result<LazyFrameHandle> {
     let mut options = parse_json_to_options(env, &options)?;
@@ -151,19 +197,60 @@ fn scan_csv<'local>(
         .unwrap_or_default();
 
     let null_value = options
-        .remove("scan_csv_null_value")
-        .map(|s| NullValues::AllColumnsSingle(s.as_str().into()));
+        .remove("scan_csv_null_values")
+        .map(|s| parse_null_values(&s))
+        .transpose()?
+        .or_else(|| {
+            options
+                .remove("scan_csv_null_value")
+                .map(|s| NullValues::AllColumnsSingle(s.as_str().into()))
+        });
+
+    let schema = opt_parse_schema(&mut options, "scan_csv_schema")?.map(Arc::new);
+
+    let mut schema_overrides =
+        opt_parse_schema_overrides(&mut options, "scan_csv_schema_overrides")?;
+
+    // Header-less files get the column names `column_1`, `column_2`, etc., so positional dtypes
+    // become overrides of those names. Named overrides take precedence.
+    if let Some(dtypes) = options.remove("scan_csv_dtypes") {
+        ensure!(
+            !has_header,
+            "`scan_csv_dtypes` is only supported for files without a header, use \
+             `scan_csv_schema_overrides` to set data types by column name"
+        );
+        let mut overrides: Schema = parse_positional_dtypes(&dtypes)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, dtype)| (PlSmallStr::from_string(format!("column_{}", i + 1)), dtype))
+            .collect();
+        if let Some(named) = schema_overrides {
+            overrides.merge(named);
+        }
+        schema_overrides = Some(overrides);
+    }
+
+    let columns = opt_parse_columns(&mut options, "scan_csv_columns");
 
     let comment_prefix = options
         .remove("scan_csv_comment_prefix")
         .map(PlSmallStr::from);
 
-    let (sources, cloud_options) = build_scan_sources(env, &paths, options)?;
+    let compression = opt_parse_scan_compression(&mut options, "scan_csv_compression")?;
+
+    let (sources, cloud_options) = build_scan_sources(env, &paths, &buffers, options)?;
+    if let Some(compression) = compression {
+        check_scan_compression(&sources, compression)?;
+    }
+
+    let row_index_name = row_index.as_ref().map(|ri| ri.name.clone());
 
     let ldf = LazyCsvReader::new_with_sources(sources)
+        .with_schema(schema)
+        .with_dtype_overwrite(schema_overrides.map(Arc::new))
         .with_glob(glob)
         .with_cache(cache)
-        .with_include_file_paths(file_path_col)
+        .with_include_file_paths(file_path_col.clone())
         .with_low_memory(low_memory)
         .with_rechunk(rechunk)
         .with_n_rows(n_rows)
@@ -189,6 +276,13 @@ fn scan_csv<'local>(
         .finish()
         .context("Failed to perform csv scan")?;
 
+    let ldf = select_scan_columns(
+        ldf,
+        columns,
+        row_index_name.as_ref(),
+        file_path_col.as_ref(),
+    );
+
     Ok(LazyFrameHandle::alloc(ldf))
 }
 
@@ -22,7 +22,7 @@ macro_rules! scan_method {
 }
 
 const SCAN_IPC_METHOD: NativeMethod = scan_method!(
-    extern fn scan_ipc(paths: [java.lang.String], options: java.lang.String) -> LazyFrameHandle,
+    extern fn scan_ipc(paths: [java.lang.String], buffers: [java.lang.Object], options: java.lang.String) -> LazyFrameHandle,
     name = "scanIPC",
 );
 
@@ -30,6 +30,7 @@ fn scan_ipc<'local>(
     env: &mut Env<'local>,
     _this: JObject<'local>,
     paths: JObjectArray<'local, JString<'local>>,
+    buffers: JObjectArray<'local, JObject<'local>>,
     options: JString<'local>,
 ) -> anyhow::Result<LazyFrameHandle> {
     let mut options = parse_json_to_options(env, &options)?;
@@ -80,7 +81,7 @@ fn scan_ipc<'local>(
         .and_then(|s| s.parse::<bool>().ok())
         .unwrap_or(true);
 
-    let (sources, cloud_options) = build_scan_sources(env, &paths, options)?;
+    let (sources, cloud_options) = build_scan_sources(env, &paths, &buffers, options)?;
 
     let options = IpcScanOptions {
         record_batch_statistics: use_statistics,
@@ -1,5 +1,6 @@
 use std::num::NonZeroUsize;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use anyhow::Context;
 use jni::objects::{JObject, JObjectArray, JString};
@@ -8,8 +9,13 @@ use polars::io::RowIndex;
 use polars::prelude::*;
 
 use crate::internal_jni::handle::{Handle, LazyFrameHandle};
-use crate::internal_jni::io::scan::build_scan_sources;
-use crate::internal_jni::io::{opt_parse, parse_json_to_options};
+use crate::internal_jni::io::scan::{
+    build_scan_sources, check_scan_compression, opt_parse_columns, opt_parse_scan_compression,
+    select_scan_columns,
+};
+use crate::internal_jni::io::{
+    opt_parse, opt_parse_schema, opt_parse_schema_overrides, parse_json_to_options,
+};
 use crate::utils::error::ThrowRuntimeException;
 
 /// Injects the shared `io.scan$` config into [`native_method!`].
@@ -25,7 +31,7 @@ macro_rules! scan_method {
 }
 
 const SCAN_JSON_LINES_METHOD: NativeMethod = scan_method!(
-    extern fn scan_json_lines(paths: [java.lang.String], options: java.lang.String) -> LazyFrameHandle,
+    extern fn scan_json_lines(paths: [java.lang.String], buffers: [java.lang.Object], options: java.lang.String) -> LazyFrameHandle,
     name = "scanJsonLines",
 );
 
@@ -33,6 +39,7 @@ fn scan_json_lines<'local>(
     env: &mut Env<'local>,
     _this: JObject<'local>,
     paths: JObjectArray<'local, JString<'local>>,
+    buffers: JObjectArray<'local, JObject<'local>>,
     options: JString<'local>,
 ) -> anyhow::Result<LazyFrameHandle> {
     let mut options = parse_json_to_options(env, &options)?;
@@ -79,7 +86,21 @@ fn scan_json_lines<'local>(
         .and_then(|s| NonZeroUsize::from_str(s.as_str()).ok())
         .map_or(NonZeroUsize::new(100), Some);
 
-    let (sources, cloud_options) = build_scan_sources(env, &paths, options)?;
+    let schema = opt_parse_schema(&mut options, "scan_ndjson_schema")?.map(Arc::new);
+
+    let schema_overrides =
+        opt_parse_schema_overrides(&mut options, "scan_ndjson_schema_overrides")?.map(Arc::new);
+
+    let columns = opt_parse_columns(&mut options, "scan_ndjson_columns");
+
+    let compression = opt_parse_scan_compression(&mut options, "scan_ndjson_compression")?;
+
+    let (sources, cloud_options) = build_scan_sources(env, &paths, &buffers, options)?;
+    if let Some(compression) = compression {
+        check_scan_compression(&sources, compression)?;
+    }
+
+    let row_index_name = row_index.as_ref().map(|ri| ri.name.clone());
 
     let ldf = LazyJsonLineReader::new_with_sources(sources)
         .low_memory(low_memory)
@@ -87,13 +108,22 @@ fn scan_json_lines<'local>(
         .with_n_rows(n_rows)
         .with_row_index(row_index)
         .with_infer_schema_length(infer_schema_length)
+        .with_schema(schema)
+        .with_schema_overwrite(schema_overrides)
         .with_ignore_errors(ignore_errors)
         .with_batch_size(batch_size)
-        .with_include_file_paths(file_path_col)
+        .with_include_file_paths(file_path_col.clone())
         .with_cloud_options(cloud_options)
         .finish()
         .context("Failed to perform ndjson scan")?;
 
+    let ldf = select_scan_columns(
+        ldf,
+        columns,
+        row_index_name.as_ref(),
+        file_path_col.as_ref(),
+    );
+
     Ok(LazyFrameHandle::alloc(ldf))
 }
 
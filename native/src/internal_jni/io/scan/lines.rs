@@ -0,0 +1,88 @@
+use anyhow::Context;
+use jni::objects::{JObject, JObjectArray, JString};
+use jni::{Env, NativeMethod, native_method};
+use polars::io::RowIndex;
+use polars::prelude::*;
+use polars_plan::dsl::DslBuilder;
+use polars_utils::slice_enum::Slice;
+
+use crate::internal_jni::handle::{Handle, LazyFrameHandle};
+use crate::internal_jni::io::scan::build_scan_sources;
+use crate::internal_jni::io::{opt_parse, parse_json_to_options};
+use crate::utils::error::ThrowRuntimeException;
+
+/// Injects the shared `io.scan$` config into [`native_method!`].
+macro_rules! scan_method {
+    ($($tt:tt)*) => {
+        native_method! {
+            java_type = "com.github.chitralverma.polars.internal.jni.io.scan$",
+            error_policy = ThrowRuntimeException,
+            type_map = { unsafe LazyFrameHandle => long },
+            $($tt)*
+        }
+    };
+}
+
+const SCAN_LINES_METHOD: NativeMethod = scan_method!(
+    extern fn scan_lines(paths: [java.lang.String], buffers: [java.lang.Object], options: java.lang.String) -> LazyFrameHandle,
+    name = "scanLines",
+);
+
+/// Scans plain text with one string row per line. Compressed inputs (gzip, zlib, zstd) are
+/// decompressed based on their content.
+fn scan_lines<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    paths: JObjectArray<'local, JString<'local>>,
+    buffers: JObjectArray<'local, JObject<'local>>,
+    options: JString<'local>,
+) -> anyhow::Result<LazyFrameHandle> {
+    let mut options = parse_json_to_options(env, &options)?;
+
+    let name = options
+        .remove("scan_lines_name")
+        .map_or(PlSmallStr::from_static("lines"), PlSmallStr::from_string);
+
+    let n_rows = opt_parse::<usize>(&mut options, "scan_lines_n_rows");
+
+    let cache = opt_parse::<bool>(&mut options, "scan_lines_cache").unwrap_or(true);
+
+    let glob = opt_parse::<bool>(&mut options, "scan_lines_glob").unwrap_or(true);
+
+    let rechunk = opt_parse::<bool>(&mut options, "scan_lines_rechunk").unwrap_or(false);
+
+    let row_index_offset =
+        opt_parse::<IdxSize>(&mut options, "scan_lines_row_index_offset").unwrap_or(0);
+
+    let row_index = options
+        .remove("scan_lines_row_index_name")
+        .map(|name| RowIndex {
+            name: name.into(),
+            offset: row_index_offset,
+        });
+
+    let file_path_col = options
+        .remove("scan_lines_include_file_paths")
+        .map(PlSmallStr::from_string);
+
+    let (sources, cloud_options) = build_scan_sources(env, &paths, &buffers, options)?;
+
+    let unified_scan_args = UnifiedScanArgs {
+        cache,
+        rechunk,
+        row_index,
+        glob,
+        cloud_options,
+        pre_slice: n_rows.map(|len| Slice::Positive { offset: 0, len }),
+        include_file_paths: file_path_col,
+        ..Default::default()
+    };
+
+    let plan = DslBuilder::scan_lines(sources, unified_scan_args, name)
+        .context("Failed to perform lines scan")?
+        .build();
+
+    Ok(LazyFrameHandle::alloc(LazyFrame::from(plan)))
+}
+
+pub const METHODS: &[NativeMethod] = &[SCAN_LINES_METHOD];
@@ -1,30 +1,85 @@
 pub mod csv;
 pub mod ipc;
 pub mod json_lines;
+pub mod lines;
 pub mod parquet;
 
-use jni::Env;
-use jni::objects::{JObjectArray, JString};
+use std::io::Read;
+
+use anyhow::{Context, bail, ensure};
+use jni::objects::{IntoAuto, JByteArray, JByteBuffer, JObject, JObjectArray, JString};
+use jni::{Env, jni_str};
 use polars::io::cloud::CloudOptions;
+use polars::io::utils::compression::SupportedCompression;
 use polars::prelude::*;
+use polars_buffer::Buffer;
 
+use crate::internal_jni::direct_buffer::buffer_from_direct_buffer;
 use crate::internal_jni::io::parse_cloud_options;
 use crate::internal_jni::utils::j_string_array_to_vec;
 
-/// Builds the [`ScanSources`] from the JVM path array and derives cloud options from the remaining
+/// Reads in-memory sources, each either a `byte[]`, which is copied, or a direct
+/// `java.nio.ByteBuffer`, which is scanned in place.
+fn build_buffer_sources(
+    env: &mut Env,
+    buffers: &JObjectArray<JObject>,
+) -> anyhow::Result<ScanSources> {
+    let len = buffers
+        .len(env)
+        .context("Failed to get number of provided buffers")?;
+
+    let mut slices = Vec::with_capacity(len);
+    for i in 0..len {
+        let buffer = buffers
+            .get_element(env, i)
+            .context("Failed to get provided buffer")?
+            .auto();
+
+        let slice = if env.is_instance_of(&buffer, jni_str!("[B")).unwrap_or(false) {
+            let bytes = env
+                .as_cast::<JByteArray>(&buffer)
+                .context("Failed to read provided buffer as byte array")?;
+            let data = env
+                .convert_byte_array(&*bytes)
+                .context("Failed to read provided byte array")?;
+            Buffer::from_vec(data)
+        } else if env
+            .is_instance_of(&buffer, jni_str!("java/nio/ByteBuffer"))
+            .unwrap_or(false)
+        {
+            let buffer = env
+                .as_cast::<JByteBuffer>(&buffer)
+                .context("Failed to read provided buffer as ByteBuffer")?;
+            buffer_from_direct_buffer(env, &buffer)?
+        } else {
+            bail!("Provided buffer {i} must be a byte[] or a direct ByteBuffer")
+        };
+        slices.push(slice);
+    }
+
+    Ok(ScanSources::Buffers(slices.into()))
+}
+
+/// Builds the [`ScanSources`] from the JVM path array, or if it is `null`, from the in-memory
+/// `buffers` (see [`build_buffer_sources`]), and derives cloud options from the remaining
 /// `options`. `parse_cloud_options` consumes the map, so callers must strip format-specific keys first.
 pub(crate) fn build_scan_sources(
     env: &mut Env,
     paths: &JObjectArray<JString>,
+    buffers: &JObjectArray<JObject>,
     options: PlHashMap<String, String>,
 ) -> anyhow::Result<(ScanSources, Option<CloudOptions>)> {
-    let paths_vec: Vec<PlRefPath> =
-        j_string_array_to_vec(env, paths, "Failed to get provided path")?
-            .into_iter()
-            .map(PlRefPath::new)
-            .collect();
+    let sources = if paths.is_null() {
+        build_buffer_sources(env, buffers)?
+    } else {
+        let paths_vec: Vec<PlRefPath> =
+            j_string_array_to_vec(env, paths, "Failed to get provided path")?
+                .into_iter()
+                .map(PlRefPath::new)
+                .collect();
+        ScanSources::Paths(paths_vec.into())
+    };
 
-    let sources = ScanSources::Paths(paths_vec.into());
     let cloud_scheme = sources
         .first_path()
         .cloned()
@@ -35,3 +90,124 @@ pub(crate) fn build_scan_sources(
 
     Ok((sources, cloud_options))
 }
+
+/// Removes `key` from `options` and parses its value as comma separated column names to read.
+pub(crate) fn opt_parse_columns(
+    options: &mut PlHashMap<String, String>,
+    key: &str,
+) -> Option<Vec<PlSmallStr>> {
+    options
+        .remove(key)
+        .map(|s| s.split(',').map(|c| c.trim().into()).collect())
+}
+
+/// Projects a scan to `columns`, keeping the row index and file path columns the scan adds, so
+/// that only the requested columns are read.
+pub(crate) fn select_scan_columns(
+    ldf: LazyFrame,
+    columns: Option<Vec<PlSmallStr>>,
+    row_index: Option<&PlSmallStr>,
+    file_path_col: Option<&PlSmallStr>,
+) -> LazyFrame {
+    match columns {
+        Some(columns) => {
+            let exprs: Vec<Expr> = row_index
+                .into_iter()
+                .chain(columns.iter())
+                .chain(file_path_col)
+                .map(|name| col(name.clone()))
+                .collect();
+            ldf.select(exprs)
+        },
+        None => ldf,
+    }
+}
+
+/// Compression expected of every scan source, see [`check_scan_compression`].
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum ScanCompression {
+    Uncompressed,
+    Compressed(SupportedCompression),
+}
+
+impl std::fmt::Display for ScanCompression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ScanCompression::Uncompressed => "uncompressed",
+            ScanCompression::Compressed(SupportedCompression::GZIP) => "gzip",
+            ScanCompression::Compressed(SupportedCompression::ZLIB) => "zlib",
+            ScanCompression::Compressed(SupportedCompression::ZSTD) => "zstd",
+        })
+    }
+}
+
+/// Removes `key` from `options` and parses its value as the compression expected of the scan
+/// sources: `auto` (the default, `None`) to detect it from their content, or one of
+/// `uncompressed`, `gzip`, `zlib` or `zstd`.
+pub(crate) fn opt_parse_scan_compression(
+    options: &mut PlHashMap<String, String>,
+    key: &str,
+) -> anyhow::Result<Option<ScanCompression>> {
+    let Some(value) = options.remove(key) else {
+        return Ok(None);
+    };
+
+    let compression = match value.to_lowercase().as_str() {
+        "auto" => None,
+        "uncompressed" => Some(ScanCompression::Uncompressed),
+        "gzip" => Some(ScanCompression::Compressed(SupportedCompression::GZIP)),
+        "zlib" => Some(ScanCompression::Compressed(SupportedCompression::ZLIB)),
+        "zstd" => Some(ScanCompression::Compressed(SupportedCompression::ZSTD)),
+        other => bail!(
+            "Unknown `{key}` value `{other}`, expected one of `auto`, `uncompressed`, `gzip`, \
+             `zlib` or `zstd`"
+        ),
+    };
+    Ok(compression)
+}
+
+/// Checks that the in-memory and local file sources are compressed with `expected`, from their
+/// leading bytes. Cloud paths and glob patterns are left to the reader, which always detects the
+/// compression from the content.
+pub(crate) fn check_scan_compression(
+    sources: &ScanSources,
+    expected: ScanCompression,
+) -> anyhow::Result<()> {
+    let detect = |bytes: &[u8]| match SupportedCompression::check(bytes) {
+        Some(compression) => ScanCompression::Compressed(compression),
+        None => ScanCompression::Uncompressed,
+    };
+
+    match sources {
+        ScanSources::Buffers(buffers) => {
+            for (i, buffer) in buffers.iter().enumerate() {
+                let found = detect(buffer);
+                ensure!(
+                    found == expected,
+                    "Buffer {i} is {found}, but the scan expects {expected} input"
+                );
+            }
+        },
+        ScanSources::Paths(paths) => {
+            for path in paths.iter() {
+                let path = std::path::Path::new(path.as_str());
+                if !path.is_file() {
+                    continue;
+                }
+
+                let mut head = Vec::with_capacity(4);
+                std::fs::File::open(path)
+                    .and_then(|file| file.take(4).read_to_end(&mut head))
+                    .context(format!("Failed to read `{}`", path.display()))?;
+                let found = detect(&head);
+                ensure!(
+                    found == expected,
+                    "`{}` is {found}, but the scan expects {expected} input",
+                    path.display()
+                );
+            }
+        },
+        ScanSources::Files(_) => {},
+    }
+    Ok(())
+}
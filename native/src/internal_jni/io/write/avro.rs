@@ -1,11 +1,13 @@
-use jni::objects::{JObject, JString};
+use jni::objects::{JByteArray, JObject, JString};
 use jni::{Env, NativeMethod, native_method};
 use polars::io::avro::{AvroCompression, AvroWriter};
 use polars::prelude::*;
 
 use crate::internal_jni::handle::{DataFrameHandle, Handle};
 use crate::internal_jni::io::parse_json_to_options;
-use crate::internal_jni::io::write::{parse_overwrite_mode, write_dataframe};
+use crate::internal_jni::io::write::{
+    parse_overwrite_mode, write_dataframe, written_bytes_to_java,
+};
 use crate::utils::error::ThrowRuntimeException;
 
 /// Injects the shared `io.write$` config into [`native_method!`].
@@ -38,7 +40,7 @@ fn parse_avro_compression(compression: Option<String>) -> Option<AvroCompression
 }
 
 const WRITE_AVRO_METHOD: NativeMethod = write_method!(
-    extern fn write_avro(df: DataFrameHandle, file_path: java.lang.String, options: java.lang.String),
+    extern fn write_avro(df: DataFrameHandle, file_path: java.lang.String, options: java.lang.String) -> [jbyte],
     name = "writeAvro",
 );
 
@@ -48,7 +50,7 @@ fn write_avro<'local>(
     df: DataFrameHandle,
     file_path: JString<'local>,
     options: JString<'local>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<JByteArray<'local>> {
     let mut options = parse_json_to_options(env, &options)?;
 
     let record_name = options.remove("write_avro_record_name");
@@ -57,7 +59,7 @@ fn write_avro<'local>(
 
     let compression = options.remove("write_compression");
 
-    write_dataframe(
+    let bytes = write_dataframe(
         env,
         df.get(),
         &file_path,
@@ -77,7 +79,7 @@ fn write_avro<'local>(
         },
     )?;
 
-    Ok(())
+    written_bytes_to_java(env, bytes)
 }
 
 pub const METHODS: &[NativeMethod] = &[WRITE_AVRO_METHOD];
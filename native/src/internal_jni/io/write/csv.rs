@@ -1,9 +1,12 @@
-use jni::objects::{JObject, JString};
+use jni::objects::{JByteArray, JObject, JString};
 use jni::{Env, NativeMethod, native_method};
 use polars::prelude::*;
 
 use crate::internal_jni::handle::{DataFrameHandle, Handle};
-use crate::internal_jni::io::write::{parse_overwrite_mode, write_dataframe};
+use crate::internal_jni::io::write::{
+    parse_external_compression, parse_overwrite_mode, write_compressed, write_dataframe,
+    written_bytes_to_java,
+};
 use crate::internal_jni::io::{opt_parse, parse_json_to_options};
 use crate::utils::error::ThrowRuntimeException;
 
@@ -20,7 +23,7 @@ macro_rules! write_method {
 }
 
 const WRITE_CSV_METHOD: NativeMethod = write_method!(
-    extern fn write_csv(df: DataFrameHandle, file_path: java.lang.String, options: java.lang.String),
+    extern fn write_csv(df: DataFrameHandle, file_path: java.lang.String, options: java.lang.String) -> [jbyte],
     name = "writeCSV",
 );
 
@@ -30,7 +33,7 @@ fn write_csv<'local>(
     df: DataFrameHandle,
     file_path: JString<'local>,
     options: JString<'local>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<JByteArray<'local>> {
     let mut options = parse_json_to_options(env, &options)?;
 
     let include_bom = opt_parse::<bool>(&mut options, "write_csv_include_bom");
@@ -63,9 +66,13 @@ fn write_csv<'local>(
             _ => QuoteStyle::Necessary,
         });
 
+    let compression = options.remove("write_compression");
+    let compression_level = opt_parse::<i32>(&mut options, "write_compression_level");
+    let compression = parse_external_compression(compression, compression_level)?;
+
     let overwrite_mode = parse_overwrite_mode(&mut options);
 
-    write_dataframe(
+    let bytes = write_dataframe(
         env,
         df.get(),
         &file_path,
@@ -73,46 +80,48 @@ fn write_csv<'local>(
         options,
         "CSV",
         |writer, dataframe| {
-            let mut csv_writer = CsvWriter::new(writer)
-                .with_date_format(date_format)
-                .with_time_format(time_format)
-                .with_datetime_format(datetime_format)
-                .with_float_precision(float_precision)
-                .with_float_scientific(float_scientific);
-
-            if let Some(value) = include_bom {
-                csv_writer = csv_writer.include_bom(value)
-            }
-
-            if let Some(value) = include_header {
-                csv_writer = csv_writer.include_header(value)
-            }
-
-            if let Some(value) = separator {
-                csv_writer = csv_writer.with_separator(value)
-            }
-
-            if let Some(value) = quote_char {
-                csv_writer = csv_writer.with_quote_char(value)
-            }
-
-            if let Some(value) = line_terminator {
-                csv_writer = csv_writer.with_line_terminator(value.into())
-            }
-
-            if let Some(value) = null_value {
-                csv_writer = csv_writer.with_null_value(value.into())
-            }
-
-            if let Some(value) = quote_style {
-                csv_writer = csv_writer.with_quote_style(value)
-            }
-
-            csv_writer.finish(dataframe)
+            write_compressed(writer, compression.as_ref(), |writer| {
+                let mut csv_writer = CsvWriter::new(writer)
+                    .with_date_format(date_format)
+                    .with_time_format(time_format)
+                    .with_datetime_format(datetime_format)
+                    .with_float_precision(float_precision)
+                    .with_float_scientific(float_scientific);
+
+                if let Some(value) = include_bom {
+                    csv_writer = csv_writer.include_bom(value)
+                }
+
+                if let Some(value) = include_header {
+                    csv_writer = csv_writer.include_header(value)
+                }
+
+                if let Some(value) = separator {
+                    csv_writer = csv_writer.with_separator(value)
+                }
+
+                if let Some(value) = quote_char {
+                    csv_writer = csv_writer.with_quote_char(value)
+                }
+
+                if let Some(value) = line_terminator {
+                    csv_writer = csv_writer.with_line_terminator(value.into())
+                }
+
+                if let Some(value) = null_value {
+                    csv_writer = csv_writer.with_null_value(value.into())
+                }
+
+                if let Some(value) = quote_style {
+                    csv_writer = csv_writer.with_quote_style(value)
+                }
+
+                csv_writer.finish(dataframe)
+            })
         },
     )?;
 
-    Ok(())
+    written_bytes_to_java(env, bytes)
 }
 
 pub const METHODS: &[NativeMethod] = &[WRITE_CSV_METHOD];
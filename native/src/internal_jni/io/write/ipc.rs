@@ -1,10 +1,12 @@
-use jni::objects::{JObject, JString};
+use jni::objects::{JByteArray, JObject, JString};
 use jni::{Env, NativeMethod, native_method};
 use polars::prelude::*;
 use polars_utils::compression::ZstdLevel;
 
 use crate::internal_jni::handle::{DataFrameHandle, Handle};
-use crate::internal_jni::io::write::{parse_overwrite_mode, write_dataframe};
+use crate::internal_jni::io::write::{
+    parse_overwrite_mode, write_dataframe, written_bytes_to_java,
+};
 use crate::internal_jni::io::{opt_parse, parse_json_to_options};
 use crate::utils::error::ThrowRuntimeException;
 
@@ -20,7 +22,19 @@ macro_rules! write_method {
     };
 }
 
-fn parse_ipc_compression(
+/// Parses the IPC compat level, shared by the IPC file and stream writers.
+pub(crate) fn parse_ipc_compat_level(
+    options: &mut PlHashMap<String, String>,
+) -> Option<CompatLevel> {
+    options
+        .remove("write_ipc_compat_level")
+        .map(|s| match s.to_lowercase().as_str() {
+            "newest" => CompatLevel::newest(),
+            _ => CompatLevel::oldest(),
+        })
+}
+
+pub(crate) fn parse_ipc_compression(
     compression: Option<String>,
     compression_level: Option<i32>,
 ) -> Option<IpcCompression> {
@@ -46,7 +60,7 @@ fn parse_ipc_compression(
 }
 
 const WRITE_IPC_METHOD: NativeMethod = write_method!(
-    extern fn write_ipc(df: DataFrameHandle, file_path: java.lang.String, options: java.lang.String),
+    extern fn write_ipc(df: DataFrameHandle, file_path: java.lang.String, options: java.lang.String) -> [jbyte],
     name = "writeIPC",
 );
 
@@ -56,23 +70,17 @@ fn write_ipc<'local>(
     df: DataFrameHandle,
     file_path: JString<'local>,
     options: JString<'local>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<JByteArray<'local>> {
     let mut options = parse_json_to_options(env, &options)?;
 
-    let compat_level =
-        options
-            .remove("write_ipc_compat_level")
-            .map(|s| match s.to_lowercase().as_str() {
-                "newest" => CompatLevel::newest(),
-                _ => CompatLevel::oldest(),
-            });
+    let compat_level = parse_ipc_compat_level(&mut options);
 
     let overwrite_mode = parse_overwrite_mode(&mut options);
 
     let compression = options.remove("write_compression");
     let compression_level = opt_parse::<i32>(&mut options, "write_compression_level");
 
-    write_dataframe(
+    let bytes = write_dataframe(
         env,
         df.get(),
         &file_path,
@@ -92,7 +100,52 @@ fn write_ipc<'local>(
         },
     )?;
 
-    Ok(())
+    written_bytes_to_java(env, bytes)
+}
+
+const WRITE_IPC_STREAM_METHOD: NativeMethod = write_method!(
+    extern fn write_ipc_stream(df: DataFrameHandle, file_path: java.lang.String, options: java.lang.String) -> [jbyte],
+    name = "writeIPCStream",
+);
+
+/// Writes the DataFrame in the Arrow IPC stream format, with the same options as `writeIPC`.
+fn write_ipc_stream<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    df: DataFrameHandle,
+    file_path: JString<'local>,
+    options: JString<'local>,
+) -> anyhow::Result<JByteArray<'local>> {
+    let mut options = parse_json_to_options(env, &options)?;
+
+    let compat_level = parse_ipc_compat_level(&mut options);
+
+    let overwrite_mode = parse_overwrite_mode(&mut options);
+
+    let compression = options.remove("write_compression");
+    let compression_level = opt_parse::<i32>(&mut options, "write_compression_level");
+
+    let bytes = write_dataframe(
+        env,
+        df.get(),
+        &file_path,
+        overwrite_mode,
+        options,
+        "IPC stream",
+        |writer, dataframe| {
+            let ipc_compression = parse_ipc_compression(compression, compression_level);
+
+            let mut ipc_writer = IpcStreamWriter::new(writer).with_compression(ipc_compression);
+
+            if let Some(value) = compat_level {
+                ipc_writer = ipc_writer.with_compat_level(value)
+            }
+
+            ipc_writer.finish(dataframe)
+        },
+    )?;
+
+    written_bytes_to_java(env, bytes)
 }
 
-pub const METHODS: &[NativeMethod] = &[WRITE_IPC_METHOD];
+pub const METHODS: &[NativeMethod] = &[WRITE_IPC_METHOD, WRITE_IPC_STREAM_METHOD];
@@ -0,0 +1,200 @@
+//! Incremental writing of the Arrow IPC stream format, one DataFrame at a time, to a path (local or
+//! cloud) or into memory. The stream is started with the schema of the first written DataFrame, and
+//! every later DataFrame must have the same schema.
+
+use std::io::Write;
+
+use anyhow::{Context, bail, ensure};
+use jni::objects::{JByteArray, JObject, JString};
+use jni::{Env, NativeMethod, native_method};
+use polars::io::cloud::cloud_writer::CloudWriterIoTraitWrap;
+use polars::io::utils::file::WriteableTrait;
+use polars::prelude::*;
+use polars_arrow::io::ipc::write::{StreamWriter, WriteOptions};
+
+use crate::internal_jni::handle::{DataFrameHandle, Handle, IpcStreamWriterHandle};
+use crate::internal_jni::io::write::ipc::{parse_ipc_compat_level, parse_ipc_compression};
+use crate::internal_jni::io::write::{
+    open_file_writer, parse_overwrite_mode, written_bytes_to_java,
+};
+use crate::internal_jni::io::{opt_parse, parse_json_to_options};
+use crate::internal_jni::macros::decl_free;
+use crate::utils::error::ThrowRuntimeException;
+
+/// Injects the shared `io.ipc_stream_writer$` config into [`native_method!`].
+macro_rules! stream_method {
+    ($($tt:tt)*) => {
+        native_method! {
+            java_type = "com.github.chitralverma.polars.internal.jni.io.ipc_stream_writer$",
+            error_policy = ThrowRuntimeException,
+            type_map = {
+                unsafe DataFrameHandle => long,
+                unsafe IpcStreamWriterHandle => long,
+            },
+            $($tt)*
+        }
+    };
+}
+
+/// Where the stream is written to.
+enum StreamSink {
+    File(CloudWriterIoTraitWrap),
+    Memory(Vec<u8>),
+}
+
+impl Write for StreamSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            StreamSink::File(writer) => writer.write(buf),
+            StreamSink::Memory(buffer) => buffer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            StreamSink::File(writer) => writer.flush(),
+            StreamSink::Memory(buffer) => buffer.flush(),
+        }
+    }
+}
+
+pub struct IpcStreamBatchWriter {
+    /// `None` once the stream is finished.
+    writer: Option<StreamWriter<StreamSink>>,
+    /// Schema of the stream, set by the first written DataFrame.
+    schema: Option<SchemaRef>,
+    compat_level: CompatLevel,
+}
+
+impl IpcStreamBatchWriter {
+    fn write_batch(&mut self, mut df: DataFrame) -> anyhow::Result<()> {
+        let writer = self
+            .writer
+            .as_mut()
+            .context("IPC stream is already finished")?;
+
+        match &self.schema {
+            Some(schema) => ensure!(
+                df.schema() == schema,
+                "DataFrame schema {:?} does not match the schema of the IPC stream {:?}",
+                df.schema(),
+                schema
+            ),
+            None => {
+                writer
+                    .start(&df.schema().to_arrow(self.compat_level), None)
+                    .context("Failed to start IPC stream")?;
+                self.schema = Some(df.schema().clone());
+            },
+        }
+
+        df.align_chunks_par();
+        for batch in df.iter_chunks(self.compat_level, true) {
+            writer
+                .write(&batch, None)
+                .context("Failed to write batch to IPC stream")?;
+        }
+        Ok(())
+    }
+
+    /// Ends the stream, returning the written bytes if it was written into memory.
+    fn finish(&mut self) -> anyhow::Result<Option<Vec<u8>>> {
+        if self.writer.is_some() && self.schema.is_none() {
+            bail!("No DataFrame was written to the IPC stream, so its schema is unknown")
+        }
+        let mut writer = self
+            .writer
+            .take()
+            .context("IPC stream is already finished")?;
+
+        writer.finish().context("Failed to finish IPC stream")?;
+        match writer.into_inner() {
+            StreamSink::File(mut file_writer) => {
+                file_writer
+                    .close()
+                    .context("Failed to finalize IPC stream data")?;
+                Ok(None)
+            },
+            StreamSink::Memory(buffer) => Ok(Some(buffer)),
+        }
+    }
+}
+
+const OPEN_METHOD: NativeMethod = stream_method! {
+    extern fn open(file_path: java.lang.String, options: java.lang.String) -> IpcStreamWriterHandle,
+};
+
+/// Opens a stream to `file_path`, or into memory if it is `null`, with the `write_mode`,
+/// compression and compat level options of `writeIPC`.
+fn open<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    file_path: JString<'local>,
+    options: JString<'local>,
+) -> anyhow::Result<IpcStreamWriterHandle> {
+    let mut options = parse_json_to_options(env, &options)?;
+
+    let compat_level = parse_ipc_compat_level(&mut options).unwrap_or(CompatLevel::oldest());
+
+    let overwrite_mode = parse_overwrite_mode(&mut options);
+
+    let compression = options.remove("write_compression");
+    let compression_level = opt_parse::<i32>(&mut options, "write_compression_level");
+    let compression = parse_ipc_compression(compression, compression_level);
+
+    let sink = if file_path.is_null() {
+        StreamSink::Memory(Vec::new())
+    } else {
+        StreamSink::File(open_file_writer(env, &file_path, overwrite_mode, options)?)
+    };
+
+    let writer = StreamWriter::new(
+        sink,
+        WriteOptions {
+            compression: compression.map(|c| c.into()),
+        },
+    );
+
+    Ok(IpcStreamWriterHandle::alloc(IpcStreamBatchWriter {
+        writer: Some(writer),
+        schema: None,
+        compat_level,
+    }))
+}
+
+const WRITE_METHOD: NativeMethod = stream_method! {
+    extern fn write(writer: IpcStreamWriterHandle, df: DataFrameHandle),
+};
+
+fn write<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    writer: IpcStreamWriterHandle,
+    df: DataFrameHandle,
+) -> anyhow::Result<()> {
+    let writer = unsafe { writer.as_mut() };
+    writer.write_batch(df.get())
+}
+
+const FINISH_METHOD: NativeMethod = stream_method! {
+    extern fn finish(writer: IpcStreamWriterHandle) -> [jbyte],
+};
+
+/// Ends the stream and returns the written bytes, or `null` if it was written to a path.
+fn finish<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    writer: IpcStreamWriterHandle,
+) -> anyhow::Result<JByteArray<'local>> {
+    let writer = unsafe { writer.as_mut() };
+    let bytes = writer.finish()?;
+    written_bytes_to_java(env, bytes)
+}
+
+decl_free!(
+    FREE_METHOD,
+    "com.github.chitralverma.polars.internal.jni.io.ipc_stream_writer$",
+    IpcStreamWriterHandle
+);
+
+pub const METHODS: &[NativeMethod] = &[OPEN_METHOD, WRITE_METHOD, FINISH_METHOD, FREE_METHOD];
@@ -1,10 +1,13 @@
-use jni::objects::{JObject, JString};
+use jni::objects::{JByteArray, JObject, JString};
 use jni::{Env, NativeMethod, native_method};
 use polars::prelude::*;
 
 use crate::internal_jni::handle::{DataFrameHandle, Handle};
-use crate::internal_jni::io::parse_json_to_options;
-use crate::internal_jni::io::write::{parse_overwrite_mode, write_dataframe};
+use crate::internal_jni::io::write::{
+    parse_external_compression, parse_overwrite_mode, write_compressed, write_dataframe,
+    written_bytes_to_java,
+};
+use crate::internal_jni::io::{opt_parse, parse_json_to_options};
 use crate::utils::error::ThrowRuntimeException;
 
 /// Injects the shared `io.write$` config into [`native_method!`].
@@ -20,7 +23,7 @@ macro_rules! write_method {
 }
 
 const WRITE_JSON_METHOD: NativeMethod = write_method!(
-    extern fn write_json(df: DataFrameHandle, file_path: java.lang.String, options: java.lang.String),
+    extern fn write_json(df: DataFrameHandle, file_path: java.lang.String, options: java.lang.String) -> [jbyte],
     name = "writeJson",
 );
 
@@ -30,7 +33,7 @@ fn write_json<'local>(
     df: DataFrameHandle,
     file_path: JString<'local>,
     options: JString<'local>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<JByteArray<'local>> {
     let mut options = parse_json_to_options(env, &options)?;
 
     let json_format = options
@@ -42,9 +45,13 @@ fn write_json<'local>(
         })
         .unwrap_or(JsonFormat::Json);
 
+    let compression = options.remove("write_compression");
+    let compression_level = opt_parse::<i32>(&mut options, "write_compression_level");
+    let compression = parse_external_compression(compression, compression_level)?;
+
     let overwrite_mode = parse_overwrite_mode(&mut options);
 
-    write_dataframe(
+    let bytes = write_dataframe(
         env,
         df.get(),
         &file_path,
@@ -52,13 +59,15 @@ fn write_json<'local>(
         options,
         "JSON",
         |writer, dataframe| {
-            JsonWriter::new(writer)
-                .with_json_format(json_format)
-                .finish(dataframe)
+            write_compressed(writer, compression.as_ref(), |writer| {
+                JsonWriter::new(writer)
+                    .with_json_format(json_format)
+                    .finish(dataframe)
+            })
         },
     )?;
 
-    Ok(())
+    written_bytes_to_java(env, bytes)
 }
 
 pub const METHODS: &[NativeMethod] = &[WRITE_JSON_METHOD];
@@ -1,14 +1,16 @@
 pub mod avro;
 pub mod csv;
 pub mod ipc;
+pub mod ipc_stream;
 pub mod json;
 pub mod parquet;
 
+use std::io::Write;
 use std::sync::Arc;
 
-use anyhow::Context;
+use anyhow::{Context, bail, ensure};
 use jni::Env;
-use jni::objects::JString;
+use jni::objects::{JByteArray, JString};
 use object_store::path::Path;
 use object_store::{ObjectStore, ObjectStoreExt};
 use polars::io::cloud::cloud_writer::{CloudWriter, CloudWriterIoTraitWrap};
@@ -17,6 +19,7 @@ use polars::io::configs::{upload_chunk_size, upload_concurrency};
 use polars::io::utils::file::WriteableTrait;
 use polars::prelude::*;
 use polars_core::runtime::ASYNC;
+use polars_utils::compression::{GzipLevel, ZstdLevel};
 
 use super::{get_file_path, parse_cloud_options};
 
@@ -76,29 +79,137 @@ async fn create_cloud_writer(
     Ok(CloudWriterIoTraitWrap::from(cloud_writer))
 }
 
-/// Writes a DataFrame to `file_path` via `write` (the format-specific writer), then commits the
-/// upload by closing the cloud writer.
-pub(crate) fn write_dataframe<F>(
+/// Compression applied to the whole output of formats without internal compression (CSV and
+/// JSON), so that e.g. `.csv.gz` or `.ndjson.zst` files can be written.
+pub(crate) enum ExternalCompression {
+    Gzip(Option<GzipLevel>),
+    Zstd(Option<ZstdLevel>),
+}
+
+/// Parses the shared `write_compression` and `write_compression_level` values for
+/// [`write_compressed`]. Unknown codecs and levels outside the range of the codec are an error.
+pub(crate) fn parse_external_compression(
+    compression: Option<String>,
+    compression_level: Option<i32>,
+) -> anyhow::Result<Option<ExternalCompression>> {
+    let Some(compression) = compression else {
+        ensure!(
+            compression_level.is_none(),
+            "`write_compression_level` requires `write_compression` to be set"
+        );
+        return Ok(None);
+    };
+
+    let compression = match compression.to_lowercase().as_str() {
+        "uncompressed" => {
+            ensure!(
+                compression_level.is_none(),
+                "`write_compression_level` cannot be set for uncompressed output"
+            );
+            None
+        },
+        "gzip" => {
+            let level = compression_level
+                .map(|v| {
+                    u8::try_from(v)
+                        .ok()
+                        .and_then(|v| GzipLevel::try_new(v).ok())
+                        .context(format!(
+                            "Invalid gzip compression level {v}, expected 0 to 9"
+                        ))
+                })
+                .transpose()?;
+            Some(ExternalCompression::Gzip(level))
+        },
+        "zstd" => {
+            let level = compression_level
+                .map(|v| {
+                    ZstdLevel::try_new(v).ok().context(format!(
+                        "Invalid zstd compression level {v}, expected 1 to 22"
+                    ))
+                })
+                .transpose()?;
+            Some(ExternalCompression::Zstd(level))
+        },
+        other => {
+            bail!("Unknown compression `{other}`, expected one of `uncompressed`, `gzip` or `zstd`")
+        },
+    };
+    Ok(compression)
+}
+
+/// Runs `write` against `writer`, compressing everything it writes with `compression` if set.
+pub(crate) fn write_compressed<F>(
+    writer: &mut dyn Write,
+    compression: Option<&ExternalCompression>,
+    write: F,
+) -> PolarsResult<()>
+where
+    F: FnOnce(&mut dyn Write) -> PolarsResult<()>,
+{
+    match compression {
+        None => write(writer),
+        Some(ExternalCompression::Gzip(level)) => {
+            let level = level.map_or(flate2::Compression::default(), |l| {
+                flate2::Compression::new(l.compression_level().into())
+            });
+            let mut encoder = flate2::write::GzEncoder::new(writer, level);
+            write(&mut encoder)?;
+            encoder.finish()?;
+            Ok(())
+        },
+        Some(ExternalCompression::Zstd(level)) => {
+            let level = level.map_or(zstd::DEFAULT_COMPRESSION_LEVEL, |l| l.compression_level());
+            let mut encoder = zstd::stream::write::Encoder::new(writer, level)?;
+            write(&mut encoder)?;
+            encoder.finish()?;
+            Ok(())
+        },
+    }
+}
+
+/// Opens a writer to `file_path` (local or cloud), deriving cloud options from the remaining
+/// `options`. The written data is only committed once the writer is closed.
+pub(crate) fn open_file_writer(
     env: &mut Env,
-    mut dataframe: DataFrame,
     file_path: &JString,
     overwrite_mode: bool,
     options: PlHashMap<String, String>,
-    format: &str,
-    write: F,
-) -> anyhow::Result<()>
-where
-    F: FnOnce(&mut CloudWriterIoTraitWrap, &mut DataFrame) -> PolarsResult<()>,
-{
+) -> anyhow::Result<CloudWriterIoTraitWrap> {
     let full_path = get_file_path(env, file_path)?;
     let uri = PlRefPath::new(full_path);
 
     let cloud_options = parse_cloud_options(uri.scheme(), options)?;
-    let mut writer: CloudWriterIoTraitWrap = ASYNC
+    ASYNC
         .block_on(async {
             create_cloud_writer(uri.as_str(), cloud_options.as_ref(), overwrite_mode).await
         })
-        .context("Failed to create writer")?;
+        .context("Failed to create writer")
+}
+
+/// Writes a DataFrame via `write` (the format-specific writer) to `file_path`, then commits the
+/// upload by closing the cloud writer. If `file_path` is `null`, the DataFrame is instead written
+/// into memory and the encoded bytes are returned.
+pub(crate) fn write_dataframe<F>(
+    env: &mut Env,
+    mut dataframe: DataFrame,
+    file_path: &JString,
+    overwrite_mode: bool,
+    options: PlHashMap<String, String>,
+    format: &str,
+    write: F,
+) -> anyhow::Result<Option<Vec<u8>>>
+where
+    F: FnOnce(&mut dyn Write, &mut DataFrame) -> PolarsResult<()>,
+{
+    if file_path.is_null() {
+        let mut buffer = Vec::new();
+        write(&mut buffer, &mut dataframe)
+            .with_context(|| format!("Failed to write {format} data"))?;
+        return Ok(Some(buffer));
+    }
+
+    let mut writer = open_file_writer(env, file_path, overwrite_mode, options)?;
 
     write(&mut writer, &mut dataframe).with_context(|| format!("Failed to write {format} data"))?;
 
@@ -106,5 +217,19 @@ where
         .close()
         .with_context(|| format!("Failed to finalize {format} data"))?;
 
-    Ok(())
+    Ok(None)
+}
+
+/// Hands the bytes returned by [`write_dataframe`] to the JVM, as `null` if the DataFrame was
+/// written to a path.
+pub(crate) fn written_bytes_to_java<'local>(
+    env: &mut Env<'local>,
+    bytes: Option<Vec<u8>>,
+) -> anyhow::Result<JByteArray<'local>> {
+    match bytes {
+        Some(bytes) => env
+            .byte_array_from_slice(&bytes)
+            .context("Failed to copy the written bytes to a byte array"),
+        None => Ok(unsafe { JByteArray::from_raw(env, std::ptr::null_mut()) }),
+    }
 }
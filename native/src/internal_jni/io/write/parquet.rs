@@ -1,11 +1,34 @@
-use jni::objects::{JObject, JString};
+use std::cell::Cell;
+use std::io::Write;
+
+use anyhow::{Context, bail, ensure};
+use jni::objects::{JByteArray, JObject, JString};
 use jni::{Env, NativeMethod, native_method};
 use num_traits::ToPrimitive;
 use polars::prelude::*;
+use polars_arrow::array::{Array, ArrayRef, FixedSizeBinaryArray, PrimitiveArray};
+use polars_arrow::datatypes::{ArrowDataType, ArrowSchema};
+use polars_core::frame::chunk_df_for_writing;
+use polars_core::runtime::RAYON;
+use polars_core::utils::rayon::prelude::*;
+use polars_parquet::parquet::error::ParquetError;
+use polars_parquet::parquet::schema::Repetition;
+use polars_parquet::parquet::schema::types::{FieldInfo, GroupLogicalType, PrimitiveType};
+use polars_parquet::parquet::{FOOTER_SIZE, HEADER_SIZE};
+use polars_parquet::write::{
+    CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding, FallibleStreamingIterator,
+    FileWriter, KeyValue, Page, ParquetPhysicalType, ParquetType, RowGroupIterColumns,
+    SchemaDescriptor, ThriftFileMetadata, Version, WriteOptions, get_dtype_encoding,
+    schema_to_metadata_key, to_parquet_type, write_metadata_sidecar,
+};
+use polars_parquet_format::SortingColumn;
 use polars_utils::compression::{BrotliLevel, GzipLevel, ZstdLevel};
+use serde_json::Value;
 
 use crate::internal_jni::handle::{DataFrameHandle, Handle};
-use crate::internal_jni::io::write::{parse_overwrite_mode, write_dataframe};
+use crate::internal_jni::io::write::{
+    parse_overwrite_mode, write_dataframe, written_bytes_to_java,
+};
 use crate::internal_jni::io::{opt_parse, parse_json_to_options};
 use crate::utils::error::ThrowRuntimeException;
 
@@ -21,40 +44,742 @@ macro_rules! write_method {
     };
 }
 
+/// Rows per row group when `write_parquet_row_group_size` is not set, as in polars.
+const DEFAULT_ROW_GROUP_SIZE: usize = 512 * 512;
+
+/// The Julian day number of the Unix epoch, the day offset of INT96 timestamps.
+const JULIAN_DAY_OF_EPOCH: i64 = 2_440_588;
+
+const NANOSECONDS_PER_DAY: i64 = 86_400 * 1_000_000_000;
+
+/// Parses a codec name and level. Unknown codecs and levels outside the range of the codec are
+/// an error.
+fn parquet_compression(
+    compression: &str,
+    level: Option<i32>,
+) -> anyhow::Result<ParquetCompression> {
+    let invalid_level = |v: i32, range: &str| {
+        format!("Invalid {compression} compression level {v}, expected {range}")
+    };
+
+    let compression = match compression.to_lowercase().as_str() {
+        "gzip" => ParquetCompression::Gzip(
+            level
+                .map(|v| {
+                    v.to_u8()
+                        .and_then(|l| GzipLevel::try_new(l).ok())
+                        .with_context(|| invalid_level(v, "0 to 9"))
+                })
+                .transpose()?,
+        ),
+        "brotli" => ParquetCompression::Brotli(
+            level
+                .map(|v| {
+                    v.to_u32()
+                        .and_then(|l| BrotliLevel::try_new(l).ok())
+                        .with_context(|| invalid_level(v, "0 to 11"))
+                })
+                .transpose()?,
+        ),
+        "zstd" => ParquetCompression::Zstd(
+            level
+                .map(|v| {
+                    ZstdLevel::try_new(v)
+                        .ok()
+                        .with_context(|| invalid_level(v, "1 to 22"))
+                })
+                .transpose()?,
+        ),
+        codec => {
+            let compression = match codec {
+                "uncompressed" => ParquetCompression::Uncompressed,
+                "snappy" => ParquetCompression::Snappy,
+                "lz4" => ParquetCompression::Lz4Raw,
+                _ => bail!(
+                    "Compression must be one of {{'uncompressed', 'snappy', 'gzip', 'brotli', 'lz4', 'zstd'}}, got {compression}"
+                ),
+            };
+            ensure!(
+                level.is_none(),
+                "A compression level cannot be set for {codec} compression"
+            );
+            compression
+        },
+    };
+    Ok(compression)
+}
+
+/// Parses the file-level `write_compression` and `write_compression_level`, see
+/// [`parquet_compression`].
 fn parse_parquet_compression(
     compression: Option<String>,
     compression_level: Option<i32>,
-) -> Option<ParquetCompression> {
-    match (compression, compression_level) {
-        (Some(t), l) => match t.to_lowercase().as_str() {
-            "uncompressed" => Some(ParquetCompression::Uncompressed),
-            "snappy" => Some(ParquetCompression::Snappy),
-            "gzip" => {
-                let level = l.and_then(|v| GzipLevel::try_new(v.to_u8()?).ok());
-                Some(ParquetCompression::Gzip(level))
-            },
-            "brotli" => {
-                let level = l.and_then(|v| BrotliLevel::try_new(v.to_u32()?).ok());
-                Some(ParquetCompression::Brotli(level))
-            },
-            "zstd" => {
-                let level = l.and_then(|v| ZstdLevel::try_new(v).ok());
-                Some(ParquetCompression::Zstd(level))
+) -> anyhow::Result<Option<ParquetCompression>> {
+    let Some(compression) = compression else {
+        ensure!(
+            compression_level.is_none(),
+            "`write_compression_level` requires `write_compression` to be set"
+        );
+        return Ok(None);
+    };
+    parquet_compression(&compression, compression_level).map(Some)
+}
+
+/// Parses `write_parquet_metadata`, a JSON object of string keys and values written as custom
+/// file-level key-value metadata.
+fn parse_key_value_metadata(value: &str) -> anyhow::Result<Vec<KeyValue>> {
+    let metadata: PlHashMap<String, String> = serde_json::from_str(value)
+        .context("Expected `write_parquet_metadata` to be a JSON object of string values")?;
+    let mut metadata: Vec<(String, String)> = metadata.into_iter().collect();
+    metadata.sort();
+    Ok(metadata
+        .into_iter()
+        .map(|(key, value)| KeyValue {
+            key,
+            value: Some(value),
+        })
+        .collect())
+}
+
+/// A column declared in `write_parquet_sorting_columns`.
+struct SortedColumn {
+    name: String,
+    descending: bool,
+    nulls_first: bool,
+}
+
+/// Parses `write_parquet_sorting_columns`, a JSON array of column names sorted ascending with
+/// nulls last, or objects such as `{"column": "a", "descending": true, "nulls_first": true}`.
+fn parse_sorting_columns(value: &str) -> anyhow::Result<Vec<SortedColumn>> {
+    let columns: Vec<Value> = serde_json::from_str(value)
+        .context("Expected `write_parquet_sorting_columns` to be a JSON array of columns")?;
+
+    columns
+        .into_iter()
+        .map(|column| match column {
+            Value::String(name) => Ok(SortedColumn {
+                name,
+                descending: false,
+                nulls_first: false,
+            }),
+            Value::Object(settings) => {
+                let flag = |key: &str| {
+                    settings
+                        .get(key)
+                        .map(|v| {
+                            v.as_bool()
+                                .context(format!("Expected `{key}` to be a boolean"))
+                        })
+                        .transpose()
+                        .map(|v| v.unwrap_or(false))
+                };
+                Ok(SortedColumn {
+                    name: settings
+                        .get("column")
+                        .and_then(Value::as_str)
+                        .context("Expected a sorting column to name its `column`")?
+                        .to_string(),
+                    descending: flag("descending")?,
+                    nulls_first: flag("nulls_first")?,
+                })
             },
-            "lz4" => Some(ParquetCompression::Lz4Raw),
-            e => {
-                polars_warn!(
-                    "Compression must be one of {{'uncompressed', 'snappy', 'gzip', 'brotli', 'lz4', 'zstd'}}, got {e}. Using defaults."
+            other => bail!("Expected a sorting column name or object, got {other}"),
+        })
+        .collect()
+}
+
+/// Per-column settings from `write_parquet_field_overwrites`.
+struct FieldOverwrite {
+    encoding: Option<Encoding>,
+    compression: Option<ParquetCompression>,
+}
+
+fn parse_encoding(encoding: &str) -> anyhow::Result<Encoding> {
+    match encoding.to_lowercase().as_str() {
+        "plain" => Ok(Encoding::Plain),
+        "rle" => Ok(Encoding::Rle),
+        "delta_binary_packed" => Ok(Encoding::DeltaBinaryPacked),
+        "delta_length_byte_array" => Ok(Encoding::DeltaLengthByteArray),
+        e => bail!(
+            "Encoding must be one of {{'plain', 'rle', 'delta_binary_packed', 'delta_length_byte_array'}}, got {e}"
+        ),
+    }
+}
+
+fn parse_field_overwrite(value: &Value) -> anyhow::Result<FieldOverwrite> {
+    let settings = value.as_object().context("Expected a JSON object")?;
+    if let Some(key) = settings.keys().find(|k| {
+        !["encoding", "dictionary", "compression", "compression_level"].contains(&k.as_str())
+    }) {
+        bail!("Unknown setting `{key}`");
+    }
+
+    let string_setting = |key: &str| {
+        settings
+            .get(key)
+            .map(|v| {
+                v.as_str()
+                    .context(format!("Expected `{key}` to be a string"))
+            })
+            .transpose()
+    };
+
+    let encoding = string_setting("encoding")?
+        .map(parse_encoding)
+        .transpose()?;
+    let dictionary = settings
+        .get("dictionary")
+        .map(|v| v.as_bool().context("Expected `dictionary` to be a boolean"))
+        .transpose()?;
+    let encoding = match (dictionary, encoding) {
+        (Some(true), Some(_)) => bail!("`encoding` cannot be combined with `\"dictionary\": true`"),
+        (Some(true), None) => Some(Encoding::RleDictionary),
+        (Some(false), None) => Some(Encoding::Plain),
+        (_, encoding) => encoding,
+    };
+
+    let level = settings
+        .get("compression_level")
+        .map(|v| {
+            v.as_i64()
+                .and_then(|l| i32::try_from(l).ok())
+                .context("Expected `compression_level` to be an integer")
+        })
+        .transpose()?;
+    let compression = string_setting("compression")?
+        .map(|c| parquet_compression(c, level))
+        .transpose()?;
+    ensure!(
+        level.is_none() || compression.is_some(),
+        "`compression_level` requires `compression`"
+    );
+
+    Ok(FieldOverwrite {
+        encoding,
+        compression,
+    })
+}
+
+/// Parses `write_parquet_field_overwrites`, a JSON object mapping column names to their
+/// `encoding`, `dictionary`, `compression` and `compression_level`.
+fn parse_field_overwrites(value: &str) -> anyhow::Result<PlHashMap<String, FieldOverwrite>> {
+    let overwrites: PlHashMap<String, Value> = serde_json::from_str(value).context(
+        "Expected `write_parquet_field_overwrites` to be a JSON object of column settings",
+    )?;
+
+    overwrites
+        .into_iter()
+        .map(|(name, settings)| {
+            let overwrite = parse_field_overwrite(&settings).with_context(|| {
+                format!("Invalid `write_parquet_field_overwrites` for column `{name}`")
+            })?;
+            Ok((name, overwrite))
+        })
+        .collect()
+}
+
+/// Fails unless the writer can encode a column of `dtype` with `encoding`.
+fn check_encoding(dtype: &DataType, encoding: Encoding) -> anyhow::Result<()> {
+    let supported = match encoding {
+        Encoding::Plain | Encoding::RleDictionary => true,
+        Encoding::Rle => dtype.is_bool(),
+        Encoding::DeltaBinaryPacked => {
+            dtype.to_physical().is_integer()
+                && !matches!(dtype, DataType::Int128 | DataType::UInt128)
+        },
+        Encoding::DeltaLengthByteArray => matches!(dtype, DataType::String | DataType::Binary),
+        _ => false,
+    };
+    ensure!(
+        supported,
+        "{encoding:?} encoding is not supported for {dtype} columns"
+    );
+    Ok(())
+}
+
+/// Maps `dtype` to one that Spark and Hive readers accept, which only know microsecond
+/// timestamps.
+fn spark_compatible_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::Datetime(TimeUnit::Nanoseconds, tz) => {
+            DataType::Datetime(TimeUnit::Microseconds, tz.clone())
+        },
+        DataType::List(inner) => DataType::List(Box::new(spark_compatible_dtype(inner))),
+        DataType::Array(inner, width) => {
+            DataType::Array(Box::new(spark_compatible_dtype(inner)), *width)
+        },
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| Field::new(f.name().clone(), spark_compatible_dtype(f.dtype())))
+                .collect(),
+        ),
+        dtype => dtype.clone(),
+    }
+}
+
+/// Casts the columns of `df` whose types Spark and Hive readers reject, see
+/// [`spark_compatible_dtype`], except the `int96_columns` that keep nanoseconds as INT96. Warns
+/// about the columns whose nanosecond timestamps are truncated.
+fn to_spark_compatible(df: DataFrame, int96_columns: &[String]) -> anyhow::Result<DataFrame> {
+    let mut truncated = Vec::new();
+    let columns = df
+        .columns()
+        .iter()
+        .map(|c| {
+            let dtype = spark_compatible_dtype(c.dtype());
+            if &dtype == c.dtype() || int96_columns.iter().any(|name| name == c.name().as_str()) {
+                Ok(c.clone())
+            } else {
+                truncated.push(c.name().to_string());
+                c.cast(&dtype)
+                    .with_context(|| format!("Failed to cast column `{}` for Spark", c.name()))
+            }
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    if !truncated.is_empty() {
+        polars_warn!(
+            "Spark compatibility truncates nanosecond timestamps to microseconds in columns {truncated:?}. List top-level columns in `write_parquet_int96_columns` to keep nanoseconds."
+        );
+    }
+
+    DataFrame::new(df.height(), columns).context("Failed to rebuild DataFrame for Spark")
+}
+
+/// Casts the `int96_columns` of `df` to nanosecond datetimes, the precision of INT96 values.
+fn to_int96_compatible(df: DataFrame, int96_columns: &[String]) -> anyhow::Result<DataFrame> {
+    let mut columns = df.columns().to_vec();
+    for name in int96_columns {
+        let column = columns
+            .iter_mut()
+            .find(|c| c.name().as_str() == name)
+            .with_context(|| {
+                format!("Column `{name}` in `write_parquet_int96_columns` not found")
+            })?;
+        let DataType::Datetime(_, tz) = column.dtype() else {
+            bail!(
+                "Column `{name}` in `write_parquet_int96_columns` must be a datetime, got {}",
+                column.dtype()
+            );
+        };
+        *column = column
+            .strict_cast(&DataType::Datetime(TimeUnit::Nanoseconds, tz.clone()))
+            .with_context(|| format!("Failed to cast column `{name}` to nanoseconds for INT96"))?;
+    }
+
+    DataFrame::new(df.height(), columns).context("Failed to rebuild DataFrame for INT96")
+}
+
+/// Encodes nanosecond timestamps as the 12-byte INT96 values Spark and Hive read: the
+/// nanoseconds within the day followed by the Julian day number, both little-endian.
+fn timestamps_to_int96(array: &dyn Array) -> ArrayRef {
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()
+        .expect("INT96 columns are cast to nanosecond datetimes");
+
+    let mut values = Vec::with_capacity(12 * array.len());
+    for ns in array.values().iter() {
+        let day = ns.div_euclid(NANOSECONDS_PER_DAY) + JULIAN_DAY_OF_EPOCH;
+        values.extend_from_slice(&ns.rem_euclid(NANOSECONDS_PER_DAY).to_le_bytes());
+        values.extend_from_slice(&(day as i32).to_le_bytes());
+    }
+
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(12),
+        values.into(),
+        array.validity().cloned(),
+    )
+    .boxed()
+}
+
+fn rename(type_: &mut ParquetType, name: &'static str) {
+    let field_info = match type_ {
+        ParquetType::PrimitiveType(primitive) => &mut primitive.field_info,
+        ParquetType::GroupType { field_info, .. } => field_info,
+    };
+    field_info.name = PlSmallStr::from_static(name);
+}
+
+/// Renames the repeated group and element of every list in `type_` to `bag` and `array`, the
+/// layout Spark writes with `spark.sql.parquet.writeLegacyFormat` for Hive and Impala.
+fn to_legacy_list_layout(type_: &mut ParquetType) {
+    let ParquetType::GroupType {
+        logical_type,
+        fields,
+        ..
+    } = type_
+    else {
+        return;
+    };
+
+    fields.iter_mut().for_each(to_legacy_list_layout);
+
+    if matches!(logical_type, Some(GroupLogicalType::List))
+        && let Some(repeated) = fields.first_mut()
+    {
+        rename(repeated, "bag");
+        if let ParquetType::GroupType { fields, .. } = repeated {
+            fields
+                .iter_mut()
+                .for_each(|element| rename(element, "array"));
+        }
+    }
+}
+
+/// Parquet write options, including those the polars `ParquetWriter` has no counterpart for.
+struct ParquetOptions {
+    compression: ParquetCompression,
+    statistics: StatisticsOptions,
+    row_group_size: Option<usize>,
+    data_page_size: Option<usize>,
+    parallel: bool,
+    key_value_metadata: Vec<KeyValue>,
+    sorting_columns: Vec<SortedColumn>,
+    field_overwrites: PlHashMap<String, FieldOverwrite>,
+    int96_columns: Vec<String>,
+    legacy_list_layout: bool,
+}
+
+impl ParquetOptions {
+    /// Whether an option is set that the polars `ParquetWriter` has no counterpart for.
+    fn needs_layout(&self) -> bool {
+        !self.sorting_columns.is_empty()
+            || !self.field_overwrites.is_empty()
+            || !self.int96_columns.is_empty()
+            || self.legacy_list_layout
+    }
+
+    /// Writes `df` with the polars `ParquetWriter`.
+    fn write(self, writer: &mut dyn Write, df: &mut DataFrame) -> PolarsResult<()> {
+        let key_value_metadata = (!self.key_value_metadata.is_empty())
+            .then(|| KeyValueMetadata::Static(self.key_value_metadata));
+
+        ParquetWriter::new(writer)
+            .with_compression(self.compression)
+            .with_statistics(self.statistics)
+            .with_row_group_size(self.row_group_size)
+            .with_data_page_size(self.data_page_size)
+            .set_parallel(self.parallel)
+            .with_key_value_metadata(key_value_metadata)
+            .finish(df)
+            .map(|_| ())
+    }
+}
+
+/// How a DataFrame is written to Parquet.
+enum ParquetWrite {
+    /// With the polars `ParquetWriter`, for files it can write on its own.
+    Stock(ParquetOptions),
+    /// With a custom layout, for the options in [`ParquetOptions::needs_layout`].
+    Layout(ParquetLayout),
+}
+
+/// How one top-level column is encoded.
+struct ColumnLayout {
+    type_: ParquetType,
+    /// The encoding of each of the column's leaves.
+    encodings: Vec<Encoding>,
+    options: WriteOptions,
+    int96: bool,
+}
+
+/// The layout of a Parquet file, resolved against the schema of the written DataFrame before
+/// any data is encoded so that invalid options fail without writing.
+struct ParquetLayout {
+    arrow_schema: ArrowSchema,
+    columns: Vec<ColumnLayout>,
+    file_options: WriteOptions,
+    sorting_columns: Vec<SortingColumn>,
+    key_value_metadata: Vec<KeyValue>,
+    row_group_size: usize,
+    parallel: bool,
+}
+
+impl ParquetLayout {
+    fn new(df: &DataFrame, mut options: ParquetOptions) -> anyhow::Result<Self> {
+        let arrow_schema = df.schema().to_arrow(CompatLevel::newest());
+
+        // The writer cannot build INT96 statistics, so those columns have none and the file has
+        // no column index, which requires statistics for every page.
+        let file_statistics = if options.int96_columns.is_empty() {
+            options.statistics
+        } else {
+            StatisticsOptions::empty()
+        };
+        let file_options = WriteOptions {
+            statistics: file_statistics,
+            version: Version::V1,
+            compression: options.compression.into(),
+            data_page_size: options.data_page_size,
+        };
+
+        let columns = df
+            .columns()
+            .iter()
+            .zip(arrow_schema.iter_values())
+            .map(|(column, field)| {
+                let name = column.name().as_str();
+                let int96 = options.int96_columns.iter().any(|c| c == name);
+                let overwrite = options.field_overwrites.remove(name);
+
+                let mut type_ = if int96 {
+                    ParquetType::PrimitiveType(PrimitiveType {
+                        field_info: FieldInfo {
+                            name: field.name.clone(),
+                            repetition: Repetition::Optional,
+                            id: None,
+                        },
+                        logical_type: None,
+                        converted_type: None,
+                        physical_type: ParquetPhysicalType::Int96,
+                    })
+                } else {
+                    to_parquet_type(field)?
+                };
+                if options.legacy_list_layout {
+                    to_legacy_list_layout(&mut type_);
+                }
+
+                let mut encodings = if int96 {
+                    vec![Encoding::Plain]
+                } else {
+                    get_dtype_encoding(&field.dtype)
+                };
+                let mut column_options = WriteOptions {
+                    statistics: if int96 {
+                        StatisticsOptions::empty()
+                    } else {
+                        options.statistics
+                    },
+                    ..file_options
+                };
+
+                if let Some(overwrite) = overwrite {
+                    if let Some(encoding) = overwrite.encoding {
+                        ensure!(
+                            !column.dtype().is_nested() && !int96,
+                            "Encodings can only be set for non-nested, non-INT96 columns, got `{name}`"
+                        );
+                        check_encoding(column.dtype(), encoding)
+                            .with_context(|| format!("Invalid encoding for column `{name}`"))?;
+                        encodings = vec![encoding];
+                    }
+                    if let Some(compression) = overwrite.compression {
+                        column_options.compression = compression.into();
+                    }
+                }
+
+                Ok(ColumnLayout {
+                    type_,
+                    encodings,
+                    options: column_options,
+                    int96,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        if let Some(name) = options.field_overwrites.keys().next() {
+            bail!("Column `{name}` in `write_parquet_field_overwrites` not found");
+        }
+
+        let sorting_columns = options
+            .sorting_columns
+            .iter()
+            .map(|sorted| {
+                let index = df
+                    .columns()
+                    .iter()
+                    .position(|c| c.name().as_str() == sorted.name)
+                    .with_context(|| {
+                        format!(
+                            "Column `{}` in `write_parquet_sorting_columns` not found",
+                            sorted.name
+                        )
+                    })?;
+                ensure!(
+                    !df.columns()[index].dtype().is_nested(),
+                    "Sorting column `{}` must not be nested",
+                    sorted.name
                 );
-                None
+                // Sorting columns refer to leaves, of which nested columns may have several.
+                let leaf: usize = columns[..index].iter().map(|c| c.encodings.len()).sum();
+                Ok(SortingColumn::new(
+                    i32::try_from(leaf)?,
+                    sorted.descending,
+                    sorted.nulls_first,
+                ))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut key_value_metadata = options.key_value_metadata;
+        let arrow_schema_key = schema_to_metadata_key(&arrow_schema);
+        if !key_value_metadata
+            .iter()
+            .any(|kv| kv.key == arrow_schema_key.key)
+        {
+            key_value_metadata.insert(0, arrow_schema_key);
+        }
+
+        Ok(Self {
+            arrow_schema,
+            columns,
+            file_options,
+            sorting_columns,
+            key_value_metadata,
+            row_group_size: options.row_group_size.unwrap_or(DEFAULT_ROW_GROUP_SIZE),
+            parallel: options.parallel,
+        })
+    }
+
+    /// Encodes and compresses one chunk of `arrays` per column with the column's options.
+    fn encode_row_group(
+        &self,
+        arrays: &[ArrayRef],
+    ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
+        let encode = |(array, column): (&ArrayRef, &ColumnLayout)| {
+            let array = if column.int96 {
+                timestamps_to_int96(array.as_ref())
+            } else {
+                array.clone()
+            };
+            let options = column.options;
+            polars_parquet::write::array_to_columns(
+                array,
+                column.type_.clone(),
+                options,
+                &column.encodings,
+            )
+            .map(|leaves| {
+                leaves
+                    .into_iter()
+                    .map(|pages| compress_pages(pages, options))
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        let columns = if self.parallel {
+            RAYON.install(|| {
+                arrays
+                    .par_iter()
+                    .zip(&self.columns)
+                    .map(encode)
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+        } else {
+            arrays
+                .iter()
+                .zip(&self.columns)
+                .map(encode)
+                .collect::<PolarsResult<Vec<_>>>()
+        }?;
+
+        Ok(DynIter::new(columns.into_iter().flatten()))
+    }
+
+    fn write(&self, writer: &mut dyn Write, df: &mut DataFrame) -> PolarsResult<()> {
+        let df = chunk_df_for_writing(df, self.row_group_size)?;
+        let parquet_schema = SchemaDescriptor::new(
+            PlSmallStr::from_static("root"),
+            self.columns.iter().map(|c| c.type_.clone()).collect(),
+        );
+
+        let capturing = Cell::new(false);
+        let mut file_writer = FileWriter::new_with_parquet_schema(
+            FooterCapture {
+                inner: writer,
+                capturing: &capturing,
+                tail: Vec::new(),
             },
-        },
-        _ => None,
+            self.arrow_schema.clone(),
+            parquet_schema,
+            self.file_options,
+        );
+
+        for batch in df.iter_chunks(CompatLevel::newest(), false) {
+            if !batch.is_empty() {
+                let row_group = self.encode_row_group(batch.columns())?;
+                file_writer.write(batch.len() as u64, row_group)?;
+            }
+        }
+
+        capturing.set(true);
+        file_writer.end(Some(self.key_value_metadata.clone()))?;
+        let (capture, metadata) = file_writer.into_inner_and_metadata();
+        capture.finish(metadata, &self.sorting_columns)
+    }
+}
+
+/// Wraps a page iterator of one leaf column in a compressor with the column's codec.
+fn compress_pages(
+    pages: DynIter<'static, PolarsResult<Page>>,
+    options: WriteOptions,
+) -> PolarsResult<DynStreamingIterator<'static, CompressedPage, PolarsError>> {
+    let pages =
+        pages.map(|page| page.map_err(|e| ParquetError::FeatureNotSupported(e.to_string())));
+    Ok(DynStreamingIterator::new(
+        Compressor::new_from_vec(pages, options.compression, vec![]).map_err(PolarsError::from),
+    ))
+}
+
+/// Forwards writes to `inner` until `capturing` is set, then holds them back so that the footer
+/// can be rewritten with the sorting columns the low-level writer leaves unset.
+struct FooterCapture<'a> {
+    inner: &'a mut dyn Write,
+    capturing: &'a Cell<bool>,
+    tail: Vec<u8>,
+}
+
+impl Write for FooterCapture<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.capturing.get() {
+            self.tail.extend_from_slice(buf);
+            Ok(buf.len())
+        } else {
+            self.inner.write(buf)
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if self.capturing.get() {
+            Ok(())
+        } else {
+            self.inner.flush()
+        }
+    }
+}
+
+impl FooterCapture<'_> {
+    /// Writes the held back page indexes and footer, the latter re-serialized from `metadata`
+    /// with `sorting_columns` declared on every row group.
+    fn finish(
+        mut self,
+        mut metadata: ThriftFileMetadata,
+        sorting_columns: &[SortingColumn],
+    ) -> PolarsResult<()> {
+        if !sorting_columns.is_empty() {
+            let footer_start = self.tail.len() - FOOTER_SIZE as usize;
+            let length = &self.tail[footer_start..footer_start + 4];
+            let metadata_len = i32::from_le_bytes(length.try_into().unwrap()) as usize;
+            self.tail.truncate(footer_start - metadata_len);
+
+            for row_group in &mut metadata.row_groups {
+                row_group.sorting_columns = Some(sorting_columns.to_vec());
+            }
+            let mut footer = Vec::new();
+            write_metadata_sidecar(&mut footer, &metadata)?;
+            self.tail.extend_from_slice(&footer[HEADER_SIZE as usize..]);
+        }
+
+        self.inner.write_all(&self.tail)?;
+        self.inner.flush()?;
+        Ok(())
     }
 }
 
 const WRITE_PARQUET_METHOD: NativeMethod = write_method!(
-    extern fn write_parquet(df: DataFrameHandle, file_path: java.lang.String, options: java.lang.String),
+    extern fn write_parquet(df: DataFrameHandle, file_path: java.lang.String, options: java.lang.String) -> [jbyte],
     name = "writeParquet",
 );
 
@@ -64,7 +789,7 @@ fn write_parquet<'local>(
     df: DataFrameHandle,
     file_path: JString<'local>,
     options: JString<'local>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<JByteArray<'local>> {
     let mut options = parse_json_to_options(env, &options)?;
 
     let is_parallel = opt_parse::<bool>(&mut options, "write_parquet_parallel");
@@ -77,6 +802,7 @@ fn write_parquet<'local>(
 
     let compression = options.remove("write_compression");
     let compression_level = opt_parse::<i32>(&mut options, "write_compression_level");
+    let compression = parse_parquet_compression(compression, compression_level)?;
 
     let write_stats = options
         .remove("write_parquet_stats")
@@ -86,37 +812,71 @@ fn write_parquet<'local>(
             _ => StatisticsOptions::default(),
         });
 
-    write_dataframe(
-        env,
-        df.get(),
-        &file_path,
-        overwrite_mode,
-        options,
-        "Parquet",
-        |writer, dataframe| {
-            let parquet_compression = parse_parquet_compression(compression, compression_level);
+    let key_value_metadata = options
+        .remove("write_parquet_metadata")
+        .map(|s| parse_key_value_metadata(&s))
+        .transpose()?;
 
-            let mut parquet_writer = ParquetWriter::new(writer)
-                .with_data_page_size(data_page_size)
-                .with_row_group_size(row_group_size);
+    let sorting_columns = options
+        .remove("write_parquet_sorting_columns")
+        .map(|s| parse_sorting_columns(&s))
+        .transpose()?;
 
-            if let Some(value) = is_parallel {
-                parquet_writer = parquet_writer.set_parallel(value)
-            }
+    let field_overwrites = options
+        .remove("write_parquet_field_overwrites")
+        .map(|s| parse_field_overwrites(&s))
+        .transpose()?;
 
-            if let Some(value) = write_stats {
-                parquet_writer = parquet_writer.with_statistics(value)
-            }
+    let int96_columns: Vec<String> = options
+        .remove("write_parquet_int96_columns")
+        .map(|s| s.split(',').map(|c| c.trim().to_string()).collect())
+        .unwrap_or_default();
 
-            if let Some(value) = parquet_compression {
-                parquet_writer = parquet_writer.with_compression(value)
-            }
+    let spark_compat =
+        opt_parse::<bool>(&mut options, "write_parquet_spark_compat").unwrap_or(false);
+
+    let mut df = df.get();
+    if !int96_columns.is_empty() {
+        df = to_int96_compatible(df, &int96_columns)?;
+    }
+    if spark_compat {
+        df = to_spark_compatible(df, &int96_columns)?;
+    }
 
-            parquet_writer.finish(dataframe).map(|_| ())
+    let parquet_options = ParquetOptions {
+        compression: compression.unwrap_or_default(),
+        statistics: write_stats.unwrap_or_default(),
+        row_group_size,
+        data_page_size,
+        parallel: is_parallel.unwrap_or(true),
+        key_value_metadata: key_value_metadata.unwrap_or_default(),
+        sorting_columns: sorting_columns.unwrap_or_default(),
+        field_overwrites: field_overwrites.unwrap_or_default(),
+        int96_columns,
+        legacy_list_layout: spark_compat,
+    };
+    let parquet_write = if parquet_options.needs_layout() {
+        ParquetWrite::Layout(
+            ParquetLayout::new(&df, parquet_options).context("Invalid Parquet write options")?,
+        )
+    } else {
+        ParquetWrite::Stock(parquet_options)
+    };
+
+    let bytes = write_dataframe(
+        env,
+        df,
+        &file_path,
+        overwrite_mode,
+        options,
+        "Parquet",
+        |writer, dataframe| match parquet_write {
+            ParquetWrite::Stock(options) => options.write(writer, dataframe),
+            ParquetWrite::Layout(layout) => layout.write(writer, dataframe),
         },
     )?;
 
-    Ok(())
+    written_bytes_to_java(env, bytes)
 }
 
 pub const METHODS: &[NativeMethod] = &[WRITE_PARQUET_METHOD];
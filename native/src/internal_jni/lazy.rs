@@ -1,15 +1,24 @@
-use anyhow::Context;
-use jni::objects::{JBooleanArray, JLongArray, JMap, JObject, JObjectArray, JString};
+use anyhow::{Context, bail, ensure};
+use jni::objects::{JBooleanArray, JByteArray, JLongArray, JMap, JObject, JObjectArray, JString};
 use jni::sys::{JNI_TRUE, jboolean, jint, jlong};
 use jni::{Env, NativeMethod, native_method};
 use polars::prelude::*;
+use polars_plan::dsl::{FileWriteFormat, SinkDestination, SinkTarget, UnifiedSinkArgs};
 use polars_plan::plans::AExprSorted;
+use polars_stream::{GraphNodeKey, NodeMetrics, StreamingQuery};
 
 use crate::internal_jni::conversion::JavaArrayToVec;
-use crate::internal_jni::handle::{DataFrameHandle, ExprHandle, Handle, LazyFrameHandle};
+use crate::internal_jni::dtype::{schema_from_json, schema_to_json};
+use crate::internal_jni::handle::{
+    DataFrameHandle, ExprHandle, Handle, LazyFrameHandle, QueryHandle,
+};
+use crate::internal_jni::io::{opt_parse, parse_cloud_options, parse_json_to_options};
 use crate::internal_jni::macros::decl_free;
+use crate::internal_jni::plan_tree::plan_to_json_tree;
+use crate::internal_jni::query::AsyncQuery;
+use crate::internal_jni::serialization;
 use crate::internal_jni::utils::{
-    j_object_ref_to_string, j_string_array_to_vec, j_string_to_string,
+    j_object_ref_to_string, j_string_array_to_vec, j_string_to_string, vec_to_j_long_array,
 };
 use crate::utils::error::ThrowRuntimeException;
 
@@ -23,6 +32,7 @@ macro_rules! ldf_method {
                 unsafe LazyFrameHandle => long,
                 unsafe DataFrameHandle => long,
                 unsafe ExprHandle => long,
+                unsafe QueryHandle => long,
             },
             $($tt)*
         }
@@ -46,6 +56,51 @@ fn schema_string<'local>(
     JString::from_str(env, schema_str).context("Failed to build schema string")
 }
 
+const SCHEMA_JSON_METHOD: NativeMethod =
+    ldf_method!(extern fn schema_json(ldf: LazyFrameHandle) -> JString, name = "schemaJson",);
+
+/// Describes the schema with polars' own data types, see [`crate::internal_jni::dtype`].
+fn schema_json<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ldf: LazyFrameHandle,
+) -> anyhow::Result<JString<'local>> {
+    let mut ldf = ldf.get();
+    let schema = ldf
+        .collect_schema()
+        .context("Failed to get schema of LazyFrame")?;
+    let schema_str = schema_to_json(&schema)?;
+    JString::from_str(env, schema_str).context("Failed to build schema string")
+}
+
+const CAST_TO_SCHEMA_METHOD: NativeMethod = ldf_method!(extern fn cast_to_schema(ldf: LazyFrameHandle, schema_json: java.lang.String, strict: jboolean) -> LazyFrameHandle, name = "castToSchema",);
+
+/// Casts the columns named in `schema_json` (as produced by `schemaJson`) to their data types,
+/// leaving other columns untouched.
+fn cast_to_schema<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ldf: LazyFrameHandle,
+    schema_json: JString<'local>,
+    strict: jboolean,
+) -> anyhow::Result<LazyFrameHandle> {
+    let schema_json = j_string_to_string(
+        env,
+        &schema_json,
+        Some("Failed to parse the provided schema as string"),
+    )?;
+    let schema = schema_from_json(&schema_json)?;
+
+    let dtypes: PlHashMap<&str, DataType> = schema
+        .iter()
+        .map(|(name, dtype)| (name.as_str(), dtype.clone()))
+        .collect();
+
+    Ok(LazyFrameHandle::alloc(
+        ldf.get().cast(dtypes, strict == JNI_TRUE),
+    ))
+}
+
 const SELECT_FROM_STRINGS_METHOD: NativeMethod = ldf_method!(extern fn select_from_strings(ldf: LazyFrameHandle, expr_strs: [java.lang.String]) -> LazyFrameHandle, name = "selectFromStrings",);
 
 fn select_from_strings<'local>(
@@ -369,6 +424,143 @@ fn explain<'local>(
     JString::from_str(env, plan_str).context("Failed to build plan string")
 }
 
+const TO_DOT_METHOD: NativeMethod = ldf_method!(extern fn to_dot(ldf: LazyFrameHandle, optimized: jboolean) -> JString, name = "toDot",);
+
+/// Renders the unoptimized or optimized plan as a Graphviz DOT graph.
+fn to_dot<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ldf: LazyFrameHandle,
+    optimized: jboolean,
+) -> anyhow::Result<JString<'local>> {
+    let dot_str = ldf
+        .get()
+        .to_dot(optimized == JNI_TRUE)
+        .context("Failed to render plan as DOT graph")?;
+
+    JString::from_str(env, dot_str).context("Failed to build plan string")
+}
+
+const PLAN_TREE_METHOD: NativeMethod = ldf_method!(extern fn plan_tree(ldf: LazyFrameHandle, optimized: jboolean) -> JString, name = "planTree",);
+
+/// Returns the unoptimized or optimized plan as a JSON tree, see [`plan_to_json_tree`].
+fn plan_tree<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ldf: LazyFrameHandle,
+    optimized: jboolean,
+) -> anyhow::Result<JString<'local>> {
+    let ldf = ldf.get();
+    let plan = if optimized == JNI_TRUE {
+        ldf.to_alp_optimized()
+    } else {
+        ldf.to_alp()
+    }
+    .context("Failed to resolve plan")?;
+
+    let tree_str = serde_json::to_string(&plan_to_json_tree(&plan))
+        .context("Failed to serialize plan tree")?;
+
+    JString::from_str(env, tree_str).context("Failed to build plan string")
+}
+
+const PROFILE_METHOD: NativeMethod =
+    ldf_method!(extern fn profile(ldf: LazyFrameHandle) -> [jlong],);
+
+/// Executes the query on the in-memory engine while timing every node of the physical plan,
+/// returning the handles of the result and of a timings DataFrame (`node`, `start`, `end`, in
+/// microseconds since the query started) as a two-element array.
+fn profile<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ldf: LazyFrameHandle,
+) -> anyhow::Result<JLongArray<'local>> {
+    let (df, timings) = ldf.get().profile().context("Failed to profile LazyFrame")?;
+
+    vec_to_j_long_array(
+        env,
+        &[
+            DataFrameHandle::alloc(df).into(),
+            DataFrameHandle::alloc(timings).into(),
+        ],
+    )
+}
+
+const PROFILE_STREAMING_METHOD: NativeMethod = ldf_method!(extern fn profile_streaming(ldf: LazyFrameHandle) -> [jlong], name = "profileStreaming",);
+
+/// Executes the query on the streaming engine while collecting metrics for every operator,
+/// returning the handles of the result and of a metrics DataFrame as a two-element array. The
+/// metrics hold one row per operator with its name (`node`), the rows and morsels it received
+/// (`rows_in`, `morsels_in`) and sent (`rows_out`, `morsels_out`), and the time spent in it in
+/// microseconds (`time`), summed over all threads.
+fn profile_streaming<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ldf: LazyFrameHandle,
+) -> anyhow::Result<JLongArray<'local>> {
+    let mut plan = ldf
+        .get()
+        .with_streaming(true)
+        .to_alp_optimized()
+        .context("Failed to optimize LazyFrame")?;
+    plan.ensure_root_node_is_sink();
+
+    // Task metrics are tracked process-wide once enabled, as polars does for
+    // `POLARS_TRACK_METRICS`; disabling them again could break concurrent profiles.
+    polars_async::executor::track_task_metrics(true);
+    let mut query = StreamingQuery::build(plan.lp_top, &mut plan.lp_arena, &mut plan.expr_arena)
+        .context("Failed to build streaming query")?;
+    let metrics = Arc::default();
+    query.metrics = Some(Arc::clone(&metrics));
+
+    let operators: Vec<(GraphNodeKey, String)> = query
+        .phys_sm
+        .keys()
+        .filter_map(|key| query.phys_to_graph.get(key))
+        .map(|&key| (key, query.graph.nodes[key].compute.name().to_string()))
+        .collect();
+
+    let df = query
+        .execute()
+        .context("Failed to profile LazyFrame")?
+        .unwrap_single();
+
+    let metrics = metrics.lock();
+    let operators: Vec<(String, NodeMetrics)> = operators
+        .into_iter()
+        .map(|(key, name)| (name, metrics.get(key).cloned().unwrap_or_default()))
+        .collect();
+    let column = |name: &str, f: fn(&NodeMetrics) -> u64| {
+        let values: Vec<i64> = operators.iter().map(|(_, m)| f(m) as i64).collect();
+        Column::new(name.into(), values)
+    };
+    let node_metrics = DataFrame::new_infer_height(vec![
+        Column::new(
+            "node".into(),
+            operators
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+        ),
+        column("rows_in", |m| m.rows_received),
+        column("rows_out", |m| m.rows_sent),
+        column("morsels_in", |m| m.morsels_received),
+        column("morsels_out", |m| m.morsels_sent),
+        column("time", |m| {
+            (m.total_poll_time_ns + m.total_state_update_time_ns) / 1_000
+        }),
+    ])
+    .context("Failed to build operator metrics")?;
+
+    vec_to_j_long_array(
+        env,
+        &[
+            DataFrameHandle::alloc(df).into(),
+            DataFrameHandle::alloc(node_metrics).into(),
+        ],
+    )
+}
+
 const SET_SORTED_METHOD: NativeMethod = ldf_method!(extern fn set_sorted( ldf: LazyFrameHandle, column: java.lang.String, descending: jboolean, nulls_last: jboolean ) -> LazyFrameHandle, name = "setSorted",);
 
 fn set_sorted<'local>(
@@ -421,6 +613,138 @@ fn collect<'local>(
     Ok(DataFrameHandle::alloc(df))
 }
 
+const COLLECT_ASYNC_METHOD: NativeMethod = ldf_method!(extern fn collect_async(ldf: LazyFrameHandle) -> QueryHandle, name = "collectAsync",);
+
+/// Starts executing the query on the polars thread pool and returns immediately with a handle to
+/// poll, await or cancel it through `query$`.
+fn collect_async<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ldf: LazyFrameHandle,
+) -> anyhow::Result<QueryHandle> {
+    let query = ldf
+        .get()
+        .collect_concurrently()
+        .context("Failed to start collecting LazyFrame into DataFrame")?;
+
+    Ok(QueryHandle::alloc(AsyncQuery::new(query)))
+}
+
+/// The optimizations enabled on every one of `ldfs`, so that evaluating them together never
+/// applies an optimization that one of the inputs turned off.
+fn common_optimizations(ldfs: &[LazyFrame]) -> OptFlags {
+    ldfs.iter()
+        .map(|ldf| ldf.get_current_optimizations())
+        .reduce(|acc, flags| acc & flags)
+        .unwrap_or_default()
+}
+
+const COLLECT_ALL_METHOD: NativeMethod =
+    ldf_method!(extern fn collect_all(inputs: [jlong]) -> [jlong], name = "collectAll",);
+
+/// Optimizes the plans of all `inputs` together so that common subplans (e.g. a shared scan) are
+/// executed once, returning one `DataFrameHandle` per input in the same order.
+fn collect_all<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    inputs: JLongArray<'local>,
+) -> anyhow::Result<JLongArray<'local>> {
+    let ldfs: Vec<LazyFrame> = JavaArrayToVec::to_vec(env, inputs)?
+        .into_iter()
+        .map(|ptr| LazyFrameHandle::from(ptr).get())
+        .collect();
+
+    let opt_state = common_optimizations(&ldfs);
+
+    let plans = ldfs.into_iter().map(|ldf| ldf.logical_plan).collect();
+
+    let ptrs: Vec<jlong> = LazyFrame::collect_all_with_engine(plans, Engine::InMemory, opt_state)
+        .context("Failed to collect LazyFrames into DataFrames")?
+        .into_iter()
+        .map(|df| DataFrameHandle::alloc(df).into())
+        .collect();
+
+    vec_to_j_long_array(env, &ptrs)
+}
+
+const SINK_ALL_METHOD: NativeMethod = ldf_method!(extern fn sink_all( inputs: [jlong], paths: [java.lang.String], formats: [java.lang.String], options: java.lang.String ), name = "sinkAll",);
+
+/// Streams each of `inputs` into the file at the matching position of `paths`, in the matching
+/// format (`parquet`, `ipc`, `csv` or `json`), optimizing and executing all sinks in one pass so
+/// that common subplans are computed once. The remaining `options` are used as cloud options,
+/// resolved separately for each path so that local and cloud targets can be mixed.
+fn sink_all<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    inputs: JLongArray<'local>,
+    paths: JObjectArray<'local, JString<'local>>,
+    formats: JObjectArray<'local, JString<'local>>,
+    options: JString<'local>,
+) -> anyhow::Result<()> {
+    let ldfs: Vec<LazyFrame> = JavaArrayToVec::to_vec(env, inputs)?
+        .into_iter()
+        .map(|ptr| LazyFrameHandle::from(ptr).get())
+        .collect();
+
+    let paths = j_string_array_to_vec(env, &paths, "Failed to get provided path")?;
+    let formats = j_string_array_to_vec(
+        env,
+        &formats,
+        "Failed to parse the provided value as sink format",
+    )?;
+
+    ensure!(
+        ldfs.len() == paths.len() && paths.len() == formats.len(),
+        "Expected one path and one format per LazyFrame, got {} LazyFrames, {} paths and {} formats",
+        ldfs.len(),
+        paths.len(),
+        formats.len()
+    );
+
+    let mut options = parse_json_to_options(env, &options)?;
+
+    let sink_args = UnifiedSinkArgs {
+        maintain_order: opt_parse::<bool>(&mut options, "sink_maintain_order").unwrap_or(true),
+        mkdir: opt_parse::<bool>(&mut options, "sink_mkdir").unwrap_or(false),
+        ..Default::default()
+    };
+
+    let opt_state = common_optimizations(&ldfs);
+
+    let mut plans = Vec::with_capacity(ldfs.len());
+    for ((ldf, path), format) in ldfs.into_iter().zip(paths).zip(formats) {
+        let file_format = match format.to_lowercase().as_str() {
+            "parquet" => FileWriteFormat::Parquet(Arc::new(ParquetWriteOptions::default())),
+            "ipc" => FileWriteFormat::Ipc(IpcWriterOptions::default()),
+            "csv" => FileWriteFormat::Csv(CsvWriterOptions::default()),
+            "json" => FileWriteFormat::NDJson(NDJsonWriterOptions::default()),
+            e => bail!("Sink format must be one of {{'parquet', 'ipc', 'csv', 'json'}}, got {e}"),
+        };
+
+        let path = PlRefPath::new(path);
+        let cloud_options = parse_cloud_options(path.scheme(), options.clone())?.map(Arc::new);
+
+        let destination = SinkDestination::File {
+            target: SinkTarget::Path(path),
+        };
+        let sink_args = UnifiedSinkArgs {
+            cloud_options,
+            ..sink_args.clone()
+        };
+
+        let sink_ldf = ldf
+            .sink(destination, file_format, sink_args)
+            .with_context(|| format!("Failed to build {format} sink"))?;
+
+        plans.push(sink_ldf.logical_plan);
+    }
+
+    LazyFrame::collect_all_with_engine(plans, Engine::Streaming, opt_state)
+        .context("Failed to sink LazyFrames")?;
+
+    Ok(())
+}
+
 const CONCAT_LAZY_FRAMES_METHOD: NativeMethod = ldf_method!(extern fn concat_lazy_frames(inputs: [jlong], parallel: jboolean, re_chunk: jboolean) -> LazyFrameHandle, name = "concatLazyFrames",);
 
 fn concat_lazy_frames<'local>(
@@ -478,6 +802,70 @@ fn optimization_toggle<'local>(
     Ok(LazyFrameHandle::alloc(ldf))
 }
 
+const SERIALIZE_JSON_METHOD: NativeMethod = ldf_method!(extern fn serialize_json(ldf: LazyFrameHandle, allow_in_memory: jboolean) -> JString, name = "serializeJson",);
+
+fn serialize_json<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ldf: LazyFrameHandle,
+    allow_in_memory: jboolean,
+) -> anyhow::Result<JString<'local>> {
+    let plan_str =
+        serialization::plan_to_json(&ldf.get().logical_plan, allow_in_memory == JNI_TRUE)
+            .context("Failed to serialize LazyFrame")?;
+
+    JString::from_str(env, plan_str).context("Failed to build plan string")
+}
+
+const SERIALIZE_BINARY_METHOD: NativeMethod = ldf_method!(extern fn serialize_binary(ldf: LazyFrameHandle, allow_in_memory: jboolean) -> [jbyte], name = "serializeBinary",);
+
+fn serialize_binary<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ldf: LazyFrameHandle,
+    allow_in_memory: jboolean,
+) -> anyhow::Result<JByteArray<'local>> {
+    let bytes = serialization::plan_to_bytes(&ldf.get().logical_plan, allow_in_memory == JNI_TRUE)
+        .context("Failed to serialize LazyFrame")?;
+
+    env.byte_array_from_slice(&bytes)
+        .context("Failed to build plan byte array")
+}
+
+const DESERIALIZE_JSON_METHOD: NativeMethod = ldf_method!(extern fn deserialize_json(json: java.lang.String) -> LazyFrameHandle, name = "deserializeJson",);
+
+fn deserialize_json<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    json: JString<'local>,
+) -> anyhow::Result<LazyFrameHandle> {
+    let json = j_string_to_string(
+        env,
+        &json,
+        Some("Failed to parse the provided serialized plan as string"),
+    )?;
+
+    let plan = serialization::plan_from_json(&json).context("Failed to deserialize LazyFrame")?;
+
+    Ok(LazyFrameHandle::alloc(LazyFrame::from(plan)))
+}
+
+const DESERIALIZE_BINARY_METHOD: NativeMethod = ldf_method!(extern fn deserialize_binary(bytes: [jbyte]) -> LazyFrameHandle, name = "deserializeBinary",);
+
+fn deserialize_binary<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    bytes: JByteArray<'local>,
+) -> anyhow::Result<LazyFrameHandle> {
+    let bytes = env
+        .convert_byte_array(&bytes)
+        .context("Failed to read the provided serialized plan")?;
+
+    let plan = serialization::plan_from_bytes(&bytes).context("Failed to deserialize LazyFrame")?;
+
+    Ok(LazyFrameHandle::alloc(LazyFrame::from(plan)))
+}
+
 decl_free!(
     FREE_METHOD,
     "com.github.chitralverma.polars.internal.jni.lazy_frame$",
@@ -496,6 +884,8 @@ fn extract_expr_and_direction(expr: &Expr, default_direction: bool) -> (Expr, bo
 
 pub const METHODS: &[NativeMethod] = &[
     SCHEMA_STRING_METHOD,
+    SCHEMA_JSON_METHOD,
+    CAST_TO_SCHEMA_METHOD,
     SELECT_FROM_STRINGS_METHOD,
     SELECT_FROM_EXPRS_METHOD,
     FILTER_FROM_EXPRS_METHOD,
@@ -509,10 +899,21 @@ pub const METHODS: &[NativeMethod] = &[
     WITH_COLUMN_METHOD,
     UNIQUE_METHOD,
     EXPLAIN_METHOD,
+    TO_DOT_METHOD,
+    PLAN_TREE_METHOD,
+    PROFILE_METHOD,
+    PROFILE_STREAMING_METHOD,
     SET_SORTED_METHOD,
     CACHE_METHOD,
     COLLECT_METHOD,
+    COLLECT_ASYNC_METHOD,
+    COLLECT_ALL_METHOD,
+    SINK_ALL_METHOD,
     CONCAT_LAZY_FRAMES_METHOD,
     OPTIMIZATION_TOGGLE_METHOD,
+    SERIALIZE_JSON_METHOD,
+    SERIALIZE_BINARY_METHOD,
+    DESERIALIZE_JSON_METHOD,
+    DESERIALIZE_BINARY_METHOD,
     FREE_METHOD,
 ];
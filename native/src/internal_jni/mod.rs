@@ -1,4 +1,6 @@
 pub mod conversion;
+pub mod direct_buffer;
+pub mod dtype;
 pub mod expr;
 pub mod frame;
 pub mod handle;
@@ -6,6 +8,9 @@ pub mod io;
 pub mod lazy;
 #[macro_use]
 pub mod macros;
+pub mod plan_tree;
+pub mod query;
 pub mod row;
+pub mod serialization;
 pub mod series;
 pub mod utils;
@@ -0,0 +1,102 @@
+//! Structured export of a logical plan as a JSON tree, for tools that render or inspect plans
+//! programmatically instead of parsing the formatted `explain` output.
+
+use polars::prelude::*;
+use polars_plan::plans::expr_ir::ExprIR;
+use polars_plan::prelude::{AExpr, Arena, IR, IRPlan, Node};
+use serde_json::{Map, Value, json};
+
+/// Converts `plan` into a JSON tree rooted at its top node. Every node carries its `type` and
+/// output `schema` (column name to dtype) and its child nodes under `inputs`; depending on the
+/// node type it also carries `sources`, `projection` (the projected columns), `predicates` (the
+/// filters evaluated at that node, including those pushed down into scans) and `expressions`.
+pub fn plan_to_json_tree(plan: &IRPlan) -> Value {
+    node_to_json(plan.lp_top, &plan.lp_arena, &plan.expr_arena)
+}
+
+fn node_to_json(node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Value {
+    let ir = lp_arena.get(node);
+
+    let mut out = Map::new();
+    out.insert("type".into(), json!(ir.name()));
+    out.insert(
+        "schema".into(),
+        schema_to_json(ir.schema(lp_arena).as_ref()),
+    );
+
+    let exprs_to_json = |exprs: &[ExprIR]| -> Value {
+        exprs
+            .iter()
+            .map(|e| json!(e.display(expr_arena).to_string()))
+            .collect()
+    };
+
+    match ir {
+        IR::Scan {
+            sources,
+            predicate,
+            output_schema,
+            ..
+        } => {
+            let paths: Vec<Value> = sources
+                .as_paths()
+                .unwrap_or_default()
+                .iter()
+                .map(|p| json!(p.as_str()))
+                .collect();
+            out.insert("sources".into(), Value::Array(paths));
+            out.insert("projection".into(), projection_to_json(output_schema));
+            out.insert("predicates".into(), exprs_to_json(predicate.as_slice()));
+        },
+        IR::DataFrameScan { output_schema, .. } => {
+            out.insert("sources".into(), json!(["<in-memory>"]));
+            out.insert("projection".into(), projection_to_json(output_schema));
+        },
+        IR::SimpleProjection { columns, .. } => {
+            out.insert(
+                "projection".into(),
+                columns.iter_names().map(|n| json!(n.as_str())).collect(),
+            );
+        },
+        IR::Filter { predicate, .. } => {
+            out.insert(
+                "predicates".into(),
+                exprs_to_json(std::slice::from_ref(predicate)),
+            );
+        },
+        IR::Select { expr, .. } => {
+            out.insert("expressions".into(), exprs_to_json(expr));
+        },
+        IR::HStack { exprs, .. } => {
+            out.insert("expressions".into(), exprs_to_json(exprs));
+        },
+        IR::Sort { by_column, .. } => {
+            out.insert("expressions".into(), exprs_to_json(by_column));
+        },
+        _ => {},
+    }
+
+    let inputs: Vec<Value> = ir
+        .inputs()
+        .map(|input| node_to_json(input, lp_arena, expr_arena))
+        .collect();
+    out.insert("inputs".into(), Value::Array(inputs));
+
+    Value::Object(out)
+}
+
+fn schema_to_json(schema: &Schema) -> Value {
+    schema
+        .iter()
+        .map(|(name, dtype)| (name.to_string(), json!(dtype.to_string())))
+        .collect::<Map<_, _>>()
+        .into()
+}
+
+/// A `null` projection means all columns of the source are read.
+fn projection_to_json(output_schema: &Option<SchemaRef>) -> Value {
+    match output_schema {
+        Some(schema) => schema.iter_names().map(|n| json!(n.as_str())).collect(),
+        None => Value::Null,
+    }
+}
@@ -0,0 +1,221 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, bail};
+use jni::objects::JObject;
+use jni::sys::{jboolean, jlong};
+use jni::{Env, NativeMethod, jni_sig, jni_str, native_method};
+use polars::prelude::*;
+
+use crate::internal_jni::handle::{DataFrameHandle, Handle, QueryHandle};
+use crate::utils::error::ThrowRuntimeException;
+
+/// Interval between result checks while a caller waits on a running query, bounding how long a
+/// timeout or a JVM thread interrupt can go unnoticed.
+const AWAIT_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Injects the shared `query$` config into [`native_method!`].
+macro_rules! query_method {
+    ($($tt:tt)*) => {
+        native_method! {
+            java_type = "com.github.chitralverma.polars.internal.jni.query$",
+            error_policy = ThrowRuntimeException,
+            type_map = { unsafe QueryHandle => long, unsafe DataFrameHandle => long },
+            $($tt)*
+        }
+    };
+}
+
+const POLL_METHOD: NativeMethod = query_method! {
+    extern fn poll(query: QueryHandle) -> DataFrameHandle,
+};
+
+/// Returns the result if the query has finished, or a zero handle while it is still running.
+fn poll<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    query: QueryHandle,
+) -> anyhow::Result<DataFrameHandle> {
+    let query = unsafe { query.as_ref() };
+
+    match query.try_fetch()? {
+        Some(df) => Ok(DataFrameHandle::alloc(df)),
+        None => Ok(DataFrameHandle::default()),
+    }
+}
+
+const AWAIT_RESULT_METHOD: NativeMethod = query_method! {
+    extern fn await_result(query: QueryHandle, timeout_millis: jlong) -> DataFrameHandle,
+    name = "awaitResult",
+};
+
+/// Blocks until the query finishes, or until `timeout_millis` elapses (a non-positive timeout
+/// waits indefinitely), returning a zero handle on timeout. An interrupt of the calling JVM
+/// thread cancels the query and surfaces as a `java.lang.InterruptedException`.
+fn await_result<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    query: QueryHandle,
+    timeout_millis: jlong,
+) -> anyhow::Result<DataFrameHandle> {
+    let query = unsafe { query.as_ref() };
+
+    let deadline = if timeout_millis.is_positive() {
+        Some(Instant::now() + Duration::from_millis(timeout_millis as u64))
+    } else {
+        None
+    };
+
+    loop {
+        if let Some(df) = query.try_fetch()? {
+            return Ok(DataFrameHandle::alloc(df));
+        }
+
+        if deadline.is_some_and(|d| Instant::now() >= d) {
+            return Ok(DataFrameHandle::default());
+        }
+
+        let interrupted = env
+            .call_static_method(
+                jni_str!("java/lang/Thread"),
+                jni_str!("interrupted"),
+                jni_sig!("()Z"),
+                &[],
+            )
+            .and_then(|v| v.z())
+            .context("Failed to check the interrupt status of the calling thread")?;
+
+        if interrupted {
+            query.cancel();
+            // The pending exception takes precedence over the error below in the error policy.
+            let _ = env.throw_new(
+                jni_str!("java/lang/InterruptedException"),
+                jni_str!("Interrupted while awaiting the query result; the query was cancelled"),
+            );
+            bail!("Interrupted while awaiting the query result");
+        }
+
+        std::thread::sleep(AWAIT_POLL_INTERVAL);
+    }
+}
+
+const CANCEL_METHOD: NativeMethod = query_method! {
+    extern fn cancel(query: QueryHandle),
+};
+
+fn cancel<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    query: QueryHandle,
+) -> anyhow::Result<()> {
+    unsafe { query.as_ref() }.cancel();
+    Ok(())
+}
+
+const IS_DONE_METHOD: NativeMethod = query_method! {
+    extern fn is_done(query: QueryHandle) -> jboolean,
+    name = "isDone",
+};
+
+fn is_done<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    query: QueryHandle,
+) -> anyhow::Result<jboolean> {
+    Ok(unsafe { query.as_ref() }.is_done())
+}
+
+decl_free!(
+    FREE_METHOD,
+    "com.github.chitralverma.polars.internal.jni.query$",
+    QueryHandle
+);
+
+enum QueryStatus {
+    Running,
+    Finished(DataFrame),
+    Failed(String),
+}
+
+/// A query executing on the polars thread pool, started by `lazy_frame$.collectAsync`.
+///
+/// The outcome is retained once received so that repeated polls return it again. Dropping the
+/// handle (via `free`) cancels a query that is still running, see the [`Drop`] impl.
+pub struct AsyncQuery {
+    query: InProcessQuery,
+    status: Mutex<QueryStatus>,
+}
+
+impl AsyncQuery {
+    pub fn new(query: InProcessQuery) -> Self {
+        Self {
+            query,
+            status: Mutex::new(QueryStatus::Running),
+        }
+    }
+
+    /// Asks polars to stop executing the query at its earliest convenience.
+    pub fn cancel(&self) {
+        self.query.cancel()
+    }
+
+    pub fn is_done(&self) -> bool {
+        let mut status = self.status.lock().expect("AsyncQuery status lock poisoned");
+        Self::receive(&self.query, &mut status);
+        !matches!(*status, QueryStatus::Running)
+    }
+
+    /// Returns the materialized result if the query has finished, `None` while it is running, or
+    /// the failure (including cancellation) reported by polars.
+    pub fn try_fetch(&self) -> anyhow::Result<Option<DataFrame>> {
+        let mut status = self.status.lock().expect("AsyncQuery status lock poisoned");
+        Self::receive(&self.query, &mut status);
+
+        match &*status {
+            QueryStatus::Running => Ok(None),
+            QueryStatus::Finished(df) => Ok(Some(df.clone())),
+            QueryStatus::Failed(msg) => bail!("Failed to collect LazyFrame into DataFrame: {msg}"),
+        }
+    }
+
+    fn receive(query: &InProcessQuery, status: &mut QueryStatus) {
+        if let QueryStatus::Running = status
+            && let Some(result) = query.fetch()
+        {
+            *status = match result {
+                Ok(df) => QueryStatus::Finished(df),
+                Err(e) => QueryStatus::Failed(e.to_string()),
+            };
+        }
+    }
+}
+
+impl Drop for AsyncQuery {
+    /// Polars sends the outcome of a query with `tx.send(result).unwrap()` on its thread pool, so
+    /// the receiver must outlive a still running query: dropping it would panic on a pool without
+    /// a panic handler and abort the JVM. The query is cancelled and a detached thread keeps the
+    /// receiver alive until the (cancelled) outcome arrives.
+    fn drop(&mut self) {
+        let status = self
+            .status
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Self::receive(&self.query, status);
+
+        if let QueryStatus::Running = status {
+            self.query.cancel();
+            let query = self.query.clone();
+            std::thread::spawn(move || {
+                let _ = query.fetch_blocking();
+            });
+        }
+    }
+}
+
+pub const METHODS: &[NativeMethod] = &[
+    POLL_METHOD,
+    AWAIT_RESULT_METHOD,
+    CANCEL_METHOD,
+    IS_DONE_METHOD,
+    FREE_METHOD,
+];
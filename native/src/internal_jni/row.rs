@@ -1,11 +1,12 @@
-use anyhow::Context;
-use jni::objects::{JObject, JObjectArray, JString};
-use jni::sys::jlong;
+use anyhow::{Context, ensure};
+use jni::objects::{IntoAuto, JObject, JObjectArray, JString};
+use jni::sys::{jboolean, jint, jlong};
 use jni::{Env, NativeMethod, native_method};
 use polars::prelude::*;
 
 use crate::internal_jni::conversion::{AnyValueWrapper, IntoJava};
-use crate::internal_jni::handle::{DataFrameHandle, Handle, RowIteratorHandle};
+use crate::internal_jni::dtype::schema_to_json;
+use crate::internal_jni::handle::{DataFrameHandle, Handle, LazyFrameHandle, RowIteratorHandle};
 use crate::internal_jni::utils::get_n_rows;
 use crate::utils::error::ThrowRuntimeException;
 
@@ -15,7 +16,11 @@ macro_rules! row_method {
         native_method! {
             java_type = "com.github.chitralverma.polars.internal.jni.row$",
             error_policy = ThrowRuntimeException,
-            type_map = { unsafe DataFrameHandle => long, unsafe RowIteratorHandle => long },
+            type_map = {
+                unsafe DataFrameHandle => long,
+                unsafe LazyFrameHandle => long,
+                unsafe RowIteratorHandle => long,
+            },
             $($tt)*
         }
     };
@@ -37,6 +42,42 @@ fn create_iterator<'local>(
     Ok(RowIteratorHandle::alloc(ri))
 }
 
+const CREATE_LAZY_ITERATOR_METHOD: NativeMethod = row_method! {
+    extern fn create_lazy_iterator(
+        ldf: LazyFrameHandle,
+        n_rows: jlong,
+        streaming: jboolean,
+    ) -> RowIteratorHandle,
+    name = "createLazyIterator",
+};
+
+/// Starts an iterator over the result of a LazyFrame. With `streaming`, the query runs on the
+/// streaming engine and rows are produced batch by batch as the engine emits them, instead of
+/// after the whole result is collected.
+fn create_lazy_iterator<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ldf: LazyFrameHandle,
+    n_rows: jlong,
+    streaming: jboolean,
+) -> anyhow::Result<RowIteratorHandle> {
+    let mut ldf = ldf.get();
+    if let Some(n) = get_n_rows(n_rows) {
+        ldf = ldf.limit(n as IdxSize);
+    }
+
+    let ri = if streaming {
+        RowIterator::streaming(ldf)?
+    } else {
+        let mut df = ldf
+            .collect()
+            .context("Failed to collect LazyFrame into DataFrame")?;
+        RowIterator::new(&mut df, None)
+    };
+
+    Ok(RowIteratorHandle::alloc(ri))
+}
+
 const ADVANCE_ITERATOR_METHOD: NativeMethod = row_method! {
     extern fn advance_iterator(ri: RowIteratorHandle) -> [java.lang.Object],
     name = "advanceIterator",
@@ -48,8 +89,11 @@ fn advance_iterator<'local>(
     ri: RowIteratorHandle,
 ) -> anyhow::Result<JObjectArray<'local, JObject<'local>>> {
     let ri = unsafe { ri.as_mut() };
+    if let Some(err) = ri.pending_error.take() {
+        return Err(err);
+    }
 
-    if let Some(next_avs) = ri.advance() {
+    if let Some(next_avs) = ri.advance()? {
         let j_array = JObjectArray::<JObject>::new(env, next_avs.len(), JObject::null())
             .context("Failed to initialize array for row values")?;
 
@@ -69,6 +113,86 @@ fn advance_iterator<'local>(
     }
 }
 
+const ADVANCE_BATCH_METHOD: NativeMethod = row_method! {
+    extern fn advance_batch(ri: RowIteratorHandle, n: jint) -> java.lang.Object[][],
+    name = "advanceBatch",
+};
+
+/// Converts the values of one row into an `Object[]` of boxed values.
+fn row_to_java<'local>(
+    env: &mut Env<'local>,
+    width: usize,
+    values: Vec<AnyValue<'_>>,
+) -> anyhow::Result<JObjectArray<'local, JObject<'local>>> {
+    let j_row = JObjectArray::<JObject>::new(env, width, JObject::null())
+        .context("Failed to initialize array for row values")?;
+    for (i, any_value) in values.into_iter().enumerate() {
+        let java_object = AnyValueWrapper(any_value.clone())
+            .try_into_java(env)?
+            .auto();
+        j_row
+            .set_element(env, i, &java_object)
+            .context(format!("Failed to set value `{any_value}` in row"))?;
+    }
+    Ok(j_row)
+}
+
+/// Returns up to `n` next rows in one call, each as an `Object[]` of boxed values, or `null` once
+/// the iterator is exhausted. If reading a row fails after some rows of the batch were read, those
+/// rows are returned first and the error is raised by the next call. `n` must be positive.
+fn advance_batch<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ri: RowIteratorHandle,
+    n: jint,
+) -> anyhow::Result<JObjectArray<'local, JObjectArray<'local, JObject<'local>>>> {
+    ensure!(n > 0, "Batch size must be positive, got `{n}`");
+    let ri = unsafe { ri.as_mut() };
+    if let Some(err) = ri.pending_error.take() {
+        return Err(err);
+    }
+
+    let n = n as usize;
+    let width = ri.schema.len();
+    let mut rows = Vec::new();
+
+    while rows.len() < n {
+        let next = ri
+            .advance()
+            .and_then(|values| values.map(|v| row_to_java(env, width, v)).transpose());
+        match next {
+            Ok(Some(j_row)) => rows.push(j_row.auto()),
+            Ok(None) => break,
+            Err(err) if !rows.is_empty() => {
+                ri.pending_error = Some(err);
+                break;
+            },
+            Err(err) => return Err(err),
+        }
+    }
+
+    if rows.is_empty() {
+        // As in `advance_iterator`, end-of-iteration must be a JVM null rather than empty.
+        return Ok(unsafe {
+            JObjectArray::<JObjectArray<JObject>>::from_raw(env, std::ptr::null_mut())
+        });
+    }
+
+    let j_rows = JObjectArray::<JObjectArray<JObject>>::new(
+        env,
+        rows.len(),
+        JObjectArray::<JObject>::null(),
+    )
+    .context("Failed to initialize array for row batch")?;
+    for (i, j_row) in rows.iter().enumerate() {
+        j_rows
+            .set_element(env, i, j_row)
+            .context("Failed to set row in row batch")?;
+    }
+
+    Ok(j_rows)
+}
+
 const SCHEMA_STRING_METHOD: NativeMethod = row_method! {
     extern fn schema_string(ri: RowIteratorHandle) -> JString,
     name = "schemaString",
@@ -87,17 +211,39 @@ fn schema_string<'local>(
     JString::from_str(env, schema_string).context("Failed to build schema string")
 }
 
+const SCHEMA_JSON_METHOD: NativeMethod = row_method! {
+    extern fn schema_json(ri: RowIteratorHandle) -> JString,
+    name = "schemaJson",
+};
+
+/// Describes the schema with polars' own data types, see [`crate::internal_jni::dtype`].
+fn schema_json<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    ri: RowIteratorHandle,
+) -> anyhow::Result<JString<'local>> {
+    let ri = unsafe { ri.as_ref() };
+
+    let schema_string = schema_to_json(&ri.schema)?;
+
+    JString::from_str(env, schema_string).context("Failed to build schema string")
+}
+
 decl_free!(
     FREE_METHOD,
     "com.github.chitralverma.polars.internal.jni.row$",
     RowIteratorHandle
 );
 
+/// Iterates over the rows of a DataFrame, or of the batches a streaming query emits.
 pub struct RowIterator {
     columns: Vec<Column>,
     start: usize,
     pub end: usize,
     pub schema: SchemaRef,
+    batches: Option<Box<dyn Iterator<Item = PolarsResult<DataFrame>> + Send>>,
+    /// Error hit while filling a batch that already held rows, raised by the next batch.
+    pending_error: Option<anyhow::Error>,
 }
 
 impl RowIterator {
@@ -111,31 +257,80 @@ impl RowIterator {
             start: 0,
             schema: schema.clone(),
             end: std::cmp::min(end.unwrap_or(data_frame.height()), data_frame.height()),
+            batches: None,
+            pending_error: None,
         }
     }
 
-    pub fn advance(&mut self) -> Option<Vec<AnyValue<'_>>> {
-        if self.start < self.end {
-            let mut row = Vec::with_capacity(self.columns.len());
-            for col in &self.columns {
-                row.push(
-                    col.get(self.start)
-                        .expect("RowIterator invariant violated: column index out of bounds"),
-                );
+    /// Runs `ldf` on the streaming engine, buffering one emitted batch at a time.
+    pub fn streaming(mut ldf: LazyFrame) -> anyhow::Result<Self> {
+        let schema = ldf
+            .collect_schema()
+            .context("Failed to resolve schema of LazyFrame")?;
+        let batches = ldf
+            .collect_batches(Engine::Streaming, true, None, false)
+            .context("Failed to start streaming LazyFrame")?;
+
+        Ok(Self {
+            columns: Vec::new(),
+            start: 0,
+            end: 0,
+            schema,
+            batches: Some(Box::new(batches)),
+            pending_error: None,
+        })
+    }
+
+    /// Moves on to the next non-empty batch once the current one is exhausted, returning whether
+    /// any rows are left.
+    fn fill(&mut self) -> anyhow::Result<bool> {
+        while self.start >= self.end {
+            let Some(batches) = self.batches.as_mut() else {
+                return Ok(false);
+            };
+
+            match batches.next() {
+                Some(batch) => {
+                    let mut batch = batch.context("Failed to stream LazyFrame")?;
+                    batch.align_chunks_par();
+                    self.columns = batch.columns().to_vec();
+                    self.start = 0;
+                    self.end = batch.height();
+                },
+                None => {
+                    self.batches = None;
+                    return Ok(false);
+                },
             }
+        }
+        Ok(true)
+    }
 
-            self.start += 1;
+    pub fn advance(&mut self) -> anyhow::Result<Option<Vec<AnyValue<'_>>>> {
+        if !self.fill()? {
+            return Ok(None);
+        }
 
-            Some(row)
-        } else {
-            None
+        let mut row = Vec::with_capacity(self.columns.len());
+        for col in &self.columns {
+            row.push(
+                col.get(self.start)
+                    .expect("RowIterator invariant violated: column index out of bounds"),
+            );
         }
+
+        self.start += 1;
+
+        Ok(Some(row))
     }
 }
 
 pub const METHODS: &[NativeMethod] = &[
     CREATE_ITERATOR_METHOD,
+    CREATE_LAZY_ITERATOR_METHOD,
     ADVANCE_ITERATOR_METHOD,
+    ADVANCE_BATCH_METHOD,
     SCHEMA_STRING_METHOD,
+    SCHEMA_JSON_METHOD,
     FREE_METHOD,
 ];
@@ -0,0 +1,135 @@
+//! Versioned (de)serialization of [`DslPlan`]s and [`Expr`]s, so that plans built in one JVM can be
+//! shipped to, cached for, and executed by another.
+//!
+//! Two encodings are offered. JSON wraps the serde representation in an envelope carrying the
+//! `DSL_VERSION` it was written with. Binary uses polars' own versioned format for plans, and the
+//! same magic-bytes-plus-version header in front of a MessagePack body for expressions. In both
+//! cases input written by an incompatible polars version is rejected with a clear error instead of
+//! being misread.
+
+use anyhow::{Context, bail, ensure};
+use polars::prelude::*;
+use polars_plan::dsl::DSL_VERSION;
+use polars_utils::pl_serialize;
+use serde_json::Value;
+
+const EXPR_MAGIC_BYTES: &[u8] = b"EXPR_DSL_VERSION";
+
+const JSON_VERSION_KEY: &str = "dsl_version";
+
+/// Rejects input written with a different major, or a newer minor, `DSL_VERSION`.
+fn check_dsl_version(major: u16, minor: u16) -> anyhow::Result<()> {
+    let (current_major, current_minor) = DSL_VERSION;
+    ensure!(
+        major == current_major && minor <= current_minor,
+        "Serialized input has DSL_VERSION {major}.{minor}, which is not compatible with this \
+         polars version (DSL_VERSION {current_major}.{current_minor})"
+    );
+    Ok(())
+}
+
+fn wrap_json(key: &str, value: Value) -> anyhow::Result<String> {
+    let envelope = serde_json::json!({
+        JSON_VERSION_KEY: [DSL_VERSION.0, DSL_VERSION.1],
+        key: value,
+    });
+    serde_json::to_string(&envelope).context("Failed to serialize to JSON")
+}
+
+fn unwrap_json(json: &str, key: &str) -> anyhow::Result<Value> {
+    let mut envelope: Value = serde_json::from_str(json).context("Failed to parse JSON")?;
+
+    let version = envelope
+        .get(JSON_VERSION_KEY)
+        .and_then(Value::as_array)
+        .and_then(|v| {
+            let major = u16::try_from(v.first()?.as_u64()?).ok()?;
+            let minor = u16::try_from(v.get(1)?.as_u64()?).ok()?;
+            Some((major, minor))
+        })
+        .context(format!(
+            "Serialized JSON is missing a valid `{JSON_VERSION_KEY}` entry"
+        ))?;
+    check_dsl_version(version.0, version.1)?;
+
+    match envelope.get_mut(key) {
+        Some(value) => Ok(value.take()),
+        None => bail!("Serialized JSON is missing the `{key}` entry"),
+    }
+}
+
+/// Fails if `plan` scans an in-memory `DataFrameScan`, i.e. a DataFrame that was materialized in
+/// this process rather than read from a source the receiving JVM can access.
+fn ensure_no_in_memory_data(plan: &DslPlan) -> anyhow::Result<()> {
+    ensure!(
+        !plan
+            .into_iter()
+            .any(|node| matches!(node, DslPlan::DataFrameScan { .. })),
+        "Plan contains an in-memory DataFrame which cannot be shipped by reference; \
+         scan it from a shared location instead, or explicitly allow in-memory data"
+    );
+    Ok(())
+}
+
+pub fn plan_to_json(plan: &DslPlan, allow_in_memory: bool) -> anyhow::Result<String> {
+    if !allow_in_memory {
+        ensure_no_in_memory_data(plan)?;
+    }
+    let value = serde_json::to_value(plan).context("Failed to serialize plan")?;
+    wrap_json("plan", value)
+}
+
+pub fn plan_from_json(json: &str) -> anyhow::Result<DslPlan> {
+    let value = unwrap_json(json, "plan")?;
+    serde_json::from_value(value).context("Failed to deserialize plan")
+}
+
+pub fn plan_to_bytes(plan: &DslPlan, allow_in_memory: bool) -> anyhow::Result<Vec<u8>> {
+    if !allow_in_memory {
+        ensure_no_in_memory_data(plan)?;
+    }
+
+    let mut buf = Vec::new();
+    plan.serialize_versioned(&mut buf, Default::default())
+        .context("Failed to serialize plan")?;
+    Ok(buf)
+}
+
+pub fn plan_from_bytes(bytes: &[u8]) -> anyhow::Result<DslPlan> {
+    DslPlan::deserialize_versioned(bytes).context("Failed to deserialize plan")
+}
+
+pub fn expr_to_json(expr: &Expr) -> anyhow::Result<String> {
+    let value = serde_json::to_value(expr).context("Failed to serialize expression")?;
+    wrap_json("expr", value)
+}
+
+pub fn expr_from_json(json: &str) -> anyhow::Result<Expr> {
+    let value = unwrap_json(json, "expr")?;
+    serde_json::from_value(value).context("Failed to deserialize expression")
+}
+
+pub fn expr_to_bytes(expr: &Expr) -> anyhow::Result<Vec<u8>> {
+    let mut buf = Vec::from(EXPR_MAGIC_BYTES);
+    buf.extend_from_slice(&DSL_VERSION.0.to_le_bytes());
+    buf.extend_from_slice(&DSL_VERSION.1.to_le_bytes());
+    pl_serialize::serialize_into_writer::<_, _, true>(&mut buf, expr)
+        .context("Failed to serialize expression")?;
+    Ok(buf)
+}
+
+pub fn expr_from_bytes(bytes: &[u8]) -> anyhow::Result<Expr> {
+    let header_len = EXPR_MAGIC_BYTES.len() + 4;
+    ensure!(
+        bytes.len() >= header_len && bytes.starts_with(EXPR_MAGIC_BYTES),
+        "Serialized expression is missing its DSL_VERSION header"
+    );
+
+    let version = &bytes[EXPR_MAGIC_BYTES.len()..header_len];
+    let major = u16::from_le_bytes([version[0], version[1]]);
+    let minor = u16::from_le_bytes([version[2], version[3]]);
+    check_dsl_version(major, minor)?;
+
+    pl_serialize::deserialize_from_reader::<_, _, true>(&bytes[header_len..])
+        .context("Failed to deserialize expression")
+}
@@ -1,14 +1,27 @@
-use anyhow::Context;
+use anyhow::{Context, bail, ensure};
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use jni::objects::{
-    JBooleanArray, JDoubleArray, JFloatArray, JIntArray, JLongArray, JObject, JObjectArray, JString,
+    IntoAuto, JBooleanArray, JByteArray, JByteBuffer, JDoubleArray, JFloatArray, JIntArray,
+    JLongArray, JObject, JObjectArray, JShortArray, JString,
 };
-use jni::{Env, NativeMethod, native_method};
+use jni::sys::{jboolean, jdouble, jint, jlong};
+use jni::{Env, NativeMethod, jni_str, native_method};
+use num_traits::FromPrimitive;
 use polars::prelude::*;
+use polars_arrow::array::Array;
+use polars_utils::float16::pf16;
 
-use crate::internal_jni::conversion::JavaArrayToVec;
-use crate::internal_jni::handle::{Handle, SeriesHandle};
-use crate::internal_jni::utils::{j_string_array_to_vec, j_string_to_string};
+use crate::internal_jni::conversion::{AnyValueWrapper, IntoJava, JavaArrayToVec};
+use crate::internal_jni::direct_buffer::{
+    fill_direct_buffers, for_each_valid, series_from_direct_buffers,
+};
+use crate::internal_jni::dtype::{dtype_to_json, parse_dtype};
+use crate::internal_jni::expr::column::{BinaryOperator, jobject_to_any_value};
+use crate::internal_jni::handle::{DataFrameHandle, Handle, SeriesHandle};
+use crate::internal_jni::utils::{
+    ensure_decimal_fits, j_string_array_to_vec, j_string_to_string, parse_time_unit,
+    vec_to_j_long_array,
+};
 use crate::utils::error::ThrowRuntimeException;
 
 /// Injects the shared `series$` config into [`native_method!`].
@@ -17,7 +30,7 @@ macro_rules! series_method {
         native_method! {
             java_type = "com.github.chitralverma.polars.internal.jni.series$",
             error_policy = ThrowRuntimeException,
-            type_map = { unsafe SeriesHandle => long },
+            type_map = { unsafe SeriesHandle => long, unsafe DataFrameHandle => long },
             $($tt)*
         }
     };
@@ -94,6 +107,604 @@ impl_new_series!(
     JBooleanArray<'local>
 );
 
+/// Reads an optional validity mask (`null` means all values are valid) for `len` values, given
+/// either as a `boolean[]` with one entry per value or as a bit-packed `long[]` where value `i` is
+/// valid if bit `i % 64` of word `i / 64` is set, the layout of `java.util.BitSet.toLongArray`.
+fn read_validity(
+    env: &mut Env,
+    validity: &JObject,
+    len: usize,
+) -> anyhow::Result<Option<Vec<bool>>> {
+    if validity.is_null() {
+        return Ok(None);
+    }
+
+    let validity = env
+        .new_local_ref(validity)
+        .context("Failed to reference validity mask")?;
+
+    let mask = if env
+        .is_instance_of(&validity, jni_str!("[Z"))
+        .unwrap_or(false)
+    {
+        let array = env
+            .cast_local::<JBooleanArray>(validity)
+            .context("Failed to read validity mask as boolean[]")?;
+        let mask = JavaArrayToVec::to_vec(env, array)?;
+        ensure!(
+            mask.len() == len,
+            "Validity mask has {} entries for {len} values",
+            mask.len()
+        );
+        mask
+    } else if env
+        .is_instance_of(&validity, jni_str!("[J"))
+        .unwrap_or(false)
+    {
+        let array = env
+            .cast_local::<JLongArray>(validity)
+            .context("Failed to read validity mask as long[]")?;
+        let words = JavaArrayToVec::to_vec(env, array)?;
+        ensure!(
+            words.len() * 64 >= len,
+            "Bit-packed validity mask of {} words is too short for {len} values",
+            words.len()
+        );
+        (0..len)
+            .map(|i| (words[i / 64] >> (i % 64)) & 1 == 1)
+            .collect()
+    } else {
+        bail!("Validity mask must be a boolean[] or a bit-packed long[]")
+    };
+
+    Ok(Some(mask))
+}
+
+/// Pairs `values` with an optional validity mask, turning masked-out entries into nulls.
+fn apply_validity<T>(values: Vec<T>, validity: Option<Vec<bool>>) -> Vec<Option<T>> {
+    match validity {
+        Some(mask) => values
+            .into_iter()
+            .zip(mask)
+            .map(|(v, valid)| valid.then_some(v))
+            .collect(),
+        None => values.into_iter().map(Some).collect(),
+    }
+}
+
+/// Generates a `native_method!` entry point that builds a nullable [`Series`] from a Java primitive
+/// array and an optional validity mask (see [`read_validity`]), converting each element with
+/// `$convert`. Unsigned types reuse the Java array of the same width and reinterpret its bits, as
+/// `java.lang.Byte.toUnsignedInt` and friends do.
+macro_rules! impl_new_nullable_series {
+    (
+        $const_name:ident,
+        $fn_name:ident,
+        $scala_name:literal,
+        [$sig_elem:tt],
+        $java_array:ty,
+        $convert:expr
+    ) => {
+        const $const_name: NativeMethod = series_method! {
+            extern fn $fn_name(
+                name: java.lang.String,
+                values: [$sig_elem],
+                validity: java.lang.Object,
+            ) -> SeriesHandle,
+            name = $scala_name,
+        };
+
+        fn $fn_name<'local>(
+            env: &mut Env<'local>,
+            _this: JObject<'local>,
+            name: JString<'local>,
+            values: $java_array,
+            validity: JObject<'local>,
+        ) -> anyhow::Result<SeriesHandle> {
+            let values: Vec<_> = JavaArrayToVec::to_vec(env, values)?
+                .into_iter()
+                .map($convert)
+                .collect();
+            let validity = read_validity(env, &validity, values.len())?;
+
+            let series_name = j_string_to_string(
+                env,
+                &name,
+                Some("Failed to parse the provided value as a series name"),
+            )?;
+
+            Ok(SeriesHandle::alloc(Series::new(
+                PlSmallStr::from_string(series_name),
+                apply_validity(values, validity),
+            )))
+        }
+    };
+}
+
+impl_new_nullable_series!(
+    NEW_NULLABLE_LONG_SERIES_METHOD,
+    new_nullable_long_series,
+    "newNullableLongSeries",
+    [jlong],
+    JLongArray<'local>,
+    |v: i64| v
+);
+impl_new_nullable_series!(
+    NEW_NULLABLE_INT_SERIES_METHOD,
+    new_nullable_int_series,
+    "newNullableIntSeries",
+    [jint],
+    JIntArray<'local>,
+    |v: i32| v
+);
+impl_new_nullable_series!(
+    NEW_NULLABLE_FLOAT_SERIES_METHOD,
+    new_nullable_float_series,
+    "newNullableFloatSeries",
+    [jfloat],
+    JFloatArray<'local>,
+    |v: f32| v
+);
+impl_new_nullable_series!(
+    NEW_FLOAT16_SERIES_METHOD,
+    new_float16_series,
+    "newFloat16Series",
+    [jfloat],
+    JFloatArray<'local>,
+    pf16::from
+);
+impl_new_nullable_series!(
+    NEW_NULLABLE_DOUBLE_SERIES_METHOD,
+    new_nullable_double_series,
+    "newNullableDoubleSeries",
+    [jdouble],
+    JDoubleArray<'local>,
+    |v: f64| v
+);
+impl_new_nullable_series!(
+    NEW_NULLABLE_BOOLEAN_SERIES_METHOD,
+    new_nullable_boolean_series,
+    "newNullableBooleanSeries",
+    [jboolean],
+    JBooleanArray<'local>,
+    |v: bool| v
+);
+impl_new_nullable_series!(
+    NEW_INT8_SERIES_METHOD,
+    new_int8_series,
+    "newInt8Series",
+    [jbyte],
+    JByteArray<'local>,
+    |v: i8| v
+);
+impl_new_nullable_series!(
+    NEW_INT16_SERIES_METHOD,
+    new_int16_series,
+    "newInt16Series",
+    [jshort],
+    JShortArray<'local>,
+    |v: i16| v
+);
+impl_new_nullable_series!(
+    NEW_UINT8_SERIES_METHOD,
+    new_uint8_series,
+    "newUInt8Series",
+    [jbyte],
+    JByteArray<'local>,
+    |v: i8| v as u8
+);
+impl_new_nullable_series!(
+    NEW_UINT16_SERIES_METHOD,
+    new_uint16_series,
+    "newUInt16Series",
+    [jshort],
+    JShortArray<'local>,
+    |v: i16| v as u16
+);
+impl_new_nullable_series!(
+    NEW_UINT32_SERIES_METHOD,
+    new_uint32_series,
+    "newUInt32Series",
+    [jint],
+    JIntArray<'local>,
+    |v: i32| v as u32
+);
+impl_new_nullable_series!(
+    NEW_UINT64_SERIES_METHOD,
+    new_uint64_series,
+    "newUInt64Series",
+    [jlong],
+    JLongArray<'local>,
+    |v: i64| v as u64
+);
+
+/// Reads a `String[]` whose elements may be `null`.
+fn read_nullable_strings(
+    env: &mut Env,
+    values: &JObjectArray<JString>,
+) -> anyhow::Result<Vec<Option<String>>> {
+    let len = values.len(env).context("Failed to get array length")?;
+    let mut out = Vec::with_capacity(len);
+    for i in 0..len {
+        let element = values
+            .get_element(env, i)
+            .context("Failed to get array element")?
+            .auto();
+        out.push(if element.is_null() {
+            None
+        } else {
+            Some(j_string_to_string(
+                env,
+                &element,
+                Some("Failed to parse the provided value as a series element"),
+            )?)
+        });
+    }
+    Ok(out)
+}
+
+const NEW_CATEGORICAL_SERIES_METHOD: NativeMethod = series_method! {
+    extern fn new_categorical_series(
+        name: java.lang.String,
+        values: [java.lang.String],
+        categories: [java.lang.String],
+    ) -> SeriesHandle,
+    name = "newCategoricalSeries",
+};
+
+/// Builds a categorical series from strings (`null` elements become nulls). With `categories`
+/// the result is an enum over exactly those categories, and any other value is an error.
+fn new_categorical_series<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    name: JString<'local>,
+    values: JObjectArray<'local, JString<'local>>,
+    categories: JObjectArray<'local, JString<'local>>,
+) -> anyhow::Result<SeriesHandle> {
+    let data = read_nullable_strings(env, &values)?;
+
+    let dtype = if categories.is_null() {
+        DataType::from_categories(Categories::global())
+    } else {
+        let categories = j_string_array_to_vec(
+            env,
+            &categories,
+            "Failed to parse the provided value as an enum category",
+        )?;
+        DataType::from_frozen_categories(FrozenCategories::new(
+            categories.iter().map(String::as_str),
+        )?)
+    };
+
+    let series_name = j_string_to_string(
+        env,
+        &name,
+        Some("Failed to parse the provided value as a series name"),
+    )?;
+
+    let series = Series::new(PlSmallStr::from_string(series_name), data)
+        .strict_cast(&dtype)
+        .context(format!("Failed to create series of type `{dtype}`"))?;
+
+    Ok(SeriesHandle::alloc(series))
+}
+
+const NEW_DECIMAL_SERIES_METHOD: NativeMethod = series_method! {
+    extern fn new_decimal_series(
+        name: java.lang.String,
+        unscaled: [jlong],
+        precision: jint,
+        scale: jint,
+        validity: java.lang.Object,
+    ) -> SeriesHandle,
+    name = "newDecimalSeries",
+};
+
+/// Builds a decimal series from unscaled values, e.g. `12345` with scale 2 for `123.45`.
+fn new_decimal_series<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    name: JString<'local>,
+    unscaled: JLongArray<'local>,
+    precision: jint,
+    scale: jint,
+    validity: JObject<'local>,
+) -> anyhow::Result<SeriesHandle> {
+    let values: Vec<i128> = JavaArrayToVec::to_vec(env, unscaled)?
+        .into_iter()
+        .map(i128::from)
+        .collect();
+    let validity = read_validity(env, &validity, values.len())?;
+
+    decimal_series(
+        env,
+        name,
+        apply_validity(values, validity),
+        precision,
+        scale,
+    )
+}
+
+const NEW_BIG_DECIMAL_SERIES_METHOD: NativeMethod = series_method! {
+    extern fn new_big_decimal_series(
+        name: java.lang.String,
+        unscaled: [java.lang.Object],
+        precision: jint,
+        scale: jint,
+    ) -> SeriesHandle,
+    name = "newBigDecimalSeries",
+};
+
+/// Builds a decimal series from unscaled `java.math.BigInteger` values (`null` elements become
+/// nulls), for values beyond the range of `long`.
+fn new_big_decimal_series<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    name: JString<'local>,
+    unscaled: JObjectArray<'local, JObject<'local>>,
+    precision: jint,
+    scale: jint,
+) -> anyhow::Result<SeriesHandle> {
+    let len = unscaled.len(env).context("Failed to get array length")?;
+    let mut values = Vec::with_capacity(len);
+    for i in 0..len {
+        let obj = unscaled
+            .get_element(env, i)
+            .context("Failed to get array element")?
+            .auto();
+        values.push(match jobject_to_any_value(env, &obj)? {
+            AnyValue::Null => None,
+            AnyValue::Int64(v) => Some(v as i128),
+            AnyValue::Int128(v) => Some(v),
+            av => bail!("Expected a BigInteger as unscaled decimal value, got `{av}`"),
+        });
+    }
+
+    decimal_series(env, name, values, precision, scale)
+}
+
+fn decimal_series(
+    env: &mut Env,
+    name: JString,
+    values: Vec<Option<i128>>,
+    precision: jint,
+    scale: jint,
+) -> anyhow::Result<SeriesHandle> {
+    ensure!(
+        (1..=38).contains(&precision) && (0..=precision).contains(&scale),
+        "Invalid decimal precision `{precision}` and scale `{scale}`, expected \
+         0 <= scale <= precision <= 38"
+    );
+    let (precision, scale) = (precision as usize, scale as usize);
+
+    let any_values = values
+        .into_iter()
+        .map(|v| match v {
+            Some(v) => {
+                ensure_decimal_fits(v, precision)?;
+                Ok(AnyValue::Decimal(v, precision, scale))
+            },
+            None => Ok(AnyValue::Null),
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let series_name = j_string_to_string(
+        env,
+        &name,
+        Some("Failed to parse the provided value as a series name"),
+    )?;
+
+    let series = Series::from_any_values_and_dtype(
+        PlSmallStr::from_string(series_name),
+        &any_values,
+        &DataType::Decimal(precision, scale),
+        true,
+    )
+    .context("Failed to create decimal series")?;
+
+    Ok(SeriesHandle::alloc(series))
+}
+
+const NEW_BINARY_SERIES_METHOD: NativeMethod = series_method! {
+    extern fn new_binary_series(name: java.lang.String, values: jbyte[][]) -> SeriesHandle,
+    name = "newBinarySeries",
+};
+
+/// Builds a binary series from a `byte[][]`, where `null` elements become nulls.
+fn new_binary_series<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    name: JString<'local>,
+    values: JObjectArray<'local, JByteArray<'local>>,
+) -> anyhow::Result<SeriesHandle> {
+    let len = values.len(env).context("Failed to get array length")?;
+    let mut data: Vec<Option<Vec<u8>>> = Vec::with_capacity(len);
+    for i in 0..len {
+        let element = values
+            .get_element(env, i)
+            .context("Failed to get array element")?
+            .auto();
+        data.push(if element.is_null() {
+            None
+        } else {
+            Some(
+                env.convert_byte_array(&*element)
+                    .context("Failed to read the provided value as a series element")?,
+            )
+        });
+    }
+
+    let series_name = j_string_to_string(
+        env,
+        &name,
+        Some("Failed to parse the provided value as a series name"),
+    )?;
+
+    let data: Vec<Option<&[u8]>> = data.iter().map(Option::as_deref).collect();
+    Ok(SeriesHandle::alloc(Series::new(
+        PlSmallStr::from_string(series_name),
+        data,
+    )))
+}
+
+const NEW_DURATION_SERIES_METHOD: NativeMethod = series_method! {
+    extern fn new_duration_series(
+        name: java.lang.String,
+        values: [jlong],
+        time_unit: java.lang.String,
+        validity: java.lang.Object,
+    ) -> SeriesHandle,
+    name = "newDurationSeries",
+};
+
+/// Builds a duration series from lengths expressed in `time_unit` (`ns`, `us` or `ms`).
+fn new_duration_series<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    name: JString<'local>,
+    values: JLongArray<'local>,
+    time_unit: JString<'local>,
+    validity: JObject<'local>,
+) -> anyhow::Result<SeriesHandle> {
+    let time_unit = parse_time_unit(&j_string_to_string(
+        env,
+        &time_unit,
+        Some("Failed to parse provided time unit as string"),
+    )?)?;
+
+    physical_long_series(env, name, values, validity, &DataType::Duration(time_unit))
+}
+
+const NEW_EPOCH_DATETIME_SERIES_METHOD: NativeMethod = series_method! {
+    extern fn new_epoch_datetime_series(
+        name: java.lang.String,
+        values: [jlong],
+        time_unit: java.lang.String,
+        time_zone: java.lang.String,
+        validity: java.lang.Object,
+    ) -> SeriesHandle,
+    name = "newEpochDatetimeSeries",
+};
+
+/// Builds a datetime series from timestamps since the epoch in `time_unit` (`ns`, `us` or `ms`),
+/// optionally tagged with `time_zone`.
+fn new_epoch_datetime_series<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    name: JString<'local>,
+    values: JLongArray<'local>,
+    time_unit: JString<'local>,
+    time_zone: JString<'local>,
+    validity: JObject<'local>,
+) -> anyhow::Result<SeriesHandle> {
+    let time_unit = parse_time_unit(&j_string_to_string(
+        env,
+        &time_unit,
+        Some("Failed to parse provided time unit as string"),
+    )?)?;
+
+    let time_zone = if time_zone.is_null() {
+        None
+    } else {
+        let tz = j_string_to_string(
+            env,
+            &time_zone,
+            Some("Failed to parse provided time zone as string"),
+        )?;
+        TimeZone::opt_try_new(Some(tz))?
+    };
+
+    physical_long_series(
+        env,
+        name,
+        values,
+        validity,
+        &DataType::Datetime(time_unit, time_zone),
+    )
+}
+
+/// Builds a nullable `Int64` series and reinterprets it as the temporal `dtype` it physically
+/// represents.
+fn physical_long_series(
+    env: &mut Env,
+    name: JString,
+    values: JLongArray,
+    validity: JObject,
+    dtype: &DataType,
+) -> anyhow::Result<SeriesHandle> {
+    let values = JavaArrayToVec::to_vec(env, values)?;
+    let validity = read_validity(env, &validity, values.len())?;
+
+    let series_name = j_string_to_string(
+        env,
+        &name,
+        Some("Failed to parse the provided value as a series name"),
+    )?;
+
+    let series = Series::new(
+        PlSmallStr::from_string(series_name),
+        apply_validity(values, validity),
+    )
+    .cast(dtype)
+    .context(format!("Failed to create series of type `{dtype}`"))?;
+
+    Ok(SeriesHandle::alloc(series))
+}
+
+const NEW_DIRECT_BUFFER_SERIES_METHOD: NativeMethod = series_method! {
+    extern fn new_direct_buffer_series(
+        name: java.lang.String,
+        data_type: java.lang.String,
+        data: java.nio.ByteBuffer,
+        length: jlong,
+        validity: java.nio.ByteBuffer,
+    ) -> SeriesHandle,
+    name = "newDirectBufferSeries",
+};
+
+/// Builds a series of `length` values of the fixed-width `data_type` directly over the memory of
+/// the direct buffer `data` from its position, with an optional Arrow validity bitmap in
+/// `validity` (may be `null`). Neither buffer is copied; see [`series_from_direct_buffers`].
+fn new_direct_buffer_series<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    name: JString<'local>,
+    data_type: JString<'local>,
+    data: JByteBuffer<'local>,
+    length: jlong,
+    validity: JByteBuffer<'local>,
+) -> anyhow::Result<SeriesHandle> {
+    ensure!(
+        length >= 0,
+        "Series length must not be negative, got {length}"
+    );
+
+    let dt_str = j_string_to_string(
+        env,
+        &data_type,
+        Some("Failed to parse provided DataType as string"),
+    )?;
+    let dtype = parse_dtype(&dt_str)?;
+
+    let series_name = j_string_to_string(
+        env,
+        &name,
+        Some("Failed to parse the provided value as a series name"),
+    )?;
+
+    let validity = (!validity.is_null()).then_some(&validity);
+    let series = series_from_direct_buffers(
+        env,
+        PlSmallStr::from_string(series_name),
+        &dtype,
+        &data,
+        length as usize,
+        validity,
+    )?;
+
+    Ok(SeriesHandle::alloc(series))
+}
+
 const NEW_STR_SERIES_METHOD: NativeMethod = series_method! {
     extern fn new_str_series(name: java.lang.String, values: [java.lang.String]) -> SeriesHandle,
     name = "newStrSeries",
@@ -295,6 +906,846 @@ fn new_struct_series<'local>(
     Ok(SeriesHandle::alloc(series))
 }
 
+const NEW_TYPED_SERIES_METHOD: NativeMethod = series_method! {
+    extern fn new_typed_series(
+        name: java.lang.String,
+        values: [java.lang.Object],
+        data_type: java.lang.String,
+    ) -> SeriesHandle,
+    name = "newTypedSeries",
+};
+
+/// Builds a series of the given `data_type` (see [`parse_dtype`] for the accepted type strings)
+/// from boxed Java values, where `null` elements become nulls. Values that cannot be represented
+/// in `data_type` are an error.
+fn new_typed_series<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    name: JString<'local>,
+    values: JObjectArray<'local, JObject<'local>>,
+    data_type: JString<'local>,
+) -> anyhow::Result<SeriesHandle> {
+    let dt_str = j_string_to_string(
+        env,
+        &data_type,
+        Some("Failed to parse provided DataType as string"),
+    )?;
+    let dtype = parse_dtype(&dt_str)?;
+
+    let len = values.len(env).context("Failed to get array length")?;
+    let mut any_values = Vec::with_capacity(len);
+    for i in 0..len {
+        let obj = values
+            .get_element(env, i)
+            .context("Failed to get array element")?
+            .auto();
+        any_values.push(jobject_to_any_value(env, &obj)?);
+    }
+
+    let series_name = j_string_to_string(
+        env,
+        &name,
+        Some("Failed to parse the provided value as a series name"),
+    )?;
+
+    let series = Series::from_any_values_and_dtype(
+        PlSmallStr::from_string(series_name),
+        &any_values,
+        &dtype,
+        true,
+    )
+    .context(format!("Failed to create series of type `{dtype}`"))?;
+
+    Ok(SeriesHandle::alloc(series))
+}
+
+/// Casts `series` to the numeric `dtype`, failing on values that do not fit, and copies out its
+/// values with nulls as zero. Temporal series yield their physical values.
+fn numeric_values<T>(series: &Series, dtype: &DataType) -> anyhow::Result<Vec<T::Native>>
+where
+    T: PolarsNumericType,
+{
+    let cast = series
+        .to_physical_repr()
+        .strict_cast(dtype)
+        .context(format!(
+            "Failed to cast series `{}` of type `{}` to `{dtype}`",
+            series.name(),
+            series.dtype()
+        ))?;
+    let ca = cast.unpack::<T>()?;
+
+    let mut values = Vec::with_capacity(ca.len());
+    for arr in ca.downcast_iter() {
+        if arr.null_count() == 0 {
+            values.extend_from_slice(arr.values());
+        } else {
+            values.extend(arr.iter().map(|v| v.copied().unwrap_or_default()));
+        }
+    }
+    Ok(values)
+}
+
+/// Generates a `native_method!` entry point that copies a [`Series`] into a new Java primitive
+/// array in one call, via [`numeric_values`]. Null values become zero; use `validity` to tell
+/// them apart.
+macro_rules! impl_to_primitive_array {
+    (
+        $const_name:ident,
+        $fn_name:ident,
+        $scala_name:literal,
+        [$sig_elem:tt],
+        $java_array:ty,
+        $pl_type:ty,
+        $dtype:expr
+    ) => {
+        const $const_name: NativeMethod = series_method! {
+            extern fn $fn_name(series_ptr: SeriesHandle) -> [$sig_elem],
+            name = $scala_name,
+        };
+
+        fn $fn_name<'local>(
+            env: &mut Env<'local>,
+            _this: JObject<'local>,
+            series_ptr: SeriesHandle,
+        ) -> anyhow::Result<$java_array> {
+            let values = numeric_values::<$pl_type>(&series_ptr.get(), &$dtype)?;
+
+            let array = <$java_array>::new(env, values.len())
+                .context("Failed to initialize array for series values")?;
+            array
+                .set_region(env, 0, &values)
+                .context("Failed to set series values in array")?;
+            Ok(array)
+        }
+    };
+}
+
+impl_to_primitive_array!(
+    TO_LONG_ARRAY_METHOD,
+    to_long_array,
+    "toLongArray",
+    [jlong],
+    JLongArray<'local>,
+    Int64Type,
+    DataType::Int64
+);
+impl_to_primitive_array!(
+    TO_INT_ARRAY_METHOD,
+    to_int_array,
+    "toIntArray",
+    [jint],
+    JIntArray<'local>,
+    Int32Type,
+    DataType::Int32
+);
+impl_to_primitive_array!(
+    TO_FLOAT_ARRAY_METHOD,
+    to_float_array,
+    "toFloatArray",
+    [jfloat],
+    JFloatArray<'local>,
+    Float32Type,
+    DataType::Float32
+);
+impl_to_primitive_array!(
+    TO_DOUBLE_ARRAY_METHOD,
+    to_double_array,
+    "toDoubleArray",
+    [jdouble],
+    JDoubleArray<'local>,
+    Float64Type,
+    DataType::Float64
+);
+
+const TO_BOOLEAN_ARRAY_METHOD: NativeMethod = series_method! {
+    extern fn to_boolean_array(series_ptr: SeriesHandle) -> [jboolean],
+    name = "toBooleanArray",
+};
+
+/// Copies a series cast to `Boolean` into a new `boolean[]`, with nulls as `false`.
+fn to_boolean_array<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+) -> anyhow::Result<JBooleanArray<'local>> {
+    let series = series_ptr.get();
+    let cast = series.strict_cast(&DataType::Boolean).context(format!(
+        "Failed to cast series `{}` of type `{}` to `bool`",
+        series.name(),
+        series.dtype()
+    ))?;
+    let values: Vec<bool> = cast.bool()?.iter().map(|v| v.unwrap_or(false)).collect();
+
+    let array = JBooleanArray::new(env, values.len())
+        .context("Failed to initialize array for series values")?;
+    array
+        .set_region(env, 0, &values)
+        .context("Failed to set series values in array")?;
+    Ok(array)
+}
+
+const TO_STRING_ARRAY_METHOD: NativeMethod = series_method! {
+    extern fn to_string_array(series_ptr: SeriesHandle) -> [java.lang.String],
+    name = "toStringArray",
+};
+
+/// Copies a series cast to `String` into a new `String[]`, with nulls as `null`.
+fn to_string_array<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+) -> anyhow::Result<JObjectArray<'local, JString<'local>>> {
+    let series = series_ptr.get();
+    let cast = series.cast(&DataType::String).context(format!(
+        "Failed to cast series `{}` of type `{}` to `str`",
+        series.name(),
+        series.dtype()
+    ))?;
+    let ca = cast.str()?;
+
+    let array = JObjectArray::<JString>::new(env, ca.len(), JString::null())
+        .context("Failed to initialize array for series values")?;
+    for (i, value) in ca.iter().enumerate() {
+        if let Some(value) = value {
+            let j_string = env
+                .new_string(value)
+                .context("Failed to convert series value to string")?
+                .auto();
+            array
+                .set_element(env, i, &j_string)
+                .context("Failed to set series value in array")?;
+        }
+    }
+    Ok(array)
+}
+
+const VALIDITY_METHOD: NativeMethod = series_method! {
+    extern fn validity(series_ptr: SeriesHandle) -> [jlong],
+};
+
+/// Returns the validity of the values as a bit-packed `long[]` in the layout of
+/// `java.util.BitSet.toLongArray` (bit `i` is set if value `i` is not null), or `null` if the
+/// series has no nulls.
+fn validity<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+) -> anyhow::Result<JLongArray<'local>> {
+    let series = series_ptr.get();
+    if series.null_count() == 0 {
+        return Ok(unsafe { JLongArray::from_raw(env, std::ptr::null_mut()) });
+    }
+
+    let mut words = vec![0i64; series.len().div_ceil(64)];
+    for_each_valid(&series, |i| words[i / 64] |= 1 << (i % 64));
+    vec_to_j_long_array(env, &words)
+}
+
+const FILL_DIRECT_BUFFER_METHOD: NativeMethod = series_method! {
+    extern fn fill_direct_buffer(
+        series_ptr: SeriesHandle,
+        data_type: java.lang.String,
+        data: java.nio.ByteBuffer,
+        validity: java.nio.ByteBuffer,
+    ) -> jlong,
+    name = "fillDirectBuffer",
+};
+
+/// Writes the values of a series cast to the fixed-width `data_type` into the direct buffer
+/// `data`, and optionally its Arrow validity bitmap into `validity` (may be `null`), returning the
+/// number of values written. See [`fill_direct_buffers`].
+fn fill_direct_buffer<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    data_type: JString<'local>,
+    data: JByteBuffer<'local>,
+    validity: JByteBuffer<'local>,
+) -> anyhow::Result<jlong> {
+    let dt_str = j_string_to_string(
+        env,
+        &data_type,
+        Some("Failed to parse provided DataType as string"),
+    )?;
+    let dtype = parse_dtype(&dt_str)?;
+
+    let validity = (!validity.is_null()).then_some(&validity);
+    let written = fill_direct_buffers(env, &series_ptr.get(), &dtype, &data, validity)?;
+    Ok(written as jlong)
+}
+
+const NAME_METHOD: NativeMethod = series_method! {
+    extern fn name(series_ptr: SeriesHandle) -> JString,
+};
+
+fn name<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+) -> anyhow::Result<JString<'local>> {
+    JString::from_str(env, series_ptr.get().name()).context("Failed to build series name string")
+}
+
+const RENAME_METHOD: NativeMethod = series_method! {
+    extern fn rename(series_ptr: SeriesHandle, name: java.lang.String) -> SeriesHandle,
+};
+
+fn rename<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    name: JString<'local>,
+) -> anyhow::Result<SeriesHandle> {
+    let series_name = j_string_to_string(
+        env,
+        &name,
+        Some("Failed to parse the provided value as a series name"),
+    )?;
+
+    Ok(SeriesHandle::alloc(
+        series_ptr
+            .get()
+            .with_name(PlSmallStr::from_string(series_name)),
+    ))
+}
+
+const DTYPE_METHOD: NativeMethod = series_method! {
+    extern fn dtype(series_ptr: SeriesHandle) -> JString,
+};
+
+/// Returns the type of the series as [`dtype_to_json`] JSON, which [`parse_dtype`] reads back.
+fn dtype<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+) -> anyhow::Result<JString<'local>> {
+    let json = dtype_to_json(series_ptr.get().dtype())?;
+    JString::from_str(env, json.to_string()).context("Failed to build data type string")
+}
+
+const LEN_METHOD: NativeMethod = series_method! {
+    extern fn len(series_ptr: SeriesHandle) -> jlong,
+};
+
+fn len<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+) -> anyhow::Result<jlong> {
+    Ok(series_ptr.get().len() as jlong)
+}
+
+const NULL_COUNT_METHOD: NativeMethod = series_method! {
+    extern fn null_count(series_ptr: SeriesHandle) -> jlong,
+    name = "nullCount",
+};
+
+fn null_count<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+) -> anyhow::Result<jlong> {
+    Ok(series_ptr.get().null_count() as jlong)
+}
+
+const GET_METHOD: NativeMethod = series_method! {
+    extern fn get(series_ptr: SeriesHandle, index: jlong) -> JObject,
+};
+
+/// Returns the value at `index` boxed as a Java object, or `null` for a null value.
+fn get<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    index: jlong,
+) -> anyhow::Result<JObject<'local>> {
+    let series = series_ptr.get();
+    ensure!(
+        index >= 0 && (index as usize) < series.len(),
+        "Index {index} is out of bounds for series `{}` of length {}",
+        series.name(),
+        series.len()
+    );
+
+    let value = series
+        .get(index as usize)
+        .context(format!("Failed to get value at index {index}"))?;
+    AnyValueWrapper(value).try_into_java(env)
+}
+
+const SLICE_METHOD: NativeMethod = series_method! {
+    extern fn slice(series_ptr: SeriesHandle, offset: jlong, length: jlong) -> SeriesHandle,
+};
+
+/// Returns `length` values starting at `offset`, where a negative offset counts from the end.
+fn slice<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    offset: jlong,
+    length: jlong,
+) -> anyhow::Result<SeriesHandle> {
+    ensure!(
+        length >= 0,
+        "Slice length must not be negative, got {length}"
+    );
+    Ok(SeriesHandle::alloc(
+        series_ptr.get().slice(offset, length as usize),
+    ))
+}
+
+/// Resolves the number of values kept by `head` and `tail`, where a negative `n` keeps all but
+/// `|n|` values as in polars.
+fn keep_len(len: usize, n: jlong) -> usize {
+    if n < 0 {
+        len.saturating_sub(n.unsigned_abs() as usize)
+    } else {
+        n as usize
+    }
+}
+
+const HEAD_METHOD: NativeMethod = series_method! {
+    extern fn head(series_ptr: SeriesHandle, n: jlong) -> SeriesHandle,
+};
+
+/// The first `n` values, or all but the last `|n|` values if `n` is negative.
+fn head<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    n: jlong,
+) -> anyhow::Result<SeriesHandle> {
+    let series = series_ptr.get();
+    let n = keep_len(series.len(), n);
+    Ok(SeriesHandle::alloc(series.head(Some(n))))
+}
+
+const TAIL_METHOD: NativeMethod = series_method! {
+    extern fn tail(series_ptr: SeriesHandle, n: jlong) -> SeriesHandle,
+};
+
+/// The last `n` values, or all but the first `|n|` values if `n` is negative.
+fn tail<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    n: jlong,
+) -> anyhow::Result<SeriesHandle> {
+    let series = series_ptr.get();
+    let n = keep_len(series.len(), n);
+    Ok(SeriesHandle::alloc(series.tail(Some(n))))
+}
+
+const SORT_METHOD: NativeMethod = series_method! {
+    extern fn sort(series_ptr: SeriesHandle, descending: jboolean, nulls_last: jboolean) -> SeriesHandle,
+};
+
+fn sort<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    descending: jboolean,
+    nulls_last: jboolean,
+) -> anyhow::Result<SeriesHandle> {
+    let sorted = series_ptr
+        .get()
+        .sort(SortOptions {
+            descending,
+            nulls_last,
+            ..Default::default()
+        })
+        .context("Failed to sort series")?;
+
+    Ok(SeriesHandle::alloc(sorted))
+}
+
+const ARG_SORT_METHOD: NativeMethod = series_method! {
+    extern fn arg_sort(series_ptr: SeriesHandle, descending: jboolean, nulls_last: jboolean) -> SeriesHandle,
+    name = "argSort",
+};
+
+/// Returns the indices that would sort the series.
+fn arg_sort<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    descending: jboolean,
+    nulls_last: jboolean,
+) -> anyhow::Result<SeriesHandle> {
+    let series = series_ptr.get();
+    let indices = series.arg_sort(SortOptions {
+        descending,
+        nulls_last,
+        ..Default::default()
+    });
+
+    Ok(SeriesHandle::alloc(
+        indices.with_name(series.name().clone()).into_series(),
+    ))
+}
+
+const UNIQUE_METHOD: NativeMethod = series_method! {
+    extern fn unique(series_ptr: SeriesHandle, maintain_order: jboolean) -> SeriesHandle,
+};
+
+fn unique<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    maintain_order: jboolean,
+) -> anyhow::Result<SeriesHandle> {
+    let series = series_ptr.get();
+    let unique = if maintain_order {
+        series.unique_stable()
+    } else {
+        series.unique()
+    }
+    .context("Failed to get unique values of series")?;
+
+    Ok(SeriesHandle::alloc(unique))
+}
+
+const N_UNIQUE_METHOD: NativeMethod = series_method! {
+    extern fn n_unique(series_ptr: SeriesHandle) -> jlong,
+    name = "nUnique",
+};
+
+fn n_unique<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+) -> anyhow::Result<jlong> {
+    let n = series_ptr
+        .get()
+        .n_unique()
+        .context("Failed to count unique values of series")?;
+    Ok(n as jlong)
+}
+
+const VALUE_COUNTS_METHOD: NativeMethod = series_method! {
+    extern fn value_counts(series_ptr: SeriesHandle, sort: jboolean, normalize: jboolean) -> DataFrameHandle,
+    name = "valueCounts",
+};
+
+/// Returns a DataFrame of the unique values and their `count` (or `proportion` if `normalize`),
+/// sorted by descending count if `sort`.
+fn value_counts<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    sort: jboolean,
+    normalize: jboolean,
+) -> anyhow::Result<DataFrameHandle> {
+    let counts_name = if normalize { "proportion" } else { "count" };
+    let df = series_ptr
+        .get()
+        .value_counts(sort, true, PlSmallStr::from_static(counts_name), normalize)
+        .context("Failed to count values of series")?;
+
+    Ok(DataFrameHandle::alloc(df))
+}
+
+const FILTER_METHOD: NativeMethod = series_method! {
+    extern fn filter(series_ptr: SeriesHandle, mask_ptr: SeriesHandle) -> SeriesHandle,
+};
+
+/// Keeps the values for which the boolean series `mask_ptr` is `true`.
+fn filter<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    mask_ptr: SeriesHandle,
+) -> anyhow::Result<SeriesHandle> {
+    let mask = mask_ptr.get();
+    let mask = mask.bool().context(format!(
+        "Filter mask must be of type `bool`, got `{}`",
+        mask.dtype()
+    ))?;
+
+    let filtered = series_ptr
+        .get()
+        .filter(mask)
+        .context("Failed to filter series")?;
+    Ok(SeriesHandle::alloc(filtered))
+}
+
+const TAKE_METHOD: NativeMethod = series_method! {
+    extern fn take(series_ptr: SeriesHandle, indices: [jlong]) -> SeriesHandle,
+};
+
+/// Gathers the values at `indices`, in that order.
+fn take<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    indices: JLongArray<'local>,
+) -> anyhow::Result<SeriesHandle> {
+    let series = series_ptr.get();
+    let indices = JavaArrayToVec::to_vec(env, indices)?
+        .into_iter()
+        .map(|i| {
+            IdxSize::try_from(i)
+                .ok()
+                .filter(|&i| (i as usize) < series.len())
+                .context(format!(
+                    "Index {i} is out of bounds for series `{}` of length {}",
+                    series.name(),
+                    series.len()
+                ))
+        })
+        .collect::<anyhow::Result<Vec<IdxSize>>>()?;
+
+    let taken = series
+        .take(&IdxCa::from_vec(PlSmallStr::EMPTY, indices))
+        .context("Failed to take values from series")?;
+    Ok(SeriesHandle::alloc(taken))
+}
+
+const CAST_METHOD: NativeMethod = series_method! {
+    extern fn cast(series_ptr: SeriesHandle, data_type: java.lang.String, strict: jboolean) -> SeriesHandle,
+};
+
+/// Casts the series to `data_type` (see [`parse_dtype`]). If `strict`, values that cannot be
+/// represented in `data_type` are an error, otherwise they become null.
+fn cast<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    data_type: JString<'local>,
+    strict: jboolean,
+) -> anyhow::Result<SeriesHandle> {
+    let dt_str = j_string_to_string(
+        env,
+        &data_type,
+        Some("Failed to parse provided DataType as string"),
+    )?;
+    let dtype = parse_dtype(&dt_str)?;
+
+    let series = series_ptr.get();
+    let cast = if strict {
+        series.strict_cast(&dtype)
+    } else {
+        series.cast(&dtype)
+    }
+    .context(format!(
+        "Failed to cast series `{}` of type `{}` to `{dtype}`",
+        series.name(),
+        series.dtype()
+    ))?;
+
+    Ok(SeriesHandle::alloc(cast))
+}
+
+/// Applies the binary `operator` (see [`BinaryOperator`]) between two series, where a series of
+/// length one is broadcast against the other.
+fn apply_binary_op(left: &Series, right: &Series, operator: jint) -> anyhow::Result<Series> {
+    let op = BinaryOperator::from_i32(operator).context(format!(
+        "Failed to parse provided ID `{operator}` as binary operator."
+    ))?;
+
+    let result = match op {
+        BinaryOperator::EqualTo => left.equal(right)?.into_series(),
+        BinaryOperator::NotEqualTo => left.not_equal(right)?.into_series(),
+        BinaryOperator::LessThan => left.lt(right)?.into_series(),
+        BinaryOperator::LessThanEqualTo => left.lt_eq(right)?.into_series(),
+        BinaryOperator::GreaterThan => left.gt(right)?.into_series(),
+        BinaryOperator::GreaterThanEqualTo => left.gt_eq(right)?.into_series(),
+        BinaryOperator::Or => (left.bool()? | right.bool()?).into_series(),
+        BinaryOperator::And => (left.bool()? & right.bool()?).into_series(),
+        BinaryOperator::Plus => (left + right)?,
+        BinaryOperator::Minus => (left - right)?,
+        BinaryOperator::Multiply => (left * right)?,
+        BinaryOperator::Divide => (left / right)?,
+        BinaryOperator::Modulus => (left % right)?,
+    };
+
+    Ok(result)
+}
+
+const APPLY_BINARY_METHOD: NativeMethod = series_method! {
+    extern fn apply_binary(left: SeriesHandle, right: SeriesHandle, operator: jint) -> SeriesHandle,
+    name = "applyBinary",
+};
+
+fn apply_binary<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    left: SeriesHandle,
+    right: SeriesHandle,
+    operator: jint,
+) -> anyhow::Result<SeriesHandle> {
+    let result = apply_binary_op(&left.get(), &right.get(), operator)?;
+    Ok(SeriesHandle::alloc(result))
+}
+
+const APPLY_BINARY_SCALAR_METHOD: NativeMethod = series_method! {
+    extern fn apply_binary_scalar(left: SeriesHandle, right: java.lang.Object, operator: jint) -> SeriesHandle,
+    name = "applyBinaryScalar",
+};
+
+/// Applies the binary `operator` between the series and a boxed Java scalar (see
+/// [`jobject_to_any_value`]), broadcast to every value.
+fn apply_binary_scalar<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    left: SeriesHandle,
+    right: JObject<'local>,
+    operator: jint,
+) -> anyhow::Result<SeriesHandle> {
+    let value = jobject_to_any_value(env, &right)?;
+    let scalar = Series::from_any_values(PlSmallStr::EMPTY, &[value], true)
+        .context("Failed to create series from scalar value")?;
+
+    let result = apply_binary_op(&left.get(), &scalar, operator)?;
+    Ok(SeriesHandle::alloc(result))
+}
+
+const REDUCE_METHOD: NativeMethod = series_method! {
+    extern fn reduce(series_ptr: SeriesHandle, aggregation: java.lang.String) -> JObject,
+};
+
+/// Reduces the series to a single boxed value with `aggregation`, one of `sum`, `min`, `max`,
+/// `mean` or `median`. The result is `null` if there is nothing to aggregate.
+fn reduce<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    aggregation: JString<'local>,
+) -> anyhow::Result<JObject<'local>> {
+    let aggregation = j_string_to_string(
+        env,
+        &aggregation,
+        Some("Failed to parse provided aggregation as string"),
+    )?;
+
+    let series = series_ptr.get();
+    let scalar = match aggregation.to_lowercase().as_str() {
+        "sum" => series.sum_reduce(),
+        "min" => series.min_reduce(),
+        "max" => series.max_reduce(),
+        "mean" => series.mean_reduce(),
+        "median" => series.median_reduce(),
+        other => bail!(
+            "Unknown aggregation `{other}`, expected one of `sum`, `min`, `max`, `mean` or \
+             `median`"
+        ),
+    }
+    .context(format!(
+        "Failed to compute {aggregation} of series `{}` of type `{}`",
+        series.name(),
+        series.dtype()
+    ))?;
+
+    AnyValueWrapper(scalar.into_value()).try_into_java(env)
+}
+
+const STD_METHOD: NativeMethod = series_method! {
+    extern fn std_dev(series_ptr: SeriesHandle, ddof: jint) -> JObject,
+    name = "std",
+};
+
+fn std_dev<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    ddof: jint,
+) -> anyhow::Result<JObject<'local>> {
+    let ddof = u8::try_from(ddof).context(format!("Invalid ddof {ddof}, expected 0 to 255"))?;
+    let scalar = series_ptr
+        .get()
+        .std_reduce(ddof)
+        .context("Failed to compute standard deviation of series")?;
+    AnyValueWrapper(scalar.into_value()).try_into_java(env)
+}
+
+const VAR_METHOD: NativeMethod = series_method! {
+    extern fn var(series_ptr: SeriesHandle, ddof: jint) -> JObject,
+    name = "variance",
+};
+
+fn var<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    ddof: jint,
+) -> anyhow::Result<JObject<'local>> {
+    let ddof = u8::try_from(ddof).context(format!("Invalid ddof {ddof}, expected 0 to 255"))?;
+    let scalar = series_ptr
+        .get()
+        .var_reduce(ddof)
+        .context("Failed to compute variance of series")?;
+    AnyValueWrapper(scalar.into_value()).try_into_java(env)
+}
+
+const QUANTILE_METHOD: NativeMethod = series_method! {
+    extern fn quantile(series_ptr: SeriesHandle, q: jdouble, method: java.lang.String) -> JObject,
+};
+
+fn quantile<'local>(
+    env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    q: jdouble,
+    method: JString<'local>,
+) -> anyhow::Result<JObject<'local>> {
+    let s_method = j_string_to_string(env, &method, Some("Failed to parse quantile method"))?;
+    let q_method = match s_method.to_lowercase().as_str() {
+        "nearest" => QuantileMethod::Nearest,
+        "lower" => QuantileMethod::Lower,
+        "higher" => QuantileMethod::Higher,
+        "midpoint" => QuantileMethod::Midpoint,
+        "linear" => QuantileMethod::Linear,
+        "equiprobable" => QuantileMethod::Equiprobable,
+        other => bail!(
+            "Unknown quantile method `{other}`, expected one of `nearest`, `lower`, `higher`, \
+             `midpoint`, `linear` or `equiprobable`"
+        ),
+    };
+
+    let scalar = series_ptr
+        .get()
+        .quantile_reduce(q, q_method)
+        .context("Failed to compute quantile of series")?;
+    AnyValueWrapper(scalar.into_value()).try_into_java(env)
+}
+
+const EQUALS_METHOD: NativeMethod = series_method! {
+    extern fn equals(series_ptr: SeriesHandle, other_ptr: SeriesHandle, null_equal: jboolean) -> jboolean,
+};
+
+/// Whether both series have the same name, type and values. With `null_equal`, nulls compare
+/// equal to each other, otherwise any null makes the series unequal.
+fn equals<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+    other_ptr: SeriesHandle,
+    null_equal: jboolean,
+) -> anyhow::Result<jboolean> {
+    let (series, other) = (series_ptr.get(), other_ptr.get());
+    let values_equal = if null_equal {
+        series.equals_missing(&other)
+    } else {
+        series.equals(&other)
+    };
+
+    Ok(series.name() == other.name() && series.dtype() == other.dtype() && values_equal)
+}
+
+const TO_FRAME_METHOD: NativeMethod = series_method! {
+    extern fn to_frame(series_ptr: SeriesHandle) -> DataFrameHandle,
+    name = "toFrame",
+};
+
+fn to_frame<'local>(
+    _env: &mut Env<'local>,
+    _this: JObject<'local>,
+    series_ptr: SeriesHandle,
+) -> anyhow::Result<DataFrameHandle> {
+    Ok(DataFrameHandle::alloc(series_ptr.get().into_frame()))
+}
+
 const SHOW_METHOD: NativeMethod = series_method! {
     extern fn show(series_ptr: SeriesHandle),
     name = "show",
@@ -328,6 +1779,59 @@ pub const METHODS: &[NativeMethod] = &[
     NEW_DATETIME_SERIES_METHOD,
     NEW_LIST_SERIES_METHOD,
     NEW_STRUCT_SERIES_METHOD,
+    NEW_TYPED_SERIES_METHOD,
+    NEW_NULLABLE_LONG_SERIES_METHOD,
+    NEW_NULLABLE_INT_SERIES_METHOD,
+    NEW_NULLABLE_FLOAT_SERIES_METHOD,
+    NEW_FLOAT16_SERIES_METHOD,
+    NEW_NULLABLE_DOUBLE_SERIES_METHOD,
+    NEW_NULLABLE_BOOLEAN_SERIES_METHOD,
+    NEW_INT8_SERIES_METHOD,
+    NEW_INT16_SERIES_METHOD,
+    NEW_UINT8_SERIES_METHOD,
+    NEW_UINT16_SERIES_METHOD,
+    NEW_UINT32_SERIES_METHOD,
+    NEW_UINT64_SERIES_METHOD,
+    NEW_CATEGORICAL_SERIES_METHOD,
+    NEW_DECIMAL_SERIES_METHOD,
+    NEW_BIG_DECIMAL_SERIES_METHOD,
+    NEW_BINARY_SERIES_METHOD,
+    NEW_DURATION_SERIES_METHOD,
+    NEW_EPOCH_DATETIME_SERIES_METHOD,
+    NEW_DIRECT_BUFFER_SERIES_METHOD,
+    TO_LONG_ARRAY_METHOD,
+    TO_INT_ARRAY_METHOD,
+    TO_FLOAT_ARRAY_METHOD,
+    TO_DOUBLE_ARRAY_METHOD,
+    TO_BOOLEAN_ARRAY_METHOD,
+    TO_STRING_ARRAY_METHOD,
+    VALIDITY_METHOD,
+    FILL_DIRECT_BUFFER_METHOD,
+    NAME_METHOD,
+    RENAME_METHOD,
+    DTYPE_METHOD,
+    LEN_METHOD,
+    NULL_COUNT_METHOD,
+    GET_METHOD,
+    SLICE_METHOD,
+    HEAD_METHOD,
+    TAIL_METHOD,
+    SORT_METHOD,
+    ARG_SORT_METHOD,
+    UNIQUE_METHOD,
+    N_UNIQUE_METHOD,
+    VALUE_COUNTS_METHOD,
+    FILTER_METHOD,
+    TAKE_METHOD,
+    CAST_METHOD,
+    APPLY_BINARY_METHOD,
+    APPLY_BINARY_SCALAR_METHOD,
+    REDUCE_METHOD,
+    STD_METHOD,
+    VAR_METHOD,
+    QUANTILE_METHOD,
+    EQUALS_METHOD,
+    TO_FRAME_METHOD,
     SHOW_METHOD,
     FREE_METHOD,
 ];
@@ -2,6 +2,7 @@ use anyhow::Context;
 use jni::Env;
 use jni::objects::*;
 use jni::sys::*;
+use polars::prelude::TimeUnit;
 
 pub fn string_to_j_string(env: &mut Env, s: impl AsRef<str>) -> anyhow::Result<jstring> {
     Ok(env
@@ -54,6 +55,37 @@ where
     j_string_to_string(env, &s, msg)
 }
 
+/// Builds a `long[]` from `values`, typically the raw pointers of freshly allocated handles.
+pub fn vec_to_j_long_array<'local>(
+    env: &mut Env<'local>,
+    values: &[jlong],
+) -> anyhow::Result<JLongArray<'local>> {
+    let array = JLongArray::new(env, values.len()).context("Error initializing long array")?;
+    array
+        .set_region(env, 0, values)
+        .context("Error setting data in long array")?;
+    Ok(array)
+}
+
+/// Builds a `String[]` from `values`.
+pub fn vec_to_j_string_array<'local, S: AsRef<str>>(
+    env: &mut Env<'local>,
+    values: &[S],
+) -> anyhow::Result<JObjectArray<'local, JString<'local>>> {
+    let array = JObjectArray::<JString>::new(env, values.len(), JString::null())
+        .context("Error initializing string array")?;
+    for (i, value) in values.iter().enumerate() {
+        let j_string = env
+            .new_string(value)
+            .context("Error converting Rust String to JString")?
+            .auto();
+        array
+            .set_element(env, i, &j_string)
+            .context("Error setting data in string array")?;
+    }
+    Ok(array)
+}
+
 pub fn get_n_rows(n_rows: jlong) -> Option<usize> {
     if n_rows.is_positive() {
         Some(n_rows as usize)
@@ -61,3 +93,22 @@ pub fn get_n_rows(n_rows: jlong) -> Option<usize> {
         None
     }
 }
+
+/// Parses a polars time unit abbreviation (`ns`, `us` or `ms`).
+pub fn parse_time_unit(unit: &str) -> anyhow::Result<TimeUnit> {
+    match unit.trim().to_ascii_lowercase().as_str() {
+        "ns" => Ok(TimeUnit::Nanoseconds),
+        "us" | "μs" => Ok(TimeUnit::Microseconds),
+        "ms" => Ok(TimeUnit::Milliseconds),
+        other => anyhow::bail!("Unknown time unit `{other}`, expected one of `ns`, `us` or `ms`"),
+    }
+}
+
+/// Fails unless the unscaled decimal `value` has at most `precision` digits.
+pub fn ensure_decimal_fits(value: i128, precision: usize) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        value.unsigned_abs() < 10u128.pow(precision as u32),
+        "Unscaled decimal value `{value}` does not fit in precision `{precision}`"
+    );
+    Ok(())
+}